@@ -0,0 +1,90 @@
+extern crate proc_macro;
+
+use proc_macro::TokenStream as _TokenStream;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use syn::{parse_macro_input, LitStr};
+
+/// Macro for embedding a directory tree of schemas in the binary, it expands to an
+/// `ion_schema::authority::EmbeddedDocumentAuthority` that resolves the ids of the schemas relative to that directory.
+///
+/// The directory is relative to the root of the crate that uses the macro (i.e. the directory that contains its
+/// `Cargo.toml`), and all the `.isl` files of the directory and of its subdirectories are embedded.
+/// The embedded files are tracked by the compiler, but new files in the directory are only embedded once the crate is
+/// recompiled for another reason.
+///
+/// This macro is re-exported as `ion_schema::include_schemas` by the `embed` feature of the `ion-schema` crate.
+#[proc_macro]
+pub fn include_schemas(input: _TokenStream) -> _TokenStream {
+    let directory = parse_macro_input!(input as LitStr);
+    generate_embedded_document_authority(&directory)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+fn generate_embedded_document_authority(directory: &LitStr) -> syn::Result<TokenStream> {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let base_path = Path::new(&manifest_dir).join(directory.value());
+    if !base_path.is_dir() {
+        return Err(syn::Error::new(
+            directory.span(),
+            format!("{} is not a directory", base_path.display()),
+        ));
+    }
+    let mut schema_files = vec![];
+    find_schema_files(&base_path, &mut schema_files).map_err(|error| {
+        syn::Error::new(
+            directory.span(),
+            format!("unable to read {}: {error}", base_path.display()),
+        )
+    })?;
+    // sorting makes the expansion of the macro deterministic
+    schema_files.sort();
+
+    let mut schemas = vec![];
+    for schema_file in schema_files {
+        let relative_path = schema_file
+            .strip_prefix(&base_path)
+            .expect("schema files are found under the base path");
+        let id = relative_path
+            .components()
+            .map(|component| component.as_os_str().to_str())
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| {
+                syn::Error::new(
+                    directory.span(),
+                    format!("{} is not a valid UTF-8 path", schema_file.display()),
+                )
+            })?
+            .join("/");
+        let absolute_path = schema_file.to_str().ok_or_else(|| {
+            syn::Error::new(
+                directory.span(),
+                format!("{} is not a valid UTF-8 path", schema_file.display()),
+            )
+        })?;
+        schemas.push(quote! {
+            (#id, ::core::include_bytes!(#absolute_path) as &'static [u8])
+        });
+    }
+
+    Ok(quote! {
+        ::ion_schema::authority::EmbeddedDocumentAuthority::new([#(#schemas),*])
+    })
+}
+
+/// Collects the `.isl` files of given directory and of its subdirectories
+fn find_schema_files(directory: &Path, schema_files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            find_schema_files(&path, schema_files)?;
+        } else if path.extension().is_some_and(|extension| extension == "isl") {
+            schema_files.push(path);
+        }
+    }
+    Ok(())
+}
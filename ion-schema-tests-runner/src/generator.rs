@@ -333,7 +333,11 @@ fn generate_preamble(root_dir_path: &Path) -> TokenStream {
             } else {
                 ion_schema::IonSchemaElement::SingleElement(value)
             };
+            let is_valid = isl_type.is_valid(&prepared_value);
             let validation_result = isl_type.validate(prepared_value);
+            if is_valid != validation_result.is_ok() {
+                return Err(format!("is_valid() returned {} for {} but validate() returned {:?}", is_valid, value_ion, validation_result));
+            }
             if validation_result.is_ok() == expect_valid {
                 Ok(())
             } else {
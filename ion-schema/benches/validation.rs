@@ -0,0 +1,107 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ion_rs::element::Element;
+use ion_schema::authority::{DocumentAuthority, MapDocumentAuthority};
+use ion_schema::system::SchemaSystem;
+use ion_schema::types::TypeDefinition;
+
+const SCHEMA: &str = r#"
+    $ion_schema_2_0
+    type::{
+        name: id,
+        one_of: [
+            { type: int, valid_values: range::[0, max] },
+            { type: string, regex: "^[a-z0-9-]+$" },
+        ],
+    }
+    type::{
+        name: tag,
+        any_of: [
+            { type: symbol, codepoint_length: range::[1, 32] },
+            { type: string, codepoint_length: range::[1, 32] },
+        ],
+    }
+    type::{
+        name: point,
+        ordered_elements: [ float, float, { type: float, occurs: optional } ],
+    }
+    type::{
+        name: record,
+        type: struct,
+        fields: closed::{
+            id: { type: id, occurs: required },
+            name: { type: string, not: { codepoint_length: 0 } },
+            tags: { type: list, element: tag },
+            location: point,
+            score: { any_of: [int, decimal, float] },
+        },
+    }
+    type::{
+        name: records,
+        type: list,
+        element: record,
+    }
+"#;
+
+const VALID_RECORD: &str = r#"
+    {
+        id: "record-1",
+        name: "first record",
+        tags: [a, b, "c", d, "e", f, g, "h"],
+        location: (1e0 2e0 3e0),
+        score: 4.5,
+    }
+"#;
+
+const INVALID_RECORD: &str = r#"
+    {
+        id: -1,
+        name: "",
+        tags: [a, 1, "c", 2, "e", 3, g, 4],
+        location: (1e0 "2" 3e0 4e0),
+        score: "high",
+        unexpected: true,
+    }
+"#;
+
+fn load_type(name: &str) -> TypeDefinition {
+    let map_authority = [("sample.isl", SCHEMA)];
+    let authorities: Vec<Box<dyn DocumentAuthority>> =
+        vec![Box::new(MapDocumentAuthority::new(map_authority))];
+    let mut schema_system = SchemaSystem::new(authorities);
+    let schema = schema_system.load_schema("sample.isl").unwrap();
+    schema.get_type(name).unwrap()
+}
+
+fn records(record: &str, count: usize) -> Element {
+    Element::read_one(format!("[{}]", vec![record; count].join(",")).as_bytes()).unwrap()
+}
+
+fn validation_benchmark(c: &mut Criterion) {
+    let record_type = load_type("record");
+    let records_type = load_type("records");
+
+    for (name, record) in [("valid", VALID_RECORD), ("invalid", INVALID_RECORD)] {
+        let value = Element::read_one(record.as_bytes()).unwrap();
+        let mut group = c.benchmark_group(format!("{name}_record"));
+        group.bench_function("validate", |b| {
+            b.iter(|| record_type.validate(black_box(&value)).is_ok())
+        });
+        group.bench_function("is_valid", |b| {
+            b.iter(|| record_type.is_valid(black_box(&value)))
+        });
+        group.finish();
+
+        let value = records(record, 100);
+        let mut group = c.benchmark_group(format!("{name}_records"));
+        group.bench_function("validate", |b| {
+            b.iter(|| records_type.validate(black_box(&value)).is_ok())
+        });
+        group.bench_function("is_valid", |b| {
+            b.iter(|| records_type.is_valid(black_box(&value)))
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, validation_benchmark);
+criterion_main!(benches);
@@ -2,14 +2,15 @@
 extern crate clap;
 use clap::{App, ArgMatches};
 use ion_rs::element::Element;
-use ion_rs::IonWriter;
-use ion_rs::{IonType, TextWriterBuilder};
+use ion_rs::{IonWriter, TextWriterBuilder};
 use ion_schema::authority::{DocumentAuthority, FileSystemDocumentAuthority};
+use ion_schema::isl::WriteToIsl;
 use ion_schema::result::IonSchemaResult;
 use ion_schema::system::SchemaSystem;
+use ion_schema::violation::ValidationReport;
 use std::fs;
+use std::io;
 use std::path::Path;
-use std::str::from_utf8;
 
 fn main() -> IonSchemaResult<()> {
     // load the YAML file which has all the CLI commands information stored in it
@@ -85,34 +86,54 @@ fn validate(command_args: &ArgMatches) -> IonSchemaResult<()> {
     // get the type provided by user from the schema file
     let type_ref = schema.unwrap().get_type(schema_type).unwrap();
 
-    // create a text writer to make the output
-    let mut output = vec![];
-    let mut writer = TextWriterBuilder::pretty().build(&mut output)?;
+    // Extract the violation display options provided by user
+    let color = command_args.is_present("color");
+    // an invalid max depth is reported like the other invalid arguments, exiting with a non-zero status
+    let max_depth = if command_args.is_present("max_depth") {
+        value_t!(command_args, "max_depth", usize).unwrap_or_else(|error| error.exit())
+    } else {
+        usize::MAX
+    };
+
+    // Extract the report format provided by user
+    let format = command_args.value_of("format").unwrap_or("text");
+    if format != "text" {
+        // validate owned_elements according to type_ref and write a machine-readable report
+        let report = ValidationReport::new(
+            schema_type,
+            owned_elements
+                .iter()
+                .map(|owned_element| type_ref.validate(owned_element))
+                .collect(),
+        );
+        if format == "json" {
+            println!("{}", report.to_json());
+        } else {
+            let mut writer = TextWriterBuilder::pretty().build(io::stdout())?;
+            report.write_to(&mut writer)?;
+            writer.flush()?;
+            println!();
+        }
+        return Ok(());
+    }
 
     // validate owned_elements according to type_ref
+    println!("Validation report:");
     for owned_element in owned_elements {
-        // create a validation report with validation result, value, schema and/or violation
-        writer.step_in(IonType::Struct)?;
-        let validation_result = type_ref.validate(&owned_element);
-        writer.set_field_name("result");
-        match validation_result {
-            Ok(_) => {
-                writer.write_string("Valid")?;
-                writer.set_field_name("value");
-                writer.write_string(format!("{owned_element}"))?;
-                writer.set_field_name("schema");
-                writer.write_string(schema_id)?;
-            }
-            Err(_) => {
-                writer.write_string("Invalid")?;
-                writer.set_field_name("violation");
-                writer.write_string(format!("{:#?}", validation_result.unwrap_err()))?;
+        // show the validation result for each value along with its violations
+        match type_ref.validate(&owned_element) {
+            Ok(_) => println!("{owned_element} is valid for type {schema_type}"),
+            Err(violation) => {
+                println!("{owned_element} is invalid for type {schema_type}:");
+                println!(
+                    "{}",
+                    violation
+                        .display()
+                        .with_color(color)
+                        .with_max_depth(max_depth)
+                );
             }
         }
-        writer.step_out()?;
     }
-    drop(writer);
-    println!("Validation report:");
-    println!("{}", from_utf8(&output).unwrap());
     Ok(())
 }
@@ -3,9 +3,16 @@
 //! A [`DocumentAuthority`] is responsible for resolving a particular class of
 //! schema identifiers as per *[Ion Schema spec]*.
 //!
-//! There are two types of a [`DocumentAuthority`] as defined below.
+//! There are six types of a [`DocumentAuthority`] as defined below.
 //! * [`FileSystemDocumentAuthority`] : Attempts to resolve schema ids to files relative to a basePath.
 //! * [`MapDocumentAuthority`] : Attempts to resolve schema ids to ion elements using the map of (id, ion content).
+//! * [`EmbeddedDocumentAuthority`] : Attempts to resolve schema ids to files that are embedded in the binary
+//!   relative to a directory, usually with the `include_schemas!` macro of the `embed` feature.
+//! * [`CachingDocumentAuthority`] : Resolves schema ids with another [`DocumentAuthority`] and caches the resulting ion elements,
+//!   so that they can be reused by other schema systems.
+//! * [`RoutingDocumentAuthority`] : Dispatches schema ids to other [`DocumentAuthority`]s based on their prefix.
+//! * `ArchiveDocumentAuthority` : Attempts to resolve schema ids to entries of a `.zip`, `.tar` or `.tar.gz` archive.
+//!   It is only available with the `archive` feature.
 //!
 //! [Ion Schema spec]: https://amazon-ion.github.io/ion-schema/docs/isl-1-0/spec#schema-authorities
 //!
@@ -60,12 +67,22 @@
 //! ));
 //! ```
 
-use crate::result::{unresolvable_schema_error_raw, IonSchemaResult};
+use crate::limits::{check_schema_size, read_all_elements};
+#[cfg(feature = "archive")]
+use crate::result::limit_exceeded_error_raw;
+use crate::result::{access_denied_error_raw, unresolvable_schema_error_raw, IonSchemaResult};
 use ion_rs::element::Element;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::io::Read;
+#[cfg(feature = "archive")]
+use std::io::Seek;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 
 /// An [`DocumentAuthority`] is responsible for resolving a particular class of
 /// schema identifiers.
@@ -79,37 +96,385 @@ use std::path::{Path, PathBuf};
 /// [Send and Sync]: https://doc.rust-lang.org/nomicon/send-and-sync.html
 pub trait DocumentAuthority: Debug + Send + Sync {
     fn elements(&self, id: &str) -> IonSchemaResult<Vec<Element>>;
+
+    /// Returns the Ion text of the schema with given id, if this authority can provide it.
+    /// The text is only used to find the line and column of the errors found while loading the schema,
+    /// hence authorities that don't have a text representation of their schemas can rely on the default
+    /// implementation which returns `None`.
+    fn source_text(&self, id: &str) -> Option<String> {
+        None
+    }
+
+    /// Returns a fingerprint of the current content of the schema with given id, if this authority can provide it.
+    /// The fingerprint must change whenever the content of the schema changes, it is used to detect the schemas that
+    /// were modified after they were loaded (see [`SchemaSystem::invalidate_modified_schemas`]).
+    /// It is only requested by schema systems that have change detection enabled, and should be cheap to compute
+    /// compared to reading the schema. Authorities whose schemas never change can rely on the default implementation
+    /// which returns `None`.
+    ///
+    /// [`SchemaSystem::invalidate_modified_schemas`]: crate::system::SchemaSystem::invalidate_modified_schemas
+    fn fingerprint(&self, id: &str) -> Option<u64> {
+        None
+    }
+
+    /// Returns the [`Element`]s of the schema with given id, along with its [`fingerprint`](Self::fingerprint) if
+    /// requested by the given [`ReadOptions`] and the size of its Ion content if this authority knows it.
+    /// When the options have a maximum size, an [`IonSchemaError::LimitExceededError`] is returned for a schema whose
+    /// Ion content is larger than that, the size is checked before the content is parsed so that
+    /// [`LoadLimits::max_schema_size`] is enforced before any memory is spent on the elements. Likewise, when the
+    /// options have a maximum nesting depth, the parsing stops with an [`IonSchemaError::LimitExceededError`] at the
+    /// first container nested deeper than that, so that [`LoadLimits::max_nesting_depth`] is enforced before a deeply
+    /// nested value exhausts the stack.
+    ///
+    /// The default implementation requests the fingerprint before the elements, so that a modification made while
+    /// reading the schema is detected later on, and doesn't check the size nor the nesting depth, which is only
+    /// suitable for authorities that don't read the schemas from untrusted Ion content. Authorities that read the Ion
+    /// content of the schemas (e.g. [`FileSystemDocumentAuthority`]) override it to check these limits and to take the
+    /// fingerprint while reading the schema.
+    ///
+    /// [`IonSchemaError::LimitExceededError`]: crate::result::IonSchemaError::LimitExceededError
+    /// [`LoadLimits::max_schema_size`]: crate::limits::LoadLimits::max_schema_size
+    /// [`LoadLimits::max_nesting_depth`]: crate::limits::LoadLimits::max_nesting_depth
+    fn read_elements(&self, id: &str, options: ReadOptions) -> IonSchemaResult<SchemaElements> {
+        let fingerprint = if options.fingerprint() {
+            self.fingerprint(id)
+        } else {
+            None
+        };
+        Ok(SchemaElements::new(self.elements(id)?).with_fingerprint(fingerprint))
+    }
+}
+
+/// Options for reading a schema with [`DocumentAuthority::read_elements`]
+/// ```
+/// use ion_schema::authority::ReadOptions;
+///
+/// // reads schemas of at most 1 MiB along with their fingerprint
+/// let options = ReadOptions::default()
+///     .with_max_size(1024 * 1024)
+///     .with_fingerprint(true);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReadOptions {
+    max_size: Option<usize>,
+    max_nesting_depth: Option<usize>,
+    fingerprint: bool,
+}
+
+impl ReadOptions {
+    /// Sets the maximum size in bytes of the Ion content of the schema
+    pub fn with_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets the maximum nesting depth of the Ion values of the schema,
+    /// see [`LoadLimits::with_max_nesting_depth`](crate::limits::LoadLimits::with_max_nesting_depth)
+    pub fn with_max_nesting_depth(mut self, max_nesting_depth: usize) -> Self {
+        self.max_nesting_depth = Some(max_nesting_depth);
+        self
+    }
+
+    /// Sets whether the fingerprint of the schema is requested
+    pub fn with_fingerprint(mut self, fingerprint: bool) -> Self {
+        self.fingerprint = fingerprint;
+        self
+    }
+
+    /// Returns the maximum size in bytes of the Ion content of the schema, if any
+    pub fn max_size(&self) -> Option<usize> {
+        self.max_size
+    }
+
+    /// Returns the maximum nesting depth of the Ion values of the schema, if any
+    pub fn max_nesting_depth(&self) -> Option<usize> {
+        self.max_nesting_depth
+    }
+
+    /// Returns `true` if the fingerprint of the schema is requested
+    pub fn fingerprint(&self) -> bool {
+        self.fingerprint
+    }
+}
+
+/// The [`Element`]s of a schema returned by [`DocumentAuthority::read_elements`],
+/// along with the fingerprint, the size and the text of the Ion content of the schema when they are known
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaElements {
+    elements: Vec<Element>,
+    fingerprint: Option<u64>,
+    size: Option<usize>,
+    source_text: Option<String>,
+}
+
+impl SchemaElements {
+    pub fn new(elements: Vec<Element>) -> Self {
+        Self {
+            elements,
+            fingerprint: None,
+            size: None,
+            source_text: None,
+        }
+    }
+
+    /// Sets the fingerprint of the schema, see [`DocumentAuthority::fingerprint`]
+    pub fn with_fingerprint(mut self, fingerprint: Option<u64>) -> Self {
+        self.fingerprint = fingerprint;
+        self
+    }
+
+    /// Sets the size in bytes of the Ion content of the schema
+    pub fn with_size(mut self, size: Option<usize>) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the Ion text that the elements were read from, which is used to find the line and column of the errors
+    /// found while loading the schema instead of requesting the [`source_text`](DocumentAuthority::source_text)
+    /// of the schema again
+    pub fn with_source_text(mut self, source_text: Option<String>) -> Self {
+        self.source_text = source_text;
+        self
+    }
+
+    pub fn elements(&self) -> &[Element] {
+        &self.elements
+    }
+
+    pub fn into_elements(self) -> Vec<Element> {
+        self.elements
+    }
+
+    pub fn fingerprint(&self) -> Option<u64> {
+        self.fingerprint
+    }
+
+    /// Returns the size in bytes of the Ion content of the schema, if the authority knows it
+    pub fn size(&self) -> Option<usize> {
+        self.size
+    }
+
+    /// Returns the Ion text that the elements were read from, if the authority provided it
+    pub fn source_text(&self) -> Option<&str> {
+        self.source_text.as_deref()
+    }
+
+    // Returns the elements along with the Ion text that they were read from
+    pub(crate) fn into_elements_and_source_text(self) -> (Vec<Element>, Option<String>) {
+        (self.elements, self.source_text)
+    }
+}
+
+// Parses the elements of the given Ion content up to the maximum nesting depth of the options,
+// after checking its size against the maximum size of the options
+pub(crate) fn read_ion_content(
+    id: &str,
+    ion_content: &[u8],
+    options: ReadOptions,
+) -> IonSchemaResult<SchemaElements> {
+    if let Some(max_size) = options.max_size() {
+        check_schema_size(id, ion_content.len(), max_size)?;
+    }
+    let elements = read_all_elements(id, ion_content, options.max_nesting_depth())?;
+    Ok(SchemaElements::new(elements).with_size(Some(ion_content.len())))
 }
 
 /// An [`DocumentAuthority`] implementation that attempts to resolve schema ids to files
 /// relative to a basePath.
+///
+/// By default, schema ids are simply joined to the base path, hence an id like `../other/sample.isl` or an absolute path
+/// refers to a file outside of the base directory. For schemas whose imports are not fully trusted, a sandboxed
+/// authority (see [`FileSystemDocumentAuthority::sandboxed`]) only loads files that are inside the base directory,
+/// and the loaded files can be restricted to some extensions and to a maximum size with
+/// [`with_allowed_extensions`](FileSystemDocumentAuthority::with_allowed_extensions) and
+/// [`with_max_file_size`](FileSystemDocumentAuthority::with_max_file_size).
+/// The schemas that are rejected by these checks are reported as [`IonSchemaError::AccessDeniedError`]s.
+///
+/// [`IonSchemaError::AccessDeniedError`]: crate::result::IonSchemaError::AccessDeniedError
+///
+/// ```
+/// use ion_schema::authority::FileSystemDocumentAuthority;
+/// use std::path::Path;
+///
+/// # fn main() -> ion_schema::result::IonSchemaResult<()> {
+/// # let base_path = std::env::temp_dir();
+/// let file_system_document_authority = FileSystemDocumentAuthority::sandboxed(&base_path)?
+///     .with_allowed_extensions(["isl"])
+///     .with_max_file_size(1024 * 1024);
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Debug, Clone)]
 pub struct FileSystemDocumentAuthority {
     base_path: PathBuf,
+    sandboxed: bool,
+    allowed_extensions: Option<Vec<String>>,
+    max_file_size: Option<u64>,
 }
 
 impl FileSystemDocumentAuthority {
     pub fn new(base_path: &Path) -> Self {
         Self {
             base_path: base_path.to_path_buf(),
+            sandboxed: false,
+            allowed_extensions: None,
+            max_file_size: None,
         }
     }
 
+    /// Creates a [`FileSystemDocumentAuthority`] that only loads the files that are inside given base directory.
+    /// Schema ids that are absolute paths or that escape the base directory with `..` components are rejected,
+    /// as well as the files that are symbolic links to files outside of the base directory.
+    ///
+    /// Returns an error if the base path doesn't exist, since it is canonicalized to check the paths of the files.
+    pub fn sandboxed(base_path: &Path) -> IonSchemaResult<Self> {
+        Ok(Self {
+            base_path: fs::canonicalize(base_path)?,
+            sandboxed: true,
+            allowed_extensions: None,
+            max_file_size: None,
+        })
+    }
+
+    /// Restricts the loaded files to the files with one of given extensions (without the leading `.`, e.g. `isl`)
+    pub fn with_allowed_extensions<'a, I: IntoIterator<Item = &'a str>>(
+        mut self,
+        extensions: I,
+    ) -> Self {
+        self.allowed_extensions = Some(extensions.into_iter().map(|e| e.to_owned()).collect());
+        self
+    }
+
+    /// Restricts the loaded files to the files whose size is at most given number of bytes
+    pub fn with_max_file_size(mut self, max_file_size: u64) -> Self {
+        self.max_file_size = Some(max_file_size);
+        self
+    }
+
     /// Returns the base path for this [`FileSystemDocumentAuthority`]
     pub fn base_path(&self) -> &Path {
         self.base_path.as_path()
     }
+
+    /// Returns `true` if this [`FileSystemDocumentAuthority`] only loads the files that are inside its base directory
+    pub fn is_sandboxed(&self) -> bool {
+        self.sandboxed
+    }
+
+    // Returns the path of the file for given schema id, after checking that this authority is allowed to load it
+    fn resolve_path(&self, id: &str) -> IonSchemaResult<PathBuf> {
+        let relative_path = Path::new(id);
+        if let Some(allowed_extensions) = &self.allowed_extensions {
+            let extension = relative_path.extension().and_then(|e| e.to_str());
+            if !allowed_extensions
+                .iter()
+                .any(|allowed| Some(allowed.as_str()) == extension)
+            {
+                return Err(access_denied_error_raw(format!(
+                    "schema id {id} doesn't have one of the allowed extensions: {allowed_extensions:?}"
+                )));
+            }
+        }
+        if !self.sandboxed {
+            return Ok(self.base_path.join(relative_path));
+        }
+        // the id is checked before accessing the file system, so that nothing is revealed about files
+        // outside of the base directory (not even whether they exist)
+        if normalize_id(id).is_none() {
+            return Err(access_denied_error_raw(format!(
+                "schema id {id} refers to a file outside of the base directory {}",
+                self.base_path.display()
+            )));
+        }
+        let path = fs::canonicalize(self.base_path.join(relative_path))?;
+        if !path.starts_with(&self.base_path) {
+            return Err(access_denied_error_raw(format!(
+                "schema id {id} is a link to a file outside of the base directory {}",
+                self.base_path.display()
+            )));
+        }
+        Ok(path)
+    }
+
+    // Returns the content of the file for given schema id along with its fingerprint if requested,
+    // after checking that this authority is allowed to load it and that it is at most the maximum size of the options
+    fn read(&self, id: &str, options: ReadOptions) -> IonSchemaResult<(Vec<u8>, Option<u64>)> {
+        let mut file = fs::File::open(self.resolve_path(id)?)?;
+        // the fingerprint is taken before the content is read, so that a modification made while reading the file
+        // is detected later on
+        let fingerprint = if options.fingerprint() {
+            file.metadata().ok().and_then(|m| metadata_fingerprint(&m))
+        } else {
+            None
+        };
+        let mut content = vec![];
+        let max_size = options.max_size().map(|max_size| max_size as u64);
+        match self.max_file_size.into_iter().chain(max_size).min() {
+            None => {
+                file.read_to_end(&mut content)?;
+            }
+            Some(limit) => {
+                // the content is read up to the limit rather than relying on the size in the file metadata,
+                // which may change while the file is read
+                file.take(limit.saturating_add(1))
+                    .read_to_end(&mut content)?;
+            }
+        }
+        if let Some(max_file_size) = self.max_file_size {
+            if content.len() as u64 > max_file_size {
+                return Err(access_denied_error_raw(format!(
+                    "schema id {id} refers to a file larger than the maximum size of {max_file_size} bytes"
+                )));
+            }
+        }
+        if let Some(max_size) = max_size {
+            check_schema_size(id, content.len(), max_size as usize)?;
+        }
+        Ok((content, fingerprint))
+    }
+}
+
+// Returns a hash of the modification time and the size of a file
+fn metadata_fingerprint(metadata: &fs::Metadata) -> Option<u64> {
+    let mut hasher = DefaultHasher::new();
+    metadata.modified().ok()?.hash(&mut hasher);
+    metadata.len().hash(&mut hasher);
+    Some(hasher.finish())
 }
 
 impl DocumentAuthority for FileSystemDocumentAuthority {
     /// Returns a vector of [`Element`]s based on given schema id
     fn elements(&self, id: &str) -> IonSchemaResult<Vec<Element>> {
-        let absolute_path = self.base_path().join(id);
-        // if absolute_path exists for the given id then load schema with file contents
-        let ion_content = fs::read(absolute_path)?;
+        // if the file exists for the given id and it can be loaded then load schema with file contents
+        let (ion_content, _) = self.read(id, ReadOptions::default())?;
         let schema_content = Element::read_all(ion_content)?;
         Ok(schema_content)
     }
+
+    /// Returns the content of the schema file for given schema id, if it is a valid UTF-8 text file
+    fn source_text(&self, id: &str) -> Option<String> {
+        String::from_utf8(self.read(id, ReadOptions::default()).ok()?.0).ok()
+    }
+
+    /// Returns a hash of the modification time and the size of the schema file for given schema id,
+    /// which doesn't require reading the file. A modification that keeps both the size and the modification time
+    /// of the file (e.g. restoring an older version of the file along with its modification time) is not detected.
+    fn fingerprint(&self, id: &str) -> Option<u64> {
+        metadata_fingerprint(&fs::metadata(self.resolve_path(id).ok()?).ok()?)
+    }
+
+    /// Returns the elements of the schema file for given schema id, the file is read up to the maximum size of the
+    /// options and parsed up to the maximum nesting depth of the options, and its fingerprint is taken from the
+    /// metadata of the opened file. The content of the file is kept as source text if it is valid UTF-8 text.
+    fn read_elements(&self, id: &str, options: ReadOptions) -> IonSchemaResult<SchemaElements> {
+        let (ion_content, fingerprint) = self.read(id, options)?;
+        let size = ion_content.len();
+        let elements = read_all_elements(id, &ion_content, options.max_nesting_depth())?;
+        Ok(SchemaElements::new(elements)
+            .with_fingerprint(fingerprint)
+            .with_size(Some(size))
+            .with_source_text(String::from_utf8(ion_content).ok()))
+    }
 }
 
 /// An [`DocumentAuthority`] implementation that attempts to resolve schema ids to ion elements using the map.
@@ -127,18 +492,764 @@ impl MapDocumentAuthority {
                 .collect(),
         }
     }
+
+    fn ion_content(&self, id: &str) -> IonSchemaResult<&str> {
+        // a missing schema is reported like for the file system, so that the schema system moves on to the next authority
+        let ion_content = self
+            .ion_content_by_id
+            .get(id)
+            .map(String::as_str)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("MapDocumentAuthority does not contain schema with id: {id}"),
+                )
+            })?;
+        Ok(ion_content)
+    }
 }
 
 impl DocumentAuthority for MapDocumentAuthority {
     /// Returns a vector of [`Element`]s based on given schema id using ion_content_by_id map
     fn elements(&self, id: &str) -> IonSchemaResult<Vec<Element>> {
         // if ion content exists for the given id  in the map then return ion content as Elements
-        let ion_content = self.ion_content_by_id.get(id).ok_or_else(|| {
+        let schema_content = Element::read_all(self.ion_content(id)?.as_bytes())?;
+        Ok(schema_content)
+    }
+
+    /// Returns a vector of [`Element`]s based on given schema id, if its ion content is at most the maximum size of
+    /// the options
+    fn read_elements(&self, id: &str, options: ReadOptions) -> IonSchemaResult<SchemaElements> {
+        read_ion_content(id, self.ion_content(id)?.as_bytes(), options)
+    }
+
+    /// Returns the ion content for given schema id from ion_content_by_id map
+    fn source_text(&self, id: &str) -> Option<String> {
+        self.ion_content_by_id.get(id).cloned()
+    }
+}
+
+/// An [`DocumentAuthority`] implementation that attempts to resolve schema ids to files that are embedded in the binary,
+/// where the id of a schema is the path of its file relative to the embedded directory (e.g. `numbers/positive.isl`).
+/// Like for [`FileSystemDocumentAuthority`], the ids may contain `.` and `..` path components.
+///
+/// The files are usually embedded with the `include_schemas!` macro of the `embed` feature:
+/// ```ignore
+/// use ion_schema::authority::EmbeddedDocumentAuthority;
+/// use ion_schema::include_schemas;
+///
+/// // embeds all the `.isl` files of the `schemas` directory of the crate
+/// let embedded_document_authority: EmbeddedDocumentAuthority = include_schemas!("schemas");
+/// ```
+#[derive(Debug, Clone)]
+pub struct EmbeddedDocumentAuthority {
+    ion_content_by_id: HashMap<String, &'static [u8]>, // This map represents (relative path, file content) of the embedded files
+}
+
+impl EmbeddedDocumentAuthority {
+    /// Creates an [`EmbeddedDocumentAuthority`] from the (relative path, file content) of the embedded files,
+    /// where the paths use `/` as separator.
+    pub fn new<'a, I: IntoIterator<Item = (&'a str, &'static [u8])>>(files: I) -> Self {
+        Self {
+            ion_content_by_id: files
+                .into_iter()
+                .filter_map(|(path, ion_content)| Some((normalize_id(path)?, ion_content)))
+                .collect(),
+        }
+    }
+
+    /// Returns the ids of the embedded schemas, in no particular order
+    pub fn schema_ids(&self) -> impl Iterator<Item = &str> {
+        self.ion_content_by_id.keys().map(String::as_str)
+    }
+
+    fn ion_content(&self, id: &str) -> IonSchemaResult<&'static [u8]> {
+        // a missing file is reported like for the file system, so that the schema system moves on to the next authority
+        let ion_content = normalize_id(id)
+            .and_then(|id| self.ion_content_by_id.get(&id))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("EmbeddedDocumentAuthority does not contain schema with id: {id}"),
+                )
+            })?;
+        Ok(ion_content)
+    }
+}
+
+// Returns given relative path with `/` separators and without any `.` or `..` component,
+// or `None` for paths that escape the directory they are relative to
+fn normalize_id(id: &str) -> Option<String> {
+    let mut names = vec![];
+    for component in Path::new(id).components() {
+        match component {
+            Component::Normal(name) => names.push(name.to_str()?),
+            Component::CurDir => {}
+            Component::ParentDir => {
+                names.pop()?;
+            }
+            Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+    Some(names.join("/"))
+}
+
+impl DocumentAuthority for EmbeddedDocumentAuthority {
+    /// Returns a vector of [`Element`]s based on given schema id using the embedded files
+    fn elements(&self, id: &str) -> IonSchemaResult<Vec<Element>> {
+        let schema_content = Element::read_all(self.ion_content(id)?)?;
+        Ok(schema_content)
+    }
+
+    /// Returns a vector of [`Element`]s based on given schema id, if its embedded file is at most the maximum size
+    /// of the options
+    fn read_elements(&self, id: &str, options: ReadOptions) -> IonSchemaResult<SchemaElements> {
+        read_ion_content(id, self.ion_content(id)?, options)
+    }
+
+    /// Returns the content of the embedded file for given schema id, if it is valid UTF-8 text
+    fn source_text(&self, id: &str) -> Option<String> {
+        let ion_content = self.ion_content_by_id.get(&normalize_id(id)?)?;
+        String::from_utf8(ion_content.to_vec()).ok()
+    }
+}
+
+/// An [`DocumentAuthority`] implementation that resolves schema ids with another [`DocumentAuthority`] and caches
+/// the resulting ion elements, so that a schema is only read and parsed once.
+///
+/// The clones of a [`CachingDocumentAuthority`] share their cache, hence a single cache can be used by many
+/// schema systems:
+/// ```
+/// use ion_schema::authority::{CachingDocumentAuthority, FileSystemDocumentAuthority};
+/// use ion_schema::system::SchemaSystem;
+/// use std::path::Path;
+/// use std::sync::Arc;
+///
+/// // keeps the elements of at most 100 schemas
+/// let authority = CachingDocumentAuthority::with_capacity(
+///     Arc::new(FileSystemDocumentAuthority::new(Path::new("sample_schemas"))),
+///     100,
+/// );
+/// let schema_system = SchemaSystem::new(vec![Box::new(authority.clone())]);
+/// let other_schema_system = SchemaSystem::new(vec![Box::new(authority)]);
+/// ```
+///
+/// Errors are not cached. If the underlying authority provides a [`fingerprint`](DocumentAuthority::fingerprint)
+/// for a schema, its cached elements are dropped when [`SchemaSystem::invalidate_modified_schemas`] finds that
+/// the schema was modified, and they are read again when the schema is read with its fingerprint (which is what
+/// the schema systems do when change detection is enabled) and the fingerprint of the underlying authority doesn't
+/// match the cached one anymore.
+/// The size of the Ion content of each schema is cached along with its elements, so that a schema cached by a
+/// schema system without any [`LoadLimits::max_schema_size`] is still rejected by the schema systems that have one.
+///
+/// [`LoadLimits::max_schema_size`]: crate::limits::LoadLimits::max_schema_size
+///
+/// [`SchemaSystem::invalidate_modified_schemas`]: crate::system::SchemaSystem::invalidate_modified_schemas
+#[derive(Debug, Clone)]
+pub struct CachingDocumentAuthority {
+    authority: Arc<dyn DocumentAuthority>,
+    cache: Arc<Mutex<ElementsCache>>,
+}
+
+// The cached entries are linked from the most recently used to the least recently used one by their indexes,
+// so that both an access and an eviction take constant time.
+#[derive(Debug)]
+struct ElementsCache {
+    capacity: Option<usize>,
+    indexes_by_id: HashMap<String, usize>,
+    entries: Vec<CachedElements>,
+    most_recently_used: Option<usize>,
+    least_recently_used: Option<usize>,
+}
+
+#[derive(Debug)]
+struct CachedElements {
+    id: String,
+    schema_elements: SchemaElements,
+    more_recently_used: Option<usize>,
+    less_recently_used: Option<usize>,
+}
+
+impl CachingDocumentAuthority {
+    /// Creates a [`CachingDocumentAuthority`] without any limit on the number of cached schemas
+    pub fn new(authority: Arc<dyn DocumentAuthority>) -> Self {
+        Self::with_cache(authority, None)
+    }
+
+    /// Creates a [`CachingDocumentAuthority`] that caches the elements of at most `capacity` schemas,
+    /// the least recently used schemas are evicted from the cache to make room for new ones.
+    pub fn with_capacity(authority: Arc<dyn DocumentAuthority>, capacity: usize) -> Self {
+        Self::with_cache(authority, Some(capacity))
+    }
+
+    fn with_cache(authority: Arc<dyn DocumentAuthority>, capacity: Option<usize>) -> Self {
+        Self {
+            authority,
+            cache: Arc::new(Mutex::new(ElementsCache {
+                capacity,
+                indexes_by_id: HashMap::new(),
+                entries: vec![],
+                most_recently_used: None,
+                least_recently_used: None,
+            })),
+        }
+    }
+
+    /// Returns the underlying [`DocumentAuthority`]
+    pub fn authority(&self) -> &Arc<dyn DocumentAuthority> {
+        &self.authority
+    }
+
+    /// Returns the number of schemas whose elements are cached
+    pub fn len(&self) -> usize {
+        self.cache().entries.len()
+    }
+
+    /// Returns `true` if no schema elements are cached
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes the cached elements of the schema with given id,
+    /// returns `true` if the elements of that schema were cached
+    pub fn invalidate(&self, id: &str) -> bool {
+        self.cache().remove(id)
+    }
+
+    /// Removes all the cached elements
+    pub fn clear(&self) {
+        self.cache().clear()
+    }
+
+    // the cache is always left consistent while it is locked, hence it is still usable after a panic poisoned the lock
+    fn cache(&self) -> MutexGuard<'_, ElementsCache> {
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl ElementsCache {
+    fn get(&mut self, id: &str) -> Option<SchemaElements> {
+        let index = *self.indexes_by_id.get(id)?;
+        self.unlink(index);
+        self.link_as_most_recently_used(index);
+        Some(self.entries[index].schema_elements.clone())
+    }
+
+    fn insert(&mut self, id: &str, schema_elements: SchemaElements) {
+        if self.capacity == Some(0) {
+            return;
+        }
+        self.remove(id);
+        let index = self.entries.len();
+        self.entries.push(CachedElements {
+            id: id.to_owned(),
+            schema_elements,
+            more_recently_used: None,
+            less_recently_used: None,
+        });
+        self.indexes_by_id.insert(id.to_owned(), index);
+        self.link_as_most_recently_used(index);
+        if self
+            .capacity
+            .is_some_and(|capacity| self.entries.len() > capacity)
+        {
+            if let Some(least_recently_used) = self.least_recently_used {
+                self.remove_at(least_recently_used);
+            }
+        }
+    }
+
+    fn fingerprint(&self, id: &str) -> Option<Option<u64>> {
+        let index = *self.indexes_by_id.get(id)?;
+        Some(self.entries[index].schema_elements.fingerprint())
+    }
+
+    fn remove(&mut self, id: &str) -> bool {
+        match self.indexes_by_id.get(id) {
+            Some(&index) => {
+                self.remove_at(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn clear(&mut self) {
+        self.indexes_by_id.clear();
+        self.entries.clear();
+        self.most_recently_used = None;
+        self.least_recently_used = None;
+    }
+
+    // Removes the entry at given index by moving the last entry to that index
+    fn remove_at(&mut self, index: usize) {
+        self.unlink(index);
+        let removed = self.entries.swap_remove(index);
+        self.indexes_by_id.remove(&removed.id);
+        if index == self.entries.len() {
+            return;
+        }
+        // the links to the moved entry are updated to its new index
+        let moved = &self.entries[index];
+        let (more_recently_used, less_recently_used) =
+            (moved.more_recently_used, moved.less_recently_used);
+        self.indexes_by_id.insert(moved.id.to_owned(), index);
+        match more_recently_used {
+            Some(more_recently_used) => {
+                self.entries[more_recently_used].less_recently_used = Some(index)
+            }
+            None => self.most_recently_used = Some(index),
+        }
+        match less_recently_used {
+            Some(less_recently_used) => {
+                self.entries[less_recently_used].more_recently_used = Some(index)
+            }
+            None => self.least_recently_used = Some(index),
+        }
+    }
+
+    fn unlink(&mut self, index: usize) {
+        let entry = &mut self.entries[index];
+        let (more_recently_used, less_recently_used) = (
+            entry.more_recently_used.take(),
+            entry.less_recently_used.take(),
+        );
+        match more_recently_used {
+            Some(more_recently_used) => {
+                self.entries[more_recently_used].less_recently_used = less_recently_used
+            }
+            None => self.most_recently_used = less_recently_used,
+        }
+        match less_recently_used {
+            Some(less_recently_used) => {
+                self.entries[less_recently_used].more_recently_used = more_recently_used
+            }
+            None => self.least_recently_used = more_recently_used,
+        }
+    }
+
+    fn link_as_most_recently_used(&mut self, index: usize) {
+        self.entries[index].less_recently_used = self.most_recently_used;
+        match self.most_recently_used {
+            Some(most_recently_used) => {
+                self.entries[most_recently_used].more_recently_used = Some(index)
+            }
+            None => self.least_recently_used = Some(index),
+        }
+        self.most_recently_used = Some(index);
+    }
+}
+
+impl DocumentAuthority for CachingDocumentAuthority {
+    /// Returns the cached vector of [`Element`]s for given schema id,
+    /// or resolves it with the underlying authority and caches it
+    fn elements(&self, id: &str) -> IonSchemaResult<Vec<Element>> {
+        Ok(self
+            .read_elements(id, ReadOptions::default())?
+            .into_elements())
+    }
+
+    /// Returns the source text of the schema with given id from the underlying authority
+    fn source_text(&self, id: &str) -> Option<String> {
+        self.authority.source_text(id)
+    }
+
+    /// Returns the fingerprint of the schema with given id from the underlying authority,
+    /// and removes the cached elements of that schema if they have a different fingerprint
+    fn fingerprint(&self, id: &str) -> Option<u64> {
+        let fingerprint = self.authority.fingerprint(id);
+        let mut cache = self.cache();
+        if cache
+            .fingerprint(id)
+            .is_some_and(|cached_fingerprint| cached_fingerprint != fingerprint)
+        {
+            cache.remove(id);
+        }
+        fingerprint
+    }
+
+    /// Returns the cached elements for given schema id, or reads them with the underlying authority and caches them.
+    /// When the fingerprint is requested, the cached elements are only returned if the underlying authority still
+    /// has the same fingerprint for the schema. When a maximum size is requested, the cached size of the schema is
+    /// checked against it, or the schema is read again if the underlying authority didn't provide its size.
+    fn read_elements(&self, id: &str, options: ReadOptions) -> IonSchemaResult<SchemaElements> {
+        let cached = self.cache().get(id);
+        if let Some(schema_elements) = cached {
+            let is_modified = options.fingerprint()
+                && self.authority.fingerprint(id) != schema_elements.fingerprint();
+            match (is_modified, options.max_size(), schema_elements.size()) {
+                (true, _, _) | (false, Some(_), None) => {}
+                (false, Some(max_size), Some(size)) => {
+                    check_schema_size(id, size, max_size)?;
+                    return Ok(schema_elements);
+                }
+                (false, None, _) => return Ok(schema_elements),
+            }
+        }
+        // the lock is not held while the schema is read, a concurrent read of the same schema only wastes some work
+        let schema_elements = self.authority.read_elements(id, options)?;
+        // the source text is only needed for the schema that was just read, hence it is not cached
+        self.cache()
+            .insert(id, schema_elements.clone().with_source_text(None));
+        Ok(schema_elements)
+    }
+}
+
+/// An [`DocumentAuthority`] implementation that dispatches schema ids to other [`DocumentAuthority`]s based on their prefix,
+/// e.g. to resolve the ids that start with `com.acme/` and `vendor/` with different authorities.
+///
+/// The prefix of a route is replaced by the replacement of that route (which is empty by default)
+/// before the id is passed to the authority of the route. When several routes match an id, the one with
+/// the longest prefix is used. An id that matches no route is reported as an unresolvable schema,
+/// instead of being silently passed to the next authority of the schema system.
+///
+/// ```
+/// use ion_schema::authority::{FileSystemDocumentAuthority, MapDocumentAuthority, RoutingDocumentAuthority};
+/// use ion_schema::system::SchemaSystem;
+/// use std::path::Path;
+/// use std::sync::Arc;
+///
+/// let authority = RoutingDocumentAuthority::new()
+///     // resolves `com.acme/sample.isl` to the file `acme/schemas/sample.isl`
+///     .with_rewrite(
+///         "com.acme/",
+///         "schemas/",
+///         Arc::new(FileSystemDocumentAuthority::new(Path::new("acme"))),
+///     )
+///     // resolves `vendor/sample.isl` to `sample.isl` in the map
+///     .with_route(
+///         "vendor/",
+///         Arc::new(MapDocumentAuthority::new([("sample.isl", "type::{ name: my_type, type: int }")])),
+///     );
+/// let mut schema_system = SchemaSystem::new(vec![Box::new(authority)]);
+///
+/// assert!(schema_system.load_schema("vendor/sample.isl").is_ok());
+/// assert!(schema_system.load_schema("sample.isl").is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RoutingDocumentAuthority {
+    routes: Vec<Route>,
+}
+
+#[derive(Debug, Clone)]
+struct Route {
+    prefix: String,
+    replacement: String,
+    authority: Arc<dyn DocumentAuthority>,
+}
+
+impl RoutingDocumentAuthority {
+    /// Creates a [`RoutingDocumentAuthority`] without any route
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a route that strips given prefix from the ids that start with it
+    /// and resolves them with given authority
+    pub fn with_route(self, prefix: &str, authority: Arc<dyn DocumentAuthority>) -> Self {
+        self.with_rewrite(prefix, "", authority)
+    }
+
+    /// Adds a route that replaces given prefix by given replacement in the ids that start with it
+    /// and resolves them with given authority
+    pub fn with_rewrite(
+        mut self,
+        prefix: &str,
+        replacement: &str,
+        authority: Arc<dyn DocumentAuthority>,
+    ) -> Self {
+        // a route replaces any existing route with the same prefix
+        self.routes.retain(|route| route.prefix != prefix);
+        self.routes.push(Route {
+            prefix: prefix.to_owned(),
+            replacement: replacement.to_owned(),
+            authority,
+        });
+        self
+    }
+
+    /// Returns the prefixes of the routes, in the order they were added
+    pub fn prefixes(&self) -> impl Iterator<Item = &str> {
+        self.routes.iter().map(|route| route.prefix.as_str())
+    }
+
+    // Returns the authority of the route with the longest prefix that matches given id and the rewritten id
+    fn route(&self, id: &str) -> Option<(&dyn DocumentAuthority, String)> {
+        self.routes
+            .iter()
+            .filter(|route| id.starts_with(&route.prefix))
+            .max_by_key(|route| route.prefix.len())
+            .map(|route| {
+                (
+                    route.authority.as_ref(),
+                    format!("{}{}", route.replacement, &id[route.prefix.len()..]),
+                )
+            })
+    }
+
+    // Returns the result of `route`, or an error if no route matches given id
+    fn route_or_error(&self, id: &str) -> IonSchemaResult<(&dyn DocumentAuthority, String)> {
+        self.route(id).ok_or_else(|| {
+            let prefixes: Vec<&str> = self.prefixes().collect();
             unresolvable_schema_error_raw(format!(
-                "MapDocumentAuthority does not contain schema with id: {id}"
+                "RoutingDocumentAuthority has no route for schema id: {id}, expected an id starting with one of {prefixes:?}"
             ))
-        })?;
-        let schema_content = Element::read_all(ion_content.as_bytes())?;
+        })
+    }
+}
+
+impl DocumentAuthority for RoutingDocumentAuthority {
+    /// Returns a vector of [`Element`]s based on given schema id using the authority of the matching route
+    fn elements(&self, id: &str) -> IonSchemaResult<Vec<Element>> {
+        let (authority, routed_id) = self.route_or_error(id)?;
+        authority.elements(&routed_id)
+    }
+
+    /// Returns the source text of the schema with given id from the authority of the matching route
+    fn source_text(&self, id: &str) -> Option<String> {
+        let (authority, routed_id) = self.route(id)?;
+        authority.source_text(&routed_id)
+    }
+
+    /// Returns the fingerprint of the schema with given id from the authority of the matching route
+    fn fingerprint(&self, id: &str) -> Option<u64> {
+        let (authority, routed_id) = self.route(id)?;
+        authority.fingerprint(&routed_id)
+    }
+
+    /// Reads the schema with given id with the authority of the matching route
+    fn read_elements(&self, id: &str, options: ReadOptions) -> IonSchemaResult<SchemaElements> {
+        let (authority, routed_id) = self.route_or_error(id)?;
+        authority.read_elements(&routed_id, options)
+    }
+}
+
+/// An [`DocumentAuthority`] implementation that attempts to resolve schema ids to the entries of a `.zip`, `.tar`
+/// or `.tar.gz` archive, where the id of a schema is the path of its entry relative to the root of the archive
+/// (e.g. `schemas/sample.isl`). Like for [`FileSystemDocumentAuthority`], the ids may contain `.` and `..` path components.
+///
+/// The archive is read once when the authority is created, hence later modifications of the archive file
+/// are not visible to the authority. The size of the entries that are read is bounded by [`ArchiveLimits`],
+/// regardless of the sizes recorded in the archive, so that a small archive can't expand into a huge amount of memory.
+#[cfg(feature = "archive")]
+#[derive(Debug, Clone)]
+pub struct ArchiveDocumentAuthority {
+    ion_content_by_id: HashMap<String, Vec<u8>>, // This map represents (entry path, entry content) of the archive
+}
+
+/// Represents the limits on the decompressed size of the entries read by an [`ArchiveDocumentAuthority`].
+/// By default, an entry can be at most 16 MiB and all the entries together at most 256 MiB.
+#[cfg(feature = "archive")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArchiveLimits {
+    max_entry_size: u64,
+    max_total_size: u64,
+}
+
+#[cfg(feature = "archive")]
+impl Default for ArchiveLimits {
+    fn default() -> Self {
+        Self {
+            max_entry_size: 16 * 1024 * 1024,
+            max_total_size: 256 * 1024 * 1024,
+        }
+    }
+}
+
+#[cfg(feature = "archive")]
+impl ArchiveLimits {
+    /// Limits the decompressed size in bytes of each entry of the archive
+    pub fn with_max_entry_size(mut self, max_entry_size: u64) -> Self {
+        self.max_entry_size = max_entry_size;
+        self
+    }
+
+    /// Limits the decompressed size in bytes of all the entries of the archive together
+    pub fn with_max_total_size(mut self, max_total_size: u64) -> Self {
+        self.max_total_size = max_total_size;
+        self
+    }
+
+    /// Returns the maximum decompressed size of each entry of the archive
+    pub fn max_entry_size(&self) -> u64 {
+        self.max_entry_size
+    }
+
+    /// Returns the maximum decompressed size of all the entries of the archive together
+    pub fn max_total_size(&self) -> u64 {
+        self.max_total_size
+    }
+
+    // Reads the content of given entry, `total_size` is the size of the entries that were read so far.
+    // The content is read up to the limits rather than relying on the size recorded in the archive,
+    // which may not match the actual size of the entry.
+    fn read_entry<R: Read>(
+        &self,
+        entry: R,
+        id: &str,
+        total_size: &mut u64,
+    ) -> IonSchemaResult<Vec<u8>> {
+        let remaining_size = self.max_total_size.saturating_sub(*total_size);
+        let limit = self.max_entry_size.min(remaining_size);
+        let mut ion_content = vec![];
+        entry
+            .take(limit.saturating_add(1))
+            .read_to_end(&mut ion_content)?;
+        let size = ion_content.len() as u64;
+        if size > self.max_entry_size {
+            return Err(limit_exceeded_error_raw(format!(
+                "archive entry {id} exceeds the maximum entry size of {} bytes",
+                self.max_entry_size
+            )));
+        }
+        if size > remaining_size {
+            return Err(limit_exceeded_error_raw(format!(
+                "archive entries exceed the maximum total size of {} bytes",
+                self.max_total_size
+            )));
+        }
+        *total_size += size;
+        Ok(ion_content)
+    }
+}
+
+#[cfg(feature = "archive")]
+impl ArchiveDocumentAuthority {
+    /// Reads the archive at given path, whose format is determined by its extension:
+    /// `.zip`, `.tar`, or `.tar.gz` and `.tgz` for gzip compressed tarballs.
+    pub fn new(archive_path: &Path) -> IonSchemaResult<Self> {
+        Self::new_with_limits(archive_path, ArchiveLimits::default())
+    }
+
+    /// Reads the archive at given path like [`new`](Self::new), with given limits on the size of its entries
+    pub fn new_with_limits(archive_path: &Path, limits: ArchiveLimits) -> IonSchemaResult<Self> {
+        let file_name = archive_path
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        if file_name.ends_with(".zip") {
+            Self::from_zip_with_limits(fs::File::open(archive_path)?, limits)
+        } else if file_name.ends_with(".tar") {
+            Self::from_tar_with_limits(fs::File::open(archive_path)?, limits)
+        } else if file_name.ends_with(".tar.gz") || file_name.ends_with(".tgz") {
+            Self::from_tar_with_limits(
+                flate2::read::GzDecoder::new(fs::File::open(archive_path)?),
+                limits,
+            )
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "unsupported archive format for {}, expected a .zip, .tar, .tar.gz or .tgz file",
+                    archive_path.display()
+                ),
+            )
+            .into())
+        }
+    }
+
+    /// Reads a zip archive from given reader
+    pub fn from_zip<R: Read + Seek>(reader: R) -> IonSchemaResult<Self> {
+        Self::from_zip_with_limits(reader, ArchiveLimits::default())
+    }
+
+    /// Reads a zip archive from given reader, with given limits on the size of its entries
+    pub fn from_zip_with_limits<R: Read + Seek>(
+        reader: R,
+        limits: ArchiveLimits,
+    ) -> IonSchemaResult<Self> {
+        let mut archive = zip::ZipArchive::new(reader).map_err(zip_error)?;
+        let mut ion_content_by_id = HashMap::new();
+        let mut total_size = 0;
+        for index in 0..archive.len() {
+            let mut entry = archive.by_index(index).map_err(zip_error)?;
+            // entries whose path escapes the root of the archive can't be referenced by a schema id
+            let id = match entry
+                .enclosed_name()
+                .and_then(|path| normalize_id(path.to_str()?))
+            {
+                Some(id) if entry.is_file() => id,
+                _ => continue,
+            };
+            let ion_content = limits.read_entry(&mut entry, &id, &mut total_size)?;
+            ion_content_by_id.insert(id, ion_content);
+        }
+        Ok(Self { ion_content_by_id })
+    }
+
+    /// Reads an uncompressed tar archive from given reader,
+    /// compressed tarballs can be read by wrapping the reader in a decoder.
+    pub fn from_tar<R: Read>(reader: R) -> IonSchemaResult<Self> {
+        Self::from_tar_with_limits(reader, ArchiveLimits::default())
+    }
+
+    /// Reads an uncompressed tar archive from given reader, with given limits on the size of its entries
+    pub fn from_tar_with_limits<R: Read>(
+        reader: R,
+        limits: ArchiveLimits,
+    ) -> IonSchemaResult<Self> {
+        let mut archive = tar::Archive::new(reader);
+        let mut ion_content_by_id = HashMap::new();
+        let mut total_size = 0;
+        for entry in archive.entries()? {
+            let mut entry = entry?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+            let id = match entry.path()?.to_str().and_then(normalize_id) {
+                Some(id) => id,
+                None => continue,
+            };
+            let ion_content = limits.read_entry(&mut entry, &id, &mut total_size)?;
+            ion_content_by_id.insert(id, ion_content);
+        }
+        Ok(Self { ion_content_by_id })
+    }
+
+    /// Returns the ids of the schemas contained in the archive, in no particular order
+    pub fn schema_ids(&self) -> impl Iterator<Item = &str> {
+        self.ion_content_by_id.keys().map(String::as_str)
+    }
+
+    fn ion_content(&self, id: &str) -> IonSchemaResult<&[u8]> {
+        // a missing entry is reported like a missing file, so that the schema system moves on to the next authority
+        let ion_content = normalize_id(id)
+            .and_then(|id| self.ion_content_by_id.get(&id))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("ArchiveDocumentAuthority does not contain schema with id: {id}"),
+                )
+            })?;
+        Ok(ion_content)
+    }
+}
+
+#[cfg(feature = "archive")]
+fn zip_error(error: zip::result::ZipError) -> io::Error {
+    match error {
+        zip::result::ZipError::Io(error) => error,
+        error => io::Error::new(io::ErrorKind::InvalidData, error),
+    }
+}
+
+#[cfg(feature = "archive")]
+impl DocumentAuthority for ArchiveDocumentAuthority {
+    /// Returns a vector of [`Element`]s based on given schema id using the entries of the archive
+    fn elements(&self, id: &str) -> IonSchemaResult<Vec<Element>> {
+        let schema_content = Element::read_all(self.ion_content(id)?)?;
         Ok(schema_content)
     }
+
+    /// Returns a vector of [`Element`]s based on given schema id, if its archive entry is at most the maximum size
+    /// of the options
+    fn read_elements(&self, id: &str, options: ReadOptions) -> IonSchemaResult<SchemaElements> {
+        read_ion_content(id, self.ion_content(id)?, options)
+    }
+
+    /// Returns the content of the archive entry for given schema id, if it is valid UTF-8 text
+    fn source_text(&self, id: &str) -> Option<String> {
+        String::from_utf8(self.ion_content_by_id.get(&normalize_id(id)?)?.clone()).ok()
+    }
 }
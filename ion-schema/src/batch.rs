@@ -0,0 +1,279 @@
+//! Provides the [`BatchValidation`] of many values against the same type, along with the [`BatchStats`] that
+//! aggregate the violations of all the values.
+//!
+//! The values are validated one after the other by [`TypeDefinition::validate_all`], or in parallel on the
+//! [rayon] thread pool by `TypeDefinition::par_validate_all` when the `parallel` feature is enabled.
+//! Both return the results in the same order as the values.
+//!
+//! ## Example usage of `batch` module to validate a list of values:
+//! ```
+//! use ion_rs::element::Element;
+//! use ion_schema::authority::MapDocumentAuthority;
+//! use ion_schema::system::SchemaSystem;
+//! use ion_schema::violation::ViolationCode;
+//!
+//! let authority = MapDocumentAuthority::new([(
+//!     "sample.isl",
+//!     "type::{ name: small_int, type: int, valid_values: range::[0, 10] }",
+//! )]);
+//! let mut schema_system = SchemaSystem::new(vec![Box::new(authority)]);
+//! let schema = schema_system.load_schema("sample.isl").unwrap();
+//! let small_int = schema.get_type("small_int").unwrap();
+//!
+//! let values: Vec<Element> = vec![1.into(), 20.into(), "three".into(), 4.into()];
+//! let validation = small_int.validate_all(&values);
+//!
+//! let invalid_indexes: Vec<usize> = validation.violations().map(|(index, _)| index).collect();
+//! assert_eq!(invalid_indexes, vec![1, 2]);
+//! assert_eq!(validation.stats().valid_count(), 2);
+//! // `20` and `"three"` are not within the range, and `"three"` is not an int either
+//! assert_eq!(validation.stats().violation_count(&ViolationCode::InvalidValue), 2);
+//! assert_eq!(validation.stats().violation_count(&ViolationCode::TypeMismatched), 1);
+//! ```
+//!
+//! [`TypeDefinition::validate_all`]: crate::types::TypeDefinition::validate_all
+//! [rayon]: https://docs.rs/rayon
+
+use crate::result::ValidationResult;
+use crate::violation::{Violation, ViolationCode};
+use std::collections::HashMap;
+
+/// Represents the results of validating a batch of values against the same type,
+/// where the result at each index is the result for the value at that index.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchValidation {
+    results: Vec<ValidationResult>,
+    stats: BatchStats,
+}
+
+impl BatchValidation {
+    pub(crate) fn new(results: Vec<ValidationResult>) -> Self {
+        let stats = BatchStats::new(&results);
+        Self { results, stats }
+    }
+
+    /// Returns the validation results, in the same order as the validated values
+    pub fn results(&self) -> &[ValidationResult] {
+        &self.results
+    }
+
+    /// Returns the validation results, in the same order as the validated values
+    pub fn into_results(self) -> Vec<ValidationResult> {
+        self.results
+    }
+
+    /// Returns `true` if all the values are valid
+    pub fn is_valid(&self) -> bool {
+        self.stats.invalid_count() == 0
+    }
+
+    /// Returns the violation of each invalid value along with the index of that value
+    pub fn violations(&self) -> impl Iterator<Item = (usize, &Violation)> {
+        self.results
+            .iter()
+            .enumerate()
+            .filter_map(|(index, result)| result.as_ref().err().map(|violation| (index, violation)))
+    }
+
+    /// Returns the statistics aggregated from the results of all the values
+    pub fn stats(&self) -> &BatchStats {
+        &self.stats
+    }
+}
+
+/// Represents the statistics of a [`BatchValidation`].
+///
+/// The violations are counted by their root causes, i.e. the violations returned by
+/// [`Violation::flattened_violations`], hence a value can contribute more than one violation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchStats {
+    valid_count: usize,
+    invalid_count: usize,
+    violation_counts_by_code: HashMap<ViolationCode, usize>,
+    violation_counts_by_type: HashMap<(Option<String>, String), usize>,
+}
+
+impl BatchStats {
+    fn new(results: &[ValidationResult]) -> Self {
+        let mut stats = Self::default();
+        for result in results {
+            let violation = match result {
+                Ok(()) => {
+                    stats.valid_count += 1;
+                    continue;
+                }
+                Err(violation) => violation,
+            };
+            stats.invalid_count += 1;
+            for root_cause in violation.flattened_violations() {
+                *stats
+                    .violation_counts_by_code
+                    .entry(root_cause.code().to_owned())
+                    .or_default() += 1;
+                if let Some(type_name) = root_cause.type_name() {
+                    *stats
+                        .violation_counts_by_type
+                        .entry((
+                            root_cause.schema_id().map(str::to_owned),
+                            type_name.to_owned(),
+                        ))
+                        .or_default() += 1;
+                }
+            }
+        }
+        stats
+    }
+
+    /// Returns the number of valid values
+    pub fn valid_count(&self) -> usize {
+        self.valid_count
+    }
+
+    /// Returns the number of invalid values
+    pub fn invalid_count(&self) -> usize {
+        self.invalid_count
+    }
+
+    /// Returns the number of root cause violations with given code, across all the values
+    pub fn violation_count(&self, code: &ViolationCode) -> usize {
+        self.violation_counts_by_code
+            .get(code)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the number of root cause violations for each [`ViolationCode`], across all the values
+    pub fn violation_counts_by_code(&self) -> &HashMap<ViolationCode, usize> {
+        &self.violation_counts_by_code
+    }
+
+    /// Returns the number of root cause violations for each type, across all the values.
+    /// The types are keyed by the id of the schema that defines them and their name, since types defined in
+    /// different schemas may have the same name. See [`Violation::schema_id`] and [`Violation::type_name`]
+    /// for the type that a violation belongs to.
+    pub fn violation_counts_by_type(&self) -> &HashMap<(Option<String>, String), usize> {
+        &self.violation_counts_by_type
+    }
+}
+
+#[cfg(test)]
+mod batch_tests {
+    use super::*;
+    use crate::authority::MapDocumentAuthority;
+    use crate::system::SchemaSystem;
+    use crate::types::{TypeDefinition, ValidationOptions};
+    use ion_rs::element::Element;
+
+    fn load_type(type_name: &str) -> TypeDefinition {
+        let authority = MapDocumentAuthority::new([(
+            "sample.isl",
+            r#"
+                type::{ name: small_int, type: int, valid_values: range::[0, 10] }
+                type::{ name: small_ints, type: list, element: small_int }
+                type::{ name: nested_list, type: list, element: nested_list }
+            "#,
+        )]);
+        let mut schema_system = SchemaSystem::new(vec![Box::new(authority)]);
+        let schema = schema_system.load_schema("sample.isl").unwrap();
+        schema.get_type(type_name).unwrap()
+    }
+
+    fn values() -> Vec<Element> {
+        Element::read_all("[1, 2] [20, a, 3] [] (1) [11]".as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn validate_all() {
+        let small_ints = load_type("small_ints");
+        let validation = small_ints.validate_all(&values());
+
+        assert!(!validation.is_valid());
+        assert_eq!(validation.results().len(), 5);
+        let invalid_indexes: Vec<usize> = validation.violations().map(|(index, _)| index).collect();
+        assert_eq!(invalid_indexes, vec![1, 3, 4]);
+        for (index, result) in validation.results().iter().enumerate() {
+            assert_eq!(result, &small_ints.validate(&values()[index]));
+        }
+
+        let stats = validation.stats();
+        assert_eq!(stats.valid_count(), 2);
+        assert_eq!(stats.invalid_count(), 3);
+        assert_eq!(
+            stats.violation_counts_by_code(),
+            &HashMap::from([
+                (ViolationCode::InvalidValue, 3),
+                (ViolationCode::TypeMismatched, 2),
+            ])
+        );
+        assert_eq!(stats.violation_count(&ViolationCode::MissingValue), 0);
+        assert_eq!(
+            stats.violation_counts_by_type(),
+            // the type mismatches belong to the built-in types that were expected
+            &HashMap::from([
+                ((Some("sample.isl".to_owned()), "small_int".to_owned()), 3),
+                ((None, "int".to_owned()), 1),
+                ((None, "list".to_owned()), 1),
+            ])
+        );
+    }
+
+    #[test]
+    fn validate_all_empty() {
+        let validation = load_type("small_int").validate_all(&Vec::<Element>::new());
+        assert!(validation.is_valid());
+        assert_eq!(validation.stats(), &BatchStats::default());
+    }
+
+    #[test]
+    fn validate_all_with_options() {
+        let nested_list = load_type("nested_list");
+        let values = Element::read_all("[[[[]]]] [[]]".as_bytes()).unwrap();
+        let options = ValidationOptions::default().with_max_depth(2);
+        let validation = nested_list.validate_all_with(&values, options);
+
+        let invalid_indexes: Vec<usize> = validation.violations().map(|(index, _)| index).collect();
+        assert_eq!(invalid_indexes, vec![0]);
+        assert_eq!(
+            validation
+                .stats()
+                .violation_count(&ViolationCode::ValidationLimitExceeded),
+            1
+        );
+        assert!(nested_list.validate_all(&values).is_valid());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn par_validate_all() {
+        let small_ints = load_type("small_ints");
+        let values: Vec<Element> = values().into_iter().cycle().take(1000).collect();
+        assert_eq!(
+            small_ints.par_validate_all(&values),
+            small_ints.validate_all(&values)
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn par_validate_all_with_options() {
+        let nested_list = load_type("nested_list");
+        let values: Vec<Element> = Element::read_all("[[[[]]]] [[]]".as_bytes())
+            .unwrap()
+            .into_iter()
+            .cycle()
+            .take(1000)
+            .collect();
+        let options = ValidationOptions::default().with_max_depth(2);
+        assert_eq!(
+            nested_list.par_validate_all_with(&values, options),
+            nested_list.validate_all_with(&values, options)
+        );
+        assert_eq!(
+            nested_list
+                .validate_all_with(&values, options)
+                .stats()
+                .invalid_count(),
+            500
+        );
+    }
+}
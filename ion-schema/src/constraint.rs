@@ -1,3 +1,12 @@
+//! Provides the resolved [`Constraint`]s of a [`TypeDefinition`].
+//!
+//! Unlike the ISL model in [`isl_constraint`], the type references of a resolved constraint refer to type definitions
+//! by their [`TypeId`], which can be resolved using [`TypeDefinition::referenced_type`].
+//!
+//! [`TypeDefinition`]: crate::types::TypeDefinition
+//! [`TypeDefinition::referenced_type`]: crate::types::TypeDefinition::referenced_type
+//! [`isl_constraint`]: crate::isl::isl_constraint
+
 use crate::ion_path::{IonPath, IonPathElement};
 use crate::isl::isl_constraint::{IslAnnotationsConstraint, IslConstraintImpl, IslRegexConstraint};
 use crate::isl::isl_range::{Range, RangeImpl};
@@ -8,20 +17,21 @@ use crate::isl::util::{
     Annotation, Ieee754InterchangeFormat, TimestampOffset, TimestampPrecision, ValidValue,
 };
 use crate::isl::IslVersion;
-use crate::nfa::{FinalState, NfaBuilder, NfaEvaluation};
+use crate::nfa::{FinalState, Nfa, NfaBuilder, NfaEvaluation};
 use crate::result::{
     invalid_schema_error, invalid_schema_error_raw, IonSchemaResult, ValidationResult,
 };
 use crate::system::{PendingTypes, TypeId, TypeStore};
 use crate::type_reference::{TypeReference, VariablyOccurringTypeRef};
-use crate::types::TypeValidator;
+use crate::types::{TypeValidator, ValidationContext};
 use crate::violation::{Violation, ViolationCode};
-use crate::IonSchemaElement;
+use crate::{IonSchemaElement, IonSchemaElementRef};
 use ion_rs::element::Element;
 use ion_rs::IonData;
 use ion_rs::{Int, IonType};
 use num_traits::ToPrimitive;
 use regex::{Regex, RegexBuilder};
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter};
 use std::iter::Peekable;
@@ -30,7 +40,7 @@ use std::str::Chars;
 use std::sync::Arc;
 
 /// Provides validation for schema Constraint
-pub trait ConstraintValidator {
+pub(crate) trait ConstraintValidator {
     /// Checks this constraint against the provided value,
     /// adding [Violation]s and/or [ViolationChild]ren to `Err(violation)`
     /// if the constraint is violated.
@@ -40,7 +50,17 @@ pub trait ConstraintValidator {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult;
+
+    /// Checks whether the provided value satisfies this constraint.
+    /// Unlike `validate`, this doesn't build any [Violation]s when the constraint is violated.
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool;
 }
 
 /// Defines schema Constraints
@@ -520,28 +540,32 @@ impl Constraint {
         }
     }
 
-    pub fn validate(
+    pub(crate) fn validate(
         &self,
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
-        match self {
-            Constraint::AllOf(all_of) => all_of.validate(value, type_store, ion_path),
+        let checkpoint = context.checkpoint();
+        let result = match self {
+            Constraint::AllOf(all_of) => all_of.validate(value, type_store, ion_path, context),
             Constraint::Annotations(annotations) => {
-                annotations.validate(value, type_store, ion_path)
+                annotations.validate(value, type_store, ion_path, context)
             }
             Constraint::Annotations2_0(annotations) => {
-                annotations.validate(value, type_store, ion_path)
+                annotations.validate(value, type_store, ion_path, context)
             }
-            Constraint::AnyOf(any_of) => any_of.validate(value, type_store, ion_path),
+            Constraint::AnyOf(any_of) => any_of.validate(value, type_store, ion_path, context),
             Constraint::ByteLength(byte_length) => {
-                byte_length.validate(value, type_store, ion_path)
+                byte_length.validate(value, type_store, ion_path, context)
             }
             Constraint::CodepointLength(codepoint_length) => {
-                codepoint_length.validate(value, type_store, ion_path)
+                codepoint_length.validate(value, type_store, ion_path, context)
+            }
+            Constraint::Contains(contains) => {
+                contains.validate(value, type_store, ion_path, context)
             }
-            Constraint::Contains(contains) => contains.validate(value, type_store, ion_path),
             Constraint::ContentClosed => {
                 // No op
                 // `content: closed` does not work as a constraint by its own, it needs to be used with other container constraints
@@ -550,45 +574,115 @@ impl Constraint {
                 Ok(())
             }
             Constraint::ContainerLength(container_length) => {
-                container_length.validate(value, type_store, ion_path)
+                container_length.validate(value, type_store, ion_path, context)
             }
-            Constraint::Element(element) => element.validate(value, type_store, ion_path),
+            Constraint::Element(element) => element.validate(value, type_store, ion_path, context),
             Constraint::FieldNames(field_names) => {
-                field_names.validate(value, type_store, ion_path)
+                field_names.validate(value, type_store, ion_path, context)
             }
-            Constraint::Fields(fields) => fields.validate(value, type_store, ion_path),
+            Constraint::Fields(fields) => fields.validate(value, type_store, ion_path, context),
             Constraint::Ieee754Float(ieee754_float) => {
-                ieee754_float.validate(value, type_store, ion_path)
+                ieee754_float.validate(value, type_store, ion_path, context)
             }
-            Constraint::Not(not) => not.validate(value, type_store, ion_path),
-            Constraint::OneOf(one_of) => one_of.validate(value, type_store, ion_path),
+            Constraint::Not(not) => not.validate(value, type_store, ion_path, context),
+            Constraint::OneOf(one_of) => one_of.validate(value, type_store, ion_path, context),
             Constraint::Type(type_constraint) => {
-                type_constraint.validate(value, type_store, ion_path)
+                type_constraint.validate(value, type_store, ion_path, context)
             }
             Constraint::OrderedElements(ordered_elements) => {
-                ordered_elements.validate(value, type_store, ion_path)
+                ordered_elements.validate(value, type_store, ion_path, context)
+            }
+            Constraint::Precision(precision) => {
+                precision.validate(value, type_store, ion_path, context)
+            }
+            Constraint::Regex(regex) => regex.validate(value, type_store, ion_path, context),
+            Constraint::Scale(scale) => scale.validate(value, type_store, ion_path, context),
+            Constraint::Exponent(exponent) => {
+                exponent.validate(value, type_store, ion_path, context)
             }
-            Constraint::Precision(precision) => precision.validate(value, type_store, ion_path),
-            Constraint::Regex(regex) => regex.validate(value, type_store, ion_path),
-            Constraint::Scale(scale) => scale.validate(value, type_store, ion_path),
-            Constraint::Exponent(exponent) => exponent.validate(value, type_store, ion_path),
             Constraint::TimestampOffset(timestamp_offset) => {
-                timestamp_offset.validate(value, type_store, ion_path)
+                timestamp_offset.validate(value, type_store, ion_path, context)
             }
             Constraint::TimestampPrecision(timestamp_precision) => {
-                timestamp_precision.validate(value, type_store, ion_path)
+                timestamp_precision.validate(value, type_store, ion_path, context)
             }
             Constraint::Utf8ByteLength(utf8_byte_length) => {
-                utf8_byte_length.validate(value, type_store, ion_path)
+                utf8_byte_length.validate(value, type_store, ion_path, context)
             }
             Constraint::ValidValues(valid_values) => {
-                valid_values.validate(value, type_store, ion_path)
+                valid_values.validate(value, type_store, ion_path, context)
             }
             Constraint::Unknown(_, _) => {
                 // No op
                 // `Unknown` represents open content which can be ignored for validation
                 Ok(())
             }
+        };
+        // violations found by nested validations don't count towards the limit when the constraint is satisfied
+        // (e.g. the violations for the branches of a satisfied `any_of`)
+        if result.is_ok() {
+            context.restore(checkpoint);
+        }
+        result
+    }
+
+    pub(crate) fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        match self {
+            Constraint::AllOf(all_of) => all_of.is_valid(value, type_store, context),
+            Constraint::Annotations(annotations) => {
+                annotations.is_valid(value, type_store, context)
+            }
+            Constraint::Annotations2_0(annotations) => {
+                annotations.is_valid(value, type_store, context)
+            }
+            Constraint::AnyOf(any_of) => any_of.is_valid(value, type_store, context),
+            Constraint::ByteLength(byte_length) => byte_length.is_valid(value, type_store, context),
+            Constraint::CodepointLength(codepoint_length) => {
+                codepoint_length.is_valid(value, type_store, context)
+            }
+            Constraint::Contains(contains) => contains.is_valid(value, type_store, context),
+            // `content: closed` is validated within other container constraints e.g. `fields`
+            Constraint::ContentClosed => true,
+            Constraint::ContainerLength(container_length) => {
+                container_length.is_valid(value, type_store, context)
+            }
+            Constraint::Element(element) => element.is_valid(value, type_store, context),
+            Constraint::FieldNames(field_names) => field_names.is_valid(value, type_store, context),
+            Constraint::Fields(fields) => fields.is_valid(value, type_store, context),
+            Constraint::Ieee754Float(ieee754_float) => {
+                ieee754_float.is_valid(value, type_store, context)
+            }
+            Constraint::Not(not) => not.is_valid(value, type_store, context),
+            Constraint::OneOf(one_of) => one_of.is_valid(value, type_store, context),
+            Constraint::Type(type_constraint) => {
+                type_constraint.is_valid(value, type_store, context)
+            }
+            Constraint::OrderedElements(ordered_elements) => {
+                ordered_elements.is_valid(value, type_store, context)
+            }
+            Constraint::Precision(precision) => precision.is_valid(value, type_store, context),
+            Constraint::Regex(regex) => regex.is_valid(value, type_store, context),
+            Constraint::Scale(scale) => scale.is_valid(value, type_store, context),
+            Constraint::Exponent(exponent) => exponent.is_valid(value, type_store, context),
+            Constraint::TimestampOffset(timestamp_offset) => {
+                timestamp_offset.is_valid(value, type_store, context)
+            }
+            Constraint::TimestampPrecision(timestamp_precision) => {
+                timestamp_precision.is_valid(value, type_store, context)
+            }
+            Constraint::Utf8ByteLength(utf8_byte_length) => {
+                utf8_byte_length.is_valid(value, type_store, context)
+            }
+            Constraint::ValidValues(valid_values) => {
+                valid_values.is_valid(value, type_store, context)
+            }
+            // `Unknown` represents open content which can be ignored for validation
+            Constraint::Unknown(_, _) => true,
         }
     }
 }
@@ -604,21 +698,24 @@ impl AllOfConstraint {
     pub fn new(type_references: Vec<TypeReference>) -> Self {
         Self { type_references }
     }
-}
 
-impl ConstraintValidator for AllOfConstraint {
-    fn validate(
+    pub fn type_references(&self) -> &[TypeReference] {
+        &self.type_references
+    }
+
+    /// Combines the validation results for each of the type references into a result for this constraint
+    pub(crate) fn combine_results<I: IntoIterator<Item = ValidationResult>>(
         &self,
-        value: &IonSchemaElement,
-        type_store: &TypeStore,
+        results: I,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         let mut violations: Vec<Violation> = vec![];
-        let mut valid_types = vec![];
-        for type_reference in &self.type_references {
-            match type_reference.validate(value, type_store, ion_path) {
-                Ok(_) => valid_types.push(type_reference.type_id()),
-                Err(violation) => violations.push(violation),
+        let mut valid_types = 0;
+        for result in results {
+            match result {
+                Ok(_) => valid_types += 1,
+                Err(violation) => context.push_violation(&mut violations, violation),
             }
         }
         if !violations.is_empty() {
@@ -627,7 +724,7 @@ impl ConstraintValidator for AllOfConstraint {
                 ViolationCode::AllTypesNotMatched,
                 format!(
                     "value matches {} types, expected {}",
-                    valid_types.len(),
+                    valid_types,
                     self.type_references.len()
                 ),
                 ion_path,
@@ -638,6 +735,34 @@ impl ConstraintValidator for AllOfConstraint {
     }
 }
 
+impl ConstraintValidator for AllOfConstraint {
+    fn validate(
+        &self,
+        value: &IonSchemaElement,
+        type_store: &TypeStore,
+        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
+    ) -> ValidationResult {
+        let results: Vec<ValidationResult> = self
+            .type_references
+            .iter()
+            .map(|type_reference| type_reference.validate(value, type_store, ion_path, context))
+            .collect();
+        self.combine_results(results, ion_path, context)
+    }
+
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        self.type_references
+            .iter()
+            .all(|type_reference| type_reference.is_valid(value, type_store, context))
+    }
+}
+
 /// Implements an `any_of` constraint of Ion Schema
 /// [any_of]: https://amazon-ion.github.io/ion-schema/docs/isl-1-0/spec#any_of
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -649,24 +774,26 @@ impl AnyOfConstraint {
     pub fn new(type_references: Vec<TypeReference>) -> Self {
         Self { type_references }
     }
-}
 
-impl ConstraintValidator for AnyOfConstraint {
-    fn validate(
+    pub fn type_references(&self) -> &[TypeReference] {
+        &self.type_references
+    }
+
+    /// Combines the validation results for each of the type references into a result for this constraint
+    pub(crate) fn combine_results<I: IntoIterator<Item = ValidationResult>>(
         &self,
-        value: &IonSchemaElement,
-        type_store: &TypeStore,
+        results: I,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         let mut violations: Vec<Violation> = vec![];
-        let mut valid_types = vec![];
-        for type_reference in &self.type_references {
-            match type_reference.validate(value, type_store, ion_path) {
-                Ok(_) => valid_types.push(type_reference.type_id()),
-                Err(violation) => violations.push(violation),
+        let mut total_valid_types = 0;
+        for result in results {
+            match result {
+                Ok(_) => total_valid_types += 1,
+                Err(violation) => context.push_violation(&mut violations, violation),
             }
         }
-        let total_valid_types = valid_types.len();
         if total_valid_types == 0 {
             return Err(Violation::with_violations(
                 "any_of",
@@ -680,6 +807,34 @@ impl ConstraintValidator for AnyOfConstraint {
     }
 }
 
+impl ConstraintValidator for AnyOfConstraint {
+    fn validate(
+        &self,
+        value: &IonSchemaElement,
+        type_store: &TypeStore,
+        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
+    ) -> ValidationResult {
+        let results: Vec<ValidationResult> = self
+            .type_references
+            .iter()
+            .map(|type_reference| type_reference.validate(value, type_store, ion_path, context))
+            .collect();
+        self.combine_results(results, ion_path, context)
+    }
+
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        self.type_references
+            .iter()
+            .any(|type_reference| type_reference.is_valid(value, type_store, context))
+    }
+}
+
 /// Implements an `one_of` constraint of Ion Schema
 /// [one_of]: https://amazon-ion.github.io/ion-schema/docs/isl-1-0/spec#one_of
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -691,24 +846,26 @@ impl OneOfConstraint {
     pub fn new(type_references: Vec<TypeReference>) -> Self {
         Self { type_references }
     }
-}
 
-impl ConstraintValidator for OneOfConstraint {
-    fn validate(
+    pub fn type_references(&self) -> &[TypeReference] {
+        &self.type_references
+    }
+
+    /// Combines the validation results for each of the type references into a result for this constraint
+    pub(crate) fn combine_results<I: IntoIterator<Item = ValidationResult>>(
         &self,
-        value: &IonSchemaElement,
-        type_store: &TypeStore,
+        results: I,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         let mut violations: Vec<Violation> = vec![];
-        let mut valid_types = vec![];
-        for type_reference in &self.type_references {
-            match type_reference.validate(value, type_store, ion_path) {
-                Ok(_) => valid_types.push(type_reference.type_id()),
-                Err(violation) => violations.push(violation),
+        let mut total_valid_types = 0;
+        for result in results {
+            match result {
+                Ok(_) => total_valid_types += 1,
+                Err(violation) => context.push_violation(&mut violations, violation),
             }
         }
-        let total_valid_types = valid_types.len();
         match total_valid_types {
             0 => Err(Violation::with_violations(
                 "one_of",
@@ -729,6 +886,38 @@ impl ConstraintValidator for OneOfConstraint {
     }
 }
 
+impl ConstraintValidator for OneOfConstraint {
+    fn validate(
+        &self,
+        value: &IonSchemaElement,
+        type_store: &TypeStore,
+        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
+    ) -> ValidationResult {
+        let results: Vec<ValidationResult> = self
+            .type_references
+            .iter()
+            .map(|type_reference| type_reference.validate(value, type_store, ion_path, context))
+            .collect();
+        self.combine_results(results, ion_path, context)
+    }
+
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        // stop as soon as a second valid type is found
+        self.type_references
+            .iter()
+            .filter(|type_reference| type_reference.is_valid(value, type_store, context))
+            .take(2)
+            .count()
+            == 1
+    }
+}
+
 /// Implements a `not` constraint
 /// [type]: https://amazon-ion.github.io/ion-schema/docs/isl-1-0/spec#not
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -740,17 +929,19 @@ impl NotConstraint {
     pub fn new(type_reference: TypeReference) -> Self {
         Self { type_reference }
     }
-}
 
-impl ConstraintValidator for NotConstraint {
-    fn validate(
+    pub fn type_reference(&self) -> TypeReference {
+        self.type_reference
+    }
+
+    /// Converts the validation result for the type reference into a result for this constraint
+    pub(crate) fn negate_result(
         &self,
-        value: &IonSchemaElement,
-        type_store: &TypeStore,
+        result: ValidationResult,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
-        let violation = self.type_reference.validate(value, type_store, ion_path);
-        match violation {
+        match result {
             Err(violation) => Ok(()),
             Ok(_) => {
                 // if there were no violations for the types then not constraint was unsatisfied
@@ -765,6 +956,30 @@ impl ConstraintValidator for NotConstraint {
     }
 }
 
+impl ConstraintValidator for NotConstraint {
+    fn validate(
+        &self,
+        value: &IonSchemaElement,
+        type_store: &TypeStore,
+        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
+    ) -> ValidationResult {
+        let result = self
+            .type_reference
+            .validate(value, type_store, ion_path, context);
+        self.negate_result(result, ion_path, context)
+    }
+
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        !self.type_reference.is_valid(value, type_store, context)
+    }
+}
+
 /// Implements a `type` constraint
 /// [type]: https://amazon-ion.github.io/ion-schema/docs/isl-1-0/spec#type
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -776,6 +991,10 @@ impl TypeConstraint {
     pub fn new(type_reference: TypeReference) -> Self {
         Self { type_reference }
     }
+
+    pub fn type_reference(&self) -> TypeReference {
+        self.type_reference
+    }
 }
 
 impl ConstraintValidator for TypeConstraint {
@@ -784,8 +1003,19 @@ impl ConstraintValidator for TypeConstraint {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
-        self.type_reference.validate(value, type_store, ion_path)
+        self.type_reference
+            .validate(value, type_store, ion_path, context)
+    }
+
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        self.type_reference.is_valid(value, type_store, context)
     }
 }
 
@@ -794,11 +1024,23 @@ impl ConstraintValidator for TypeConstraint {
 #[derive(Debug, Clone, PartialEq)]
 pub struct OrderedElementsConstraint {
     type_references: Vec<VariablyOccurringTypeRef>,
+    // the NFA is built once from the type references and shared by all the validations of this constraint
+    nfa: Arc<Nfa>,
 }
 
 impl OrderedElementsConstraint {
     pub fn new(type_references: Vec<VariablyOccurringTypeRef>) -> Self {
-        Self { type_references }
+        let nfa = Arc::new(OrderedElementsConstraint::build_nfa_from_type_references(
+            &type_references,
+        ));
+        Self {
+            type_references,
+            nfa,
+        }
+    }
+
+    pub fn type_references(&self) -> &[VariablyOccurringTypeRef] {
+        &self.type_references
     }
 
     /// Tries to create an [OrderedElements] constraint from the given Element
@@ -864,10 +1106,7 @@ impl OrderedElementsConstraint {
     // +------------------------------+
     // As shown above visit count for `END` doesn't have final state in it which means the value resulted to be invalid.
     //
-    fn build_nfa_from_type_references(
-        type_ids: &[VariablyOccurringTypeRef],
-        type_store: &TypeStore,
-    ) -> NfaEvaluation {
+    fn build_nfa_from_type_references(type_ids: &[VariablyOccurringTypeRef]) -> Nfa {
         let mut nfa_builder = NfaBuilder::new();
         let mut final_states = HashSet::new();
         for (state_id, variably_occurring_type_reference) in type_ids.iter().enumerate() {
@@ -901,7 +1140,60 @@ impl OrderedElementsConstraint {
             }
         }
 
-        NfaEvaluation::new(Arc::new(nfa_builder.build(final_states)))
+        nfa_builder.build(final_states)
+    }
+
+    /// Starts validating the elements of a container against this constraint, one element at a time
+    pub(crate) fn start_validation(&self) -> OrderedElementsValidation {
+        OrderedElementsValidation {
+            nfa_evaluation: NfaEvaluation::new(Arc::clone(&self.nfa)),
+            is_empty: true,
+        }
+    }
+}
+
+/// Represents an in-progress validation of an `ordered_elements` constraint,
+/// where the elements of the container are provided one at a time.
+#[derive(Debug, Clone)]
+pub(crate) struct OrderedElementsValidation {
+    nfa_evaluation: NfaEvaluation,
+    is_empty: bool,
+}
+
+impl OrderedElementsValidation {
+    /// Validates the next element of the container
+    pub(crate) fn validate_next(
+        &mut self,
+        element: &Element,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) {
+        self.is_empty = false;
+        self.nfa_evaluation
+            .validate_next_element(element, type_store, context);
+    }
+
+    /// Verifies if the elements provided so far satisfy the constraint
+    fn is_satisfied(&self, type_store: &TypeStore) -> bool {
+        (self.is_empty || !self.nfa_evaluation.nfa.get_final_states().is_empty())
+            && self.nfa_evaluation.has_final_state(type_store)
+    }
+
+    /// Returns the result of the validation once all the elements of the container have been provided
+    pub(crate) fn finish(self, type_store: &TypeStore, ion_path: &mut IonPath) -> ValidationResult {
+        let violations: Vec<Violation> = vec![];
+
+        if !self.is_satisfied(type_store) {
+            return Err(Violation::with_violations(
+                "ordered_elements",
+                ViolationCode::TypeMismatched,
+                "one or more ordered elements didn't match",
+                ion_path,
+                violations,
+            ));
+        }
+
+        Ok(())
     }
 }
 
@@ -911,6 +1203,7 @@ impl ConstraintValidator for OrderedElementsConstraint {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         let violations: Vec<Violation> = vec![];
 
@@ -937,36 +1230,37 @@ impl ConstraintValidator for OrderedElementsConstraint {
             IonSchemaElement::Document(document) => document.to_owned(),
         };
 
-        // build nfa for validation
-        let mut nfa_evaluation = OrderedElementsConstraint::build_nfa_from_type_references(
-            &self.type_references,
-            type_store,
-        );
-
-        if !values.is_empty() && nfa_evaluation.nfa.get_final_states().is_empty() {
-            return Err(Violation::with_violations(
-                "ordered_elements",
-                ViolationCode::TypeMismatched,
-                "one or more ordered elements didn't match",
-                ion_path,
-                violations,
-            ));
+        // use nfa for validation
+        let mut validation = self.start_validation();
+        for value in &values {
+            validation.validate_next(value, type_store, context);
         }
+        validation.finish(type_store, ion_path)
+    }
 
-        // use nfa_evaluation for validation
-        nfa_evaluation.validate_ordered_elements(&values, type_store);
-
-        if !nfa_evaluation.has_final_state(type_store) {
-            return Err(Violation::with_violations(
-                "ordered_elements",
-                ViolationCode::TypeMismatched,
-                "one or more ordered elements didn't match",
-                ion_path,
-                violations,
-            ));
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        let mut validation = self.start_validation();
+        match value {
+            IonSchemaElementRef::SingleElement(element) => match element.as_sequence() {
+                None => return false,
+                Some(sequence) => {
+                    for element in sequence.elements() {
+                        validation.validate_next(element, type_store, context);
+                    }
+                }
+            },
+            IonSchemaElementRef::Document(document) => {
+                for element in document {
+                    validation.validate_next(element, type_store, context);
+                }
+            }
         }
-
-        Ok(())
+        validation.is_satisfied(type_store)
     }
 }
 
@@ -986,11 +1280,25 @@ impl FieldsConstraint {
         }
     }
 
+    /// Provides the type references of the fields by field name
+    pub fn fields(&self) -> &HashMap<String, VariablyOccurringTypeRef> {
+        &self.fields
+    }
+
     /// Provides boolean value indicating whether open content is allowed or not for the fields
     pub fn open_content(&self) -> bool {
         self.open_content
     }
 
+    /// Starts validating the fields of a struct against this constraint, one field at a time
+    pub(crate) fn start_validation(&self) -> FieldsValidation<'_> {
+        FieldsValidation {
+            constraint: self,
+            occurrences: HashMap::new(),
+            violations: vec![],
+        }
+    }
+
     /// Tries to create an [Fields] constraint from the given Element
     fn resolve_from_isl_constraint(
         isl_version: IslVersion,
@@ -1018,63 +1326,158 @@ impl ConstraintValidator for FieldsConstraint {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
-        let mut violations: Vec<Violation> = vec![];
-
         // get struct value
         let ion_struct = value
             .expect_element_of_type(&[IonType::Struct], "fields", ion_path)?
             .as_struct()
             .unwrap();
 
-        // Verify if open content exists in the struct fields
-        if !self.open_content() {
-            for (field_name, value) in ion_struct.iter() {
-                if !self.fields.contains_key(field_name.text().unwrap()) {
-                    violations.push(Violation::new(
-                        "fields",
-                        ViolationCode::InvalidOpenContent,
-                        format!("Found open content in the struct: {field_name}: {value}"),
-                        ion_path,
-                    ));
+        let mut validation = self.start_validation();
+        for (field_name, value) in ion_struct.iter() {
+            validation.validate_next(
+                field_name.text().unwrap(),
+                value,
+                type_store,
+                ion_path,
+                context,
+            );
+        }
+        validation.finish(ion_path, context)
+    }
+
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        let ion_struct = match value.element_of_type(&[IonType::Struct]) {
+            Some(element) => element.as_struct().unwrap(),
+            None => return false,
+        };
+
+        // verify the open content and the value of each of the fields
+        for (field_name, value) in ion_struct.iter() {
+            let is_valid_field = match self.fields.get(field_name.text().unwrap()) {
+                None => self.open_content(),
+                Some(variably_occurring_type_ref) => variably_occurring_type_ref
+                    .type_ref()
+                    .is_valid(value.into(), type_store, context),
+            };
+            if !is_valid_field {
+                return false;
+            }
+        }
+
+        // verify the occurrences of each of the fields
+        self.fields
+            .iter()
+            .all(|(field_name, variably_occurring_type_ref)| {
+                let occurrences = ion_struct.get_all(field_name.as_str()).count();
+                variably_occurring_type_ref
+                    .occurs_range()
+                    .contains(&(occurrences as i64).into())
+            })
+    }
+}
+
+/// Represents an in-progress validation of a `fields` constraint,
+/// where the fields of the struct are provided one at a time.
+#[derive(Debug, Clone)]
+pub(crate) struct FieldsValidation<'a> {
+    constraint: &'a FieldsConstraint,
+    // number of values found so far for each of the fields defined by the constraint
+    occurrences: HashMap<&'a str, usize>,
+    violations: Vec<Violation>,
+}
+
+impl<'a> FieldsValidation<'a> {
+    /// Validates the next field of the struct
+    pub(crate) fn validate_next(
+        &mut self,
+        field_name: &str,
+        value: &Element,
+        type_store: &TypeStore,
+        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
+    ) {
+        if context.should_stop(&self.violations) {
+            return;
+        }
+        match self.constraint.fields.get_key_value(field_name) {
+            None => {
+                // Verify if open content exists in the struct fields
+                if !self.constraint.open_content() {
+                    context.push_violation(
+                        &mut self.violations,
+                        Violation::new(
+                            "fields",
+                            ViolationCode::InvalidOpenContent,
+                            format!("Found open content in the struct: {field_name}: {value}"),
+                            ion_path,
+                        ),
+                    );
                 }
             }
+            Some((field_name, variably_occurring_type_ref)) => {
+                *self.occurrences.entry(field_name.as_str()).or_default() += 1;
+
+                // add parent value for current field in ion path
+                ion_path.push(IonPathElement::Field(field_name.to_owned()));
+
+                // verify if the value for this field name is valid according to type_def
+                let schema_element: IonSchemaElement = value.into();
+                if let Err(violation) = variably_occurring_type_ref.type_ref().validate(
+                    &schema_element,
+                    type_store,
+                    ion_path,
+                    context,
+                ) {
+                    context.push_violation(&mut self.violations, violation);
+                }
+
+                // remove current field from list of parents
+                ion_path.pop();
+            }
         }
+    }
 
-        // get the values corresponding to the field_name and perform occurs_validation based on the type_def
-        for (field_name, variably_occurring_type_ref) in &self.fields {
-            let type_reference = variably_occurring_type_ref.type_ref();
-            let values: Vec<&Element> = ion_struct.get_all(field_name).collect();
+    /// Returns the result of the validation once all the fields of the struct have been provided
+    pub(crate) fn finish(
+        mut self,
+        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
+    ) -> ValidationResult {
+        // perform occurs validation for all the field names defined by the constraint
+        for (field_name, variably_occurring_type_ref) in &self.constraint.fields {
+            if context.should_stop(&self.violations) {
+                break;
+            }
+            let occurrences = self
+                .occurrences
+                .get(field_name.as_str())
+                .copied()
+                .unwrap_or(0);
 
             // add parent value for current field in ion path
             ion_path.push(IonPathElement::Field(field_name.to_owned()));
 
-            // perform occurs validation for type_def for all values of the given field_name
-            let occurs_range: &Range = variably_occurring_type_ref.occurs_range();
-
             // verify if values follow occurs_range constraint
-            if !occurs_range.contains(&(values.len() as i64).into()) {
-                violations.push(Violation::new(
-                    "fields",
-                    ViolationCode::TypeMismatched,
-                    &format!(
-                        "Expected {} of field {}: found {}",
-                        occurs_range,
-                        field_name,
-                        values.len()
+            let occurs_range: &Range = variably_occurring_type_ref.occurs_range();
+            if !occurs_range.contains(&(occurrences as i64).into()) {
+                context.push_violation(
+                    &mut self.violations,
+                    Violation::new(
+                        "fields",
+                        ViolationCode::TypeMismatched,
+                        format!(
+                            "Expected {occurs_range} of field {field_name}: found {occurrences}"
+                        ),
+                        ion_path,
                     ),
-                    ion_path,
-                ));
-            }
-
-            // verify if all the values for this field name are valid according to type_def
-            for value in values {
-                let schema_element: IonSchemaElement = value.into();
-                if let Err(violation) =
-                    type_reference.validate(&schema_element, type_store, ion_path)
-                {
-                    violations.push(violation);
-                }
+                );
             }
 
             // remove current field from list of parents
@@ -1082,13 +1485,13 @@ impl ConstraintValidator for FieldsConstraint {
         }
 
         // return error if there were any violation found during validation
-        if !violations.is_empty() {
+        if !self.violations.is_empty() {
             return Err(Violation::with_violations(
                 "fields",
                 ViolationCode::FieldsNotMatched,
                 "value didn't satisfy fields constraint",
                 ion_path,
-                violations,
+                self.violations,
             ));
         }
         Ok(())
@@ -1110,6 +1513,24 @@ impl FieldNamesConstraint {
             requires_distinct,
         }
     }
+
+    pub fn type_reference(&self) -> TypeReference {
+        self.type_reference
+    }
+
+    /// Provides boolean value indicating whether the field names are required to be distinct
+    pub fn requires_distinct(&self) -> bool {
+        self.requires_distinct
+    }
+
+    /// Starts validating the field names of a struct against this constraint, one field at a time
+    pub(crate) fn start_validation(&self) -> FieldNamesValidation<'_> {
+        FieldNamesValidation {
+            constraint: self,
+            field_names: HashSet::new(),
+            violations: vec![],
+        }
+    }
 }
 
 impl ConstraintValidator for FieldNamesConstraint {
@@ -1118,47 +1539,98 @@ impl ConstraintValidator for FieldNamesConstraint {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
-        let mut violations: Vec<Violation> = vec![];
-
-        // create a set for checking duplicate field names
-        let mut field_name_set = HashSet::new();
-
         let ion_struct = value
             .expect_element_of_type(&[IonType::Struct], "field_names", ion_path)?
             .as_struct()
             .unwrap();
 
+        let mut validation = self.start_validation();
         for (field_name, _) in ion_struct.iter() {
-            ion_path.push(IonPathElement::Field(field_name.text().unwrap().to_owned()));
-            let schema_element: IonSchemaElement = (&Element::symbol(field_name)).into();
+            validation.validate_next(field_name.text().unwrap(), type_store, ion_path, context);
+        }
+        validation.finish(ion_path)
+    }
 
-            if let Err(violation) =
-                self.type_reference
-                    .validate(&schema_element, type_store, ion_path)
-            {
-                violations.push(violation);
-            }
-            if self.requires_distinct && !field_name_set.insert(field_name.text().unwrap()) {
-                violations.push(Violation::new(
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        let ion_struct = match value.element_of_type(&[IonType::Struct]) {
+            Some(element) => element.as_struct().unwrap(),
+            None => return false,
+        };
+
+        let mut field_names: HashSet<&str> = HashSet::new();
+        ion_struct.iter().all(|(field_name, _)| {
+            let field_name = field_name.text().unwrap();
+            self.type_reference
+                .is_valid((&Element::symbol(field_name)).into(), type_store, context)
+                && (!self.requires_distinct || field_names.insert(field_name))
+        })
+    }
+}
+
+/// Represents an in-progress validation of a `field_names` constraint,
+/// where the field names of the struct are provided one at a time.
+#[derive(Debug, Clone)]
+pub(crate) struct FieldNamesValidation<'a> {
+    constraint: &'a FieldNamesConstraint,
+    // a set for checking duplicate field names
+    field_names: HashSet<String>,
+    violations: Vec<Violation>,
+}
+
+impl<'a> FieldNamesValidation<'a> {
+    /// Validates the next field name of the struct
+    pub(crate) fn validate_next(
+        &mut self,
+        field_name: &str,
+        type_store: &TypeStore,
+        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
+    ) {
+        if context.should_stop(&self.violations) {
+            return;
+        }
+        ion_path.push(IonPathElement::Field(field_name.to_owned()));
+        let schema_element: IonSchemaElement = (&Element::symbol(field_name)).into();
+
+        if let Err(violation) =
+            self.constraint
+                .type_reference
+                .validate(&schema_element, type_store, ion_path, context)
+        {
+            context.push_violation(&mut self.violations, violation);
+        }
+        if self.constraint.requires_distinct && !self.field_names.insert(field_name.to_owned()) {
+            context.push_violation(
+                &mut self.violations,
+                Violation::new(
                     "field_names",
                     ViolationCode::FieldNamesNotDistinct,
                     format!(
                         "expected distinct field names but found duplicate field name {field_name}",
                     ),
                     ion_path,
-                ))
-            }
-            ion_path.pop();
+                ),
+            )
         }
+        ion_path.pop();
+    }
 
-        if !violations.is_empty() {
+    /// Returns the result of the validation once all the field names of the struct have been provided
+    pub(crate) fn finish(self, ion_path: &mut IonPath) -> ValidationResult {
+        if !self.violations.is_empty() {
             return Err(Violation::with_violations(
                 "field_names",
                 ViolationCode::FieldNamesMismatched,
                 "one or more field names don't satisfy field_names constraint",
                 ion_path,
-                violations,
+                self.violations,
             ));
         }
         Ok(())
@@ -1178,6 +1650,18 @@ impl ContainsConstraint {
     pub fn new(values: Vec<Element>) -> Self {
         Self { values }
     }
+
+    pub fn values(&self) -> &[Element] {
+        &self.values
+    }
+
+    /// Starts validating the elements of a container against this constraint, one element at a time
+    pub(crate) fn start_validation(&self) -> ContainsValidation<'_> {
+        ContainsValidation {
+            constraint: self,
+            found_values: vec![false; self.values.len()],
+        }
+    }
 }
 
 impl ConstraintValidator for ContainsConstraint {
@@ -1186,6 +1670,7 @@ impl ConstraintValidator for ContainsConstraint {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         // Create a peekable iterator for given sequence
         let values: Vec<Element> = match &value {
@@ -1196,7 +1681,7 @@ impl ConstraintValidator for ContainsConstraint {
                         return Err(Violation::new(
                             "contains",
                             ViolationCode::TypeMismatched,
-                            &format!(
+                            format!(
                                 "expected list/sexp found {}",
                                 if element.is_null() {
                                     format!("{element}")
@@ -1213,16 +1698,66 @@ impl ConstraintValidator for ContainsConstraint {
             IonSchemaElement::Document(document) => document.to_owned(),
         };
 
-        // add all the missing values found during validation
-        let mut missing_values = vec![];
+        let mut validation = self.start_validation();
+        for element in &values {
+            validation.validate_next(element);
+        }
+        validation.finish(value, ion_path)
+    }
+
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        match value {
+            IonSchemaElementRef::SingleElement(element) => match element.as_sequence() {
+                None => false,
+                Some(sequence) => self
+                    .values
+                    .iter()
+                    .all(|expected_value| sequence.elements().any(|e| e == expected_value)),
+            },
+            IonSchemaElementRef::Document(document) => self
+                .values
+                .iter()
+                .all(|expected_value| document.contains(expected_value)),
+        }
+    }
+}
 
-        // for each value in expected values if it does not exist in ion sequence
-        // then add it to missing_values to keep track of missing values
-        for expected_value in self.values.iter() {
-            if !values.contains(expected_value) {
-                missing_values.push(expected_value);
+/// Represents an in-progress validation of a `contains` constraint,
+/// where the elements of the container are provided one at a time.
+#[derive(Debug, Clone)]
+pub(crate) struct ContainsValidation<'a> {
+    constraint: &'a ContainsConstraint,
+    // represents whether each of the expected values was found in the container so far
+    found_values: Vec<bool>,
+}
+
+impl<'a> ContainsValidation<'a> {
+    /// Validates the next element of the container
+    pub(crate) fn validate_next(&mut self, element: &Element) {
+        for (expected_value, found) in self.constraint.values.iter().zip(&mut self.found_values) {
+            if !*found && expected_value == element {
+                *found = true;
             }
         }
+    }
+
+    /// Returns the result of the validation once all the elements of the container have been provided,
+    /// `value` is used to describe the container in the violation message
+    pub(crate) fn finish<D: Display>(self, value: D, ion_path: &mut IonPath) -> ValidationResult {
+        // add all the missing values found during validation
+        let missing_values: Vec<&Element> = self
+            .constraint
+            .values
+            .iter()
+            .zip(self.found_values)
+            .filter(|(_, found)| !found)
+            .map(|(expected_value, _)| expected_value)
+            .collect();
 
         // return Violation if there were any values added to the missing values vector
         if !missing_values.is_empty() {
@@ -1261,6 +1796,7 @@ impl ConstraintValidator for ContainerLengthConstraint {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         // get the size of given value container
         let size = match value {
@@ -1295,6 +1831,31 @@ impl ConstraintValidator for ContainerLengthConstraint {
             IonSchemaElement::Document(document) => document.len(),
         };
 
+        self.validate_length(size, ion_path)
+    }
+
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        let size = match value {
+            IonSchemaElementRef::SingleElement(element) if element.is_null() => return false,
+            IonSchemaElementRef::SingleElement(element) => match element.ion_type() {
+                IonType::List | IonType::SExp => element.as_sequence().unwrap().len(),
+                IonType::Struct => element.as_struct().unwrap().iter().count(),
+                _ => return false,
+            },
+            IonSchemaElementRef::Document(document) => document.len(),
+        };
+        self.length().contains(&(size as i64).into())
+    }
+}
+
+impl ContainerLengthConstraint {
+    /// Verifies if the given container size follows this constraint
+    pub(crate) fn validate_length(&self, size: usize, ion_path: &mut IonPath) -> ValidationResult {
         // get isl length as a range
         let length_range: &Range = self.length();
 
@@ -1335,6 +1896,7 @@ impl ConstraintValidator for ByteLengthConstraint {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         // get the size of given bytes
         let size = value
@@ -1358,6 +1920,20 @@ impl ConstraintValidator for ByteLengthConstraint {
 
         Ok(())
     }
+
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        value
+            .element_of_type(&[IonType::Blob, IonType::Clob])
+            .is_some_and(|element| {
+                let size = element.as_lob().unwrap().len();
+                self.length().contains(&(size as i64).into())
+            })
+    }
 }
 
 /// Implements an `codepoint_length` constraint of Ion Schema
@@ -1383,6 +1959,7 @@ impl ConstraintValidator for CodepointLengthConstraint {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         // get the size of given string/symbol Unicode codepoints
         let size = value
@@ -1411,6 +1988,20 @@ impl ConstraintValidator for CodepointLengthConstraint {
 
         Ok(())
     }
+
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        value
+            .element_of_type(&[IonType::String, IonType::Symbol])
+            .is_some_and(|element| {
+                let size = element.as_text().unwrap().chars().count();
+                self.length().contains(&(size as i64).into())
+            })
+    }
 }
 
 /// Implements the `element` constraint
@@ -1430,6 +2021,50 @@ impl ElementConstraint {
             required_distinct_elements,
         }
     }
+
+    pub fn type_reference(&self) -> TypeReference {
+        self.type_reference
+    }
+
+    /// Provides boolean value indicating whether the elements are required to be distinct
+    pub fn required_distinct_elements(&self) -> bool {
+        self.required_distinct_elements
+    }
+
+    /// Starts validating the elements of a container against this constraint, one element at a time
+    pub(crate) fn start_validation(&self) -> ElementValidation<'_> {
+        ElementValidation {
+            constraint: self,
+            distinct_elements: vec![],
+            violations: vec![],
+        }
+    }
+
+    /// Checks whether all the given elements of a container satisfy this constraint
+    fn are_valid_elements<'a, I: Iterator<Item = &'a Element>>(
+        &self,
+        elements: I,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        // this is only populated when distinct elements are required
+        let mut distinct_elements: Vec<&Element> = vec![];
+        for element in elements {
+            if !self
+                .type_reference
+                .is_valid(element.into(), type_store, context)
+            {
+                return false;
+            }
+            if self.required_distinct_elements {
+                if distinct_elements.contains(&element) {
+                    return false;
+                }
+                distinct_elements.push(element);
+            }
+        }
+        true
+    }
 }
 
 impl ConstraintValidator for ElementConstraint {
@@ -1438,12 +2073,8 @@ impl ConstraintValidator for ElementConstraint {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
-        let mut violations: Vec<Violation> = vec![];
-
-        // create a set for checking duplicate elements
-        let mut element_set = vec![];
-
         // get elements for given container in the form (ion_path_element, element_value)
         let elements: Vec<(IonPathElement, &Element)> = match value {
             IonSchemaElement::SingleElement(element) => {
@@ -1499,35 +2130,101 @@ impl ConstraintValidator for ElementConstraint {
         };
 
         // validate element constraint
+        let mut validation = self.start_validation();
         for (ion_path_element, val) in elements {
-            ion_path.push(ion_path_element);
-            let schema_element: IonSchemaElement = val.into();
+            validation.validate_next(ion_path_element, val, type_store, ion_path, context);
+        }
+        validation.finish(ion_path)
+    }
 
-            if let Err(violation) =
-                self.type_reference
-                    .validate(&schema_element, type_store, ion_path)
-            {
-                violations.push(violation);
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        match value {
+            IonSchemaElementRef::SingleElement(element) if element.is_null() => false,
+            IonSchemaElementRef::SingleElement(element) => match element.ion_type() {
+                IonType::List | IonType::SExp => self.are_valid_elements(
+                    element.as_sequence().unwrap().elements(),
+                    type_store,
+                    context,
+                ),
+                IonType::Struct => self.are_valid_elements(
+                    element.as_struct().unwrap().iter().map(|(_, value)| value),
+                    type_store,
+                    context,
+                ),
+                _ => false,
+            },
+            IonSchemaElementRef::Document(document) => {
+                self.are_valid_elements(document.iter(), type_store, context)
             }
-            if self.required_distinct_elements && element_set.contains(&val) {
-                violations.push(Violation::new(
-                    "element",
-                    ViolationCode::ElementNotDistinct,
-                    format!("expected distinct elements but found duplicate element {val}",),
-                    ion_path,
-                ))
+        }
+    }
+}
+
+/// Represents an in-progress validation of an `element` constraint,
+/// where the elements of the container are provided one at a time.
+#[derive(Debug, Clone)]
+pub(crate) struct ElementValidation<'a> {
+    constraint: &'a ElementConstraint,
+    // a set for checking duplicate elements, this is only populated when distinct elements are required
+    distinct_elements: Vec<Element>,
+    violations: Vec<Violation>,
+}
+
+impl<'a> ElementValidation<'a> {
+    /// Validates the next element of the container
+    pub(crate) fn validate_next(
+        &mut self,
+        ion_path_element: IonPathElement,
+        val: &Element,
+        type_store: &TypeStore,
+        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
+    ) {
+        if context.should_stop(&self.violations) {
+            return;
+        }
+        ion_path.push(ion_path_element);
+        let schema_element: IonSchemaElement = val.into();
+
+        if let Err(violation) =
+            self.constraint
+                .type_reference
+                .validate(&schema_element, type_store, ion_path, context)
+        {
+            context.push_violation(&mut self.violations, violation);
+        }
+        if self.constraint.required_distinct_elements {
+            if self.distinct_elements.contains(val) {
+                context.push_violation(
+                    &mut self.violations,
+                    Violation::new(
+                        "element",
+                        ViolationCode::ElementNotDistinct,
+                        format!("expected distinct elements but found duplicate element {val}",),
+                        ion_path,
+                    ),
+                )
+            } else {
+                self.distinct_elements.push(val.to_owned());
             }
-            element_set.push(val);
-            ion_path.pop();
         }
+        ion_path.pop();
+    }
 
-        if !violations.is_empty() {
+    /// Returns the result of the validation once all the elements of the container have been provided
+    pub(crate) fn finish(self, ion_path: &mut IonPath) -> ValidationResult {
+        if !self.violations.is_empty() {
             return Err(Violation::with_violations(
                 "element",
                 ViolationCode::ElementMismatched,
                 "one or more elements don't satisfy element constraint",
                 ion_path,
-                violations,
+                self.violations,
             ));
         }
         Ok(())
@@ -1547,6 +2244,10 @@ impl AnnotationsConstraint2_0 {
     pub fn new(type_ref: TypeReference) -> Self {
         Self { type_ref }
     }
+
+    pub fn type_reference(&self) -> TypeReference {
+        self.type_ref
+    }
 }
 
 impl ConstraintValidator for AnnotationsConstraint2_0 {
@@ -1555,6 +2256,7 @@ impl ConstraintValidator for AnnotationsConstraint2_0 {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         match value {
             IonSchemaElement::SingleElement(element) => {
@@ -1566,7 +2268,7 @@ impl ConstraintValidator for AnnotationsConstraint2_0 {
                     .into();
 
                 self.type_ref
-                    .validate(&schema_element, type_store, ion_path)
+                    .validate(&schema_element, type_store, ion_path, context)
                     .map_err(|v| {
                         Violation::with_violations(
                             "annotations",
@@ -1588,6 +2290,34 @@ impl ConstraintValidator for AnnotationsConstraint2_0 {
             }
         }
     }
+
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        match value {
+            IonSchemaElementRef::SingleElement(element) if element.annotations().is_empty() => self
+                .type_ref
+                .is_valid(IonSchemaElementRef::Document(&[]), type_store, context),
+            IonSchemaElementRef::SingleElement(element) => {
+                // the annotations are validated as a list of symbols, which are copied into a buffer
+                // that is reused for the other values checked with the same context
+                let mut annotations = context.take_annotations_buffer();
+                annotations.extend(element.annotations().iter().map(Element::symbol));
+                let is_valid = self.type_ref.is_valid(
+                    IonSchemaElementRef::Document(&annotations),
+                    type_store,
+                    context,
+                );
+                context.restore_annotations_buffer(annotations);
+                is_valid
+            }
+            // document type can not have annotations
+            IonSchemaElementRef::Document(_) => false,
+        }
+    }
 }
 
 /// Implements the `annotations` constraint
@@ -1610,6 +2340,20 @@ impl AnnotationsConstraint {
         }
     }
 
+    /// Provides boolean value indicating whether annotations other than the specified annotations are allowed or not
+    pub fn is_closed(&self) -> bool {
+        self.is_closed
+    }
+
+    /// Provides boolean value indicating whether the annotations must appear in the specified order
+    pub fn is_ordered(&self) -> bool {
+        self.is_ordered
+    }
+
+    pub fn annotations(&self) -> &[Annotation] {
+        &self.annotations
+    }
+
     // Find the required expected annotation from value annotations
     // This is a helper method used by validate_ordered_annotations
     pub fn find_expected_annotation<'a, I: Iterator<Item = &'a str>>(
@@ -1690,6 +2434,51 @@ impl AnnotationsConstraint {
         Ok(())
     }
 
+    /// Checks whether the annotations of the given value satisfy this constraint
+    /// when it has list-level `ordered` annotation
+    fn has_valid_ordered_annotations(&self, value: &Element) -> bool {
+        let mut value_annotations = value
+            .annotations()
+            .iter()
+            .map(|sym| sym.text().unwrap())
+            .peekable();
+
+        for expected_annotation in &self.annotations {
+            if let Some(actual_annotation) = value_annotations.peek() {
+                if expected_annotation.is_required()
+                    && expected_annotation.value() != actual_annotation
+                {
+                    if !self.find_expected_annotation(&mut value_annotations, expected_annotation) {
+                        return false;
+                    }
+                } else if expected_annotation.value() == actual_annotation {
+                    let _ = value_annotations.next();
+                }
+            } else if expected_annotation.is_required() {
+                return false;
+            }
+        }
+
+        !(self.is_closed && value_annotations.peek().is_some())
+    }
+
+    /// Checks whether the annotations of the given value satisfy this constraint
+    /// when it doesn't have list-level `ordered` annotation
+    fn has_valid_unordered_annotations(&self, value: &Element) -> bool {
+        let has_required_annotations = self.annotations.iter().all(|expected_annotation| {
+            !expected_annotation.is_required()
+                || value.annotations().contains(expected_annotation.value())
+        });
+
+        has_required_annotations
+            && (!self.is_closed
+                || value.annotations().iter().all(|v| {
+                    self.annotations
+                        .iter()
+                        .any(|expected_ann| v.text() == Some(expected_ann.value()))
+                }))
+    }
+
     pub fn validate_unordered_annotations(
         &self,
         value: &Element,
@@ -1754,6 +2543,7 @@ impl ConstraintValidator for AnnotationsConstraint {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         let violations: Vec<Violation> = vec![];
 
@@ -1779,6 +2569,24 @@ impl ConstraintValidator for AnnotationsConstraint {
             }
         }
     }
+
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        match value {
+            IonSchemaElementRef::SingleElement(element) if self.is_ordered => {
+                self.has_valid_ordered_annotations(element)
+            }
+            IonSchemaElementRef::SingleElement(element) => {
+                self.has_valid_unordered_annotations(element)
+            }
+            // document type can not have annotations
+            IonSchemaElementRef::Document(_) => false,
+        }
+    }
 }
 
 /// Implements Ion Schema's `precision` constraint
@@ -1804,6 +2612,7 @@ impl ConstraintValidator for PrecisionConstraint {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         // get precision of decimal value
         let value_precision = value
@@ -1827,6 +2636,20 @@ impl ConstraintValidator for PrecisionConstraint {
 
         Ok(())
     }
+
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        value
+            .element_of_type(&[IonType::Decimal])
+            .is_some_and(|element| {
+                let value_precision = element.as_decimal().unwrap().precision();
+                self.precision().contains(&(value_precision as i64).into())
+            })
+    }
 }
 
 /// Implements Ion Schema's `scale` constraint
@@ -1852,6 +2675,7 @@ impl ConstraintValidator for ScaleConstraint {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         // get scale of decimal value
         let value_scale = value
@@ -1875,6 +2699,20 @@ impl ConstraintValidator for ScaleConstraint {
 
         Ok(())
     }
+
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        value
+            .element_of_type(&[IonType::Decimal])
+            .is_some_and(|element| {
+                let value_scale = element.as_decimal().unwrap().scale();
+                self.scale().contains(&(value_scale).into())
+            })
+    }
 }
 
 /// Implements Ion Schema's `exponent` constraint
@@ -1900,6 +2738,7 @@ impl ConstraintValidator for ExponentConstraint {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         // get exponent of decimal value
         let value_exponent = value
@@ -1924,6 +2763,20 @@ impl ConstraintValidator for ExponentConstraint {
 
         Ok(())
     }
+
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        value
+            .element_of_type(&[IonType::Decimal])
+            .is_some_and(|element| {
+                let value_exponent = element.as_decimal().unwrap().scale().neg();
+                self.exponent().contains(&(value_exponent).into())
+            })
+    }
 }
 
 /// Implements Ion Schema's `timestamp_precision` constraint
@@ -1951,6 +2804,7 @@ impl ConstraintValidator for TimestampPrecisionConstraint {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         // get timestamp value
         let timestamp_value = value
@@ -1962,7 +2816,7 @@ impl ConstraintValidator for TimestampPrecisionConstraint {
         let precision_range: &Range = self.timestamp_precision();
 
         // return a Violation if the value didn't follow timestamp precision constraint
-        if !precision_range.contains(&(timestamp_value.to_owned()).into()) {
+        if !precision_range.contains_timestamp_precision(timestamp_value) {
             return Err(Violation::new(
                 "precision",
                 ViolationCode::InvalidLength,
@@ -1977,6 +2831,21 @@ impl ConstraintValidator for TimestampPrecisionConstraint {
 
         Ok(())
     }
+
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        value
+            .element_of_type(&[IonType::Timestamp])
+            .is_some_and(|element| {
+                let timestamp_value = element.as_timestamp().unwrap();
+                self.timestamp_precision()
+                    .contains_timestamp_precision(timestamp_value)
+            })
+    }
 }
 
 /// Implements Ion Schema's `valid_values` constraint
@@ -2002,6 +2871,32 @@ impl ValidValuesConstraint {
             valid_values: valid_values?,
         })
     }
+
+    pub fn valid_values(&self) -> &[ValidValue] {
+        &self.valid_values
+    }
+
+    /// Verifies if the given value is one of the valid values
+    fn contains_value(&self, value: &Element) -> bool {
+        self.valid_values
+            .iter()
+            .any(|valid_value| match valid_value {
+                ValidValue::Range(range) => {
+                    matches!(
+                        value.ion_type(),
+                        IonType::Int | IonType::Float | IonType::Decimal | IonType::Timestamp
+                    ) && range.contains(value)
+                }
+                ValidValue::Element(element) => {
+                    // get value without annotations
+                    let value: IonData<_> = value.value().into();
+                    let actual_value: IonData<_> = element.value().into();
+
+                    // this comparison uses the Ion equivalence based on Ion specification
+                    actual_value == value
+                }
+            })
+    }
 }
 
 impl Display for ValidValuesConstraint {
@@ -2024,33 +2919,12 @@ impl ConstraintValidator for ValidValuesConstraint {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         match value {
             IonSchemaElement::SingleElement(value) => {
-                for valid_value in &self.valid_values {
-                    match valid_value {
-                        ValidValue::Range(range) => match value.ion_type() {
-                            IonType::Int
-                            | IonType::Float
-                            | IonType::Decimal
-                            | IonType::Timestamp => {
-                                if range.contains(value) {
-                                    return Ok(());
-                                }
-                            }
-                            _ => {}
-                        },
-                        ValidValue::Element(element) => {
-                            // get value without annotations
-                            let value: IonData<_> = value.value().into();
-                            let actual_value: IonData<_> = element.value().into();
-
-                            // this comparison uses the Ion equivalence based on Ion specification
-                            if actual_value == value {
-                                return Ok(());
-                            }
-                        }
-                    };
+                if self.contains_value(value) {
+                    return Ok(());
                 }
                 Err(Violation::new(
                     "valid_values",
@@ -2073,6 +2947,17 @@ impl ConstraintValidator for ValidValuesConstraint {
             )),
         }
     }
+
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        value
+            .as_element()
+            .is_some_and(|element| self.contains_value(element))
+    }
 }
 
 /// Implements Ion Schema's `regex` constraint
@@ -2093,6 +2978,31 @@ impl RegexConstraint {
         }
     }
 
+    /// Provides the regular expression of this constraint, translated from the ISL regex syntax to the syntax
+    /// of the [regex] crate, e.g. `\w` is translated to `[a-zA-Z0-9_]`
+    pub fn expression(&self) -> &str {
+        self.expression.as_str()
+    }
+
+    /// Provides boolean value indicating whether the regular expression is case insensitive (`i` flag)
+    pub fn case_insensitive(&self) -> bool {
+        self.case_insensitive
+    }
+
+    /// Provides boolean value indicating whether `^` and `$` match at line boundaries (`m` flag)
+    pub fn multiline(&self) -> bool {
+        self.multiline
+    }
+
+    /// Replaces each `\r` in the given text with `\n`, the text is only copied when it contains a `\r`
+    fn normalize_line_endings(text: &str) -> Cow<'_, str> {
+        if text.contains('\r') {
+            Cow::Owned(text.replace('\r', "\n"))
+        } else {
+            Cow::Borrowed(text)
+        }
+    }
+
     fn from_isl(isl_regex: &IslRegexConstraint, isl_version: IslVersion) -> IonSchemaResult<Self> {
         let pattern =
             RegexConstraint::convert_to_pattern(isl_regex.expression().to_owned(), isl_version)?;
@@ -2173,10 +3083,8 @@ impl RegexConstraint {
         while let Some(ch) = si.next() {
             sb.push(ch);
             match ch {
-                '&' => {
-                    if si.peek() == Some(&'&') {
-                        return invalid_schema_error("'&&' is not supported in a character class");
-                    }
+                '&' if si.peek() == Some(&'&') => {
+                    return invalid_schema_error("'&&' is not supported in a character class");
                 }
                 '[' => return invalid_schema_error("'[' must be escaped within a character class"),
                 '\\' => {
@@ -2283,6 +3191,7 @@ impl ConstraintValidator for RegexConstraint {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         // get string value and return violation if its not a string or symbol type
         let string_value = value
@@ -2290,13 +3199,10 @@ impl ConstraintValidator for RegexConstraint {
             .as_text()
             .unwrap();
 
-        // create regular expression
-        let re = Regex::new(r"\r").unwrap();
-        let result = re.replace_all(string_value, "\n");
-        let value = result.to_string();
+        let value = RegexConstraint::normalize_line_endings(string_value);
 
         // verify if given value matches regular expression
-        if !self.expression.is_match(value.as_str()) {
+        if !self.expression.is_match(&value) {
             return Err(Violation::new(
                 "regex",
                 ViolationCode::RegexMismatched,
@@ -2307,6 +3213,20 @@ impl ConstraintValidator for RegexConstraint {
 
         Ok(())
     }
+
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        value
+            .element_of_type(&[IonType::String, IonType::Symbol])
+            .is_some_and(|element| {
+                let value = RegexConstraint::normalize_line_endings(element.as_text().unwrap());
+                self.expression.is_match(&value)
+            })
+    }
 }
 
 impl PartialEq for RegexConstraint {
@@ -2340,6 +3260,7 @@ impl ConstraintValidator for Utf8ByteLengthConstraint {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         // get the size of given bytes
         let size = value
@@ -2367,6 +3288,20 @@ impl ConstraintValidator for Utf8ByteLengthConstraint {
 
         Ok(())
     }
+
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        value
+            .element_of_type(&[IonType::String, IonType::Symbol])
+            .is_some_and(|element| {
+                let size = element.as_text().unwrap().len();
+                self.length().contains(&(size as i64).into())
+            })
+    }
 }
 
 /// Implements Ion Schema's `timestamp_offset` constraint
@@ -2392,6 +3327,7 @@ impl ConstraintValidator for TimestampOffsetConstraint {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         // get timestamp value
         let timestamp_value = value
@@ -2421,6 +3357,21 @@ impl ConstraintValidator for TimestampOffsetConstraint {
 
         Ok(())
     }
+
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        value
+            .element_of_type(&[IonType::Timestamp])
+            .is_some_and(|element| {
+                let timestamp_value = element.as_timestamp().unwrap();
+                self.valid_offsets()
+                    .contains(&timestamp_value.offset().into())
+            })
+    }
 }
 
 /// Implements Ion Schema's `ieee754_float` constraint
@@ -2438,6 +3389,24 @@ impl Ieee754FloatConstraint {
     pub fn interchange_format(&self) -> Ieee754InterchangeFormat {
         self.interchange_format
     }
+
+    /// Verifies if the given float value can be losslessly represented by the interchange format
+    fn is_representable(&self, float_value: f64) -> bool {
+        if !float_value.is_finite() {
+            return true;
+        }
+
+        match self.interchange_format {
+            Ieee754InterchangeFormat::Binary16 => {
+                half::f16::from_f64(float_value).to_f64() == float_value
+            }
+            Ieee754InterchangeFormat::Binary32 => float_value
+                .to_f32()
+                .and_then(|f32_value| f32_value.to_f64().map(|f64_value| f64_value == float_value))
+                .unwrap_or(false),
+            Ieee754InterchangeFormat::Binary64 => true,
+        }
+    }
 }
 
 impl ConstraintValidator for Ieee754FloatConstraint {
@@ -2446,6 +3415,7 @@ impl ConstraintValidator for Ieee754FloatConstraint {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         // get ieee interchange format value
         let float_value = value
@@ -2453,22 +3423,7 @@ impl ConstraintValidator for Ieee754FloatConstraint {
             .as_float()
             .unwrap();
 
-        if !float_value.is_finite() {
-            return Ok(());
-        }
-
-        let is_valid = match self.interchange_format {
-            Ieee754InterchangeFormat::Binary16 => {
-                half::f16::from_f64(float_value).to_f64() == float_value
-            }
-            Ieee754InterchangeFormat::Binary32 => float_value
-                .to_f32()
-                .and_then(|f32_value| f32_value.to_f64().map(|f64_value| f64_value == float_value))
-                .unwrap_or(false),
-            Ieee754InterchangeFormat::Binary64 => true,
-        };
-
-        if is_valid {
+        if self.is_representable(float_value) {
             Ok(())
         } else {
             Err(Violation::new(
@@ -2482,4 +3437,15 @@ impl ConstraintValidator for Ieee754FloatConstraint {
             ))
         }
     }
+
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        value
+            .element_of_type(&[IonType::Float])
+            .is_some_and(|element| self.is_representable(element.as_float().unwrap()))
+    }
 }
@@ -0,0 +1,165 @@
+//! Provides the [`DependencyGraph`] of a schema, i.e. the schemas that it imports directly or transitively.
+//!
+//! ## Example usage of `dependency_graph` module to export the imports of a schema as [Graphviz DOT]:
+//! ```
+//! use ion_schema::authority::MapDocumentAuthority;
+//! use ion_schema::system::SchemaSystem;
+//!
+//! let authority = MapDocumentAuthority::new([
+//!     (
+//!         "sample.isl",
+//!         r#"
+//!             schema_header::{ imports: [{ id: "numbers.isl", type: positive_int, as: count }] }
+//!             type::{ name: counts, type: list, element: count }
+//!             schema_footer::{}
+//!         "#,
+//!     ),
+//!     ("numbers.isl", "type::{ name: positive_int, type: int, valid_values: range::[1, max] }"),
+//! ]);
+//! let mut schema_system = SchemaSystem::new(vec![Box::new(authority)]);
+//!
+//! let dependency_graph = schema_system.dependency_graph("sample.isl").unwrap();
+//! assert_eq!(dependency_graph.schema_ids(), ["sample.isl", "numbers.isl"]);
+//! assert_eq!(
+//!     dependency_graph.to_dot(),
+//!     r#"digraph "sample.isl" {
+//!     "sample.isl";
+//!     "numbers.isl";
+//!     "sample.isl" -> "numbers.isl" [label="positive_int as count"];
+//! }
+//! "#
+//! );
+//! ```
+//!
+//! [Graphviz DOT]: https://graphviz.org/doc/info/lang.html
+
+use crate::isl::isl_import::IslImport;
+use std::fmt::Write;
+
+/// Represents the transitive import graph of a schema, where the nodes are schema ids and each edge
+/// represents the imports of a schema from another schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyGraph {
+    schema_ids: Vec<String>,
+    dependencies: Vec<Dependency>,
+}
+
+impl DependencyGraph {
+    pub(crate) fn new(schema_ids: Vec<String>, dependencies: Vec<Dependency>) -> Self {
+        Self {
+            schema_ids,
+            dependencies,
+        }
+    }
+
+    /// Returns the id of the schema whose dependencies are represented by this graph
+    pub fn root_schema_id(&self) -> &str {
+        &self.schema_ids[0]
+    }
+
+    /// Returns the ids of all the schemas in this graph, starting with the root schema id
+    /// and followed by the imported schema ids in the order they were discovered
+    pub fn schema_ids(&self) -> &[String] {
+        &self.schema_ids
+    }
+
+    /// Returns the edges of this graph
+    pub fn dependencies(&self) -> &[Dependency] {
+        &self.dependencies
+    }
+
+    /// Returns the edges of this graph that start from the schema with given id,
+    /// i.e. the direct dependencies of that schema
+    pub fn dependencies_of<'a>(&'a self, id: &'a str) -> impl Iterator<Item = &'a Dependency> {
+        self.dependencies
+            .iter()
+            .filter(move |dependency| dependency.schema_id() == id)
+    }
+
+    /// Returns this graph in [Graphviz DOT] format, where each edge is labelled with the imported type names
+    /// and their aliases, or with `*` for the imports of all the types of a schema.
+    ///
+    /// [Graphviz DOT]: https://graphviz.org/doc/info/lang.html
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        // writing to a String never fails
+        let _ = writeln!(dot, "digraph {} {{", dot_id(self.root_schema_id()));
+        for schema_id in &self.schema_ids {
+            let _ = writeln!(dot, "    {};", dot_id(schema_id));
+        }
+        for dependency in &self.dependencies {
+            let label: Vec<String> = dependency
+                .imports()
+                .iter()
+                .map(|import| match import {
+                    IslImport::Schema(_) => "*".to_owned(),
+                    IslImport::Type(import_type) | IslImport::TypeAlias(import_type) => {
+                        match import_type.alias() {
+                            Some(alias) => format!("{} as {alias}", import_type.type_name()),
+                            None => import_type.type_name().to_owned(),
+                        }
+                    }
+                })
+                .collect();
+            let _ = writeln!(
+                dot,
+                "    {} -> {} [label={}];",
+                dot_id(dependency.schema_id()),
+                dot_id(dependency.imported_schema_id()),
+                dot_id(&label.join("\n"))
+            );
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+// Returns given text as a quoted DOT identifier
+fn dot_id(text: &str) -> String {
+    format!(
+        "\"{}\"",
+        text.replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+    )
+}
+
+/// Represents an edge of a [`DependencyGraph`], i.e. all the imports of a schema from another schema,
+/// including the inline imports of its type definitions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dependency {
+    schema_id: String,
+    imported_schema_id: String,
+    imports: Vec<IslImport>,
+}
+
+impl Dependency {
+    pub(crate) fn new(schema_id: String, imported_schema_id: String) -> Self {
+        Self {
+            schema_id,
+            imported_schema_id,
+            imports: vec![],
+        }
+    }
+
+    pub(crate) fn add_import(&mut self, import: IslImport) {
+        if !self.imports.contains(&import) {
+            self.imports.push(import);
+        }
+    }
+
+    /// Returns the id of the importing schema
+    pub fn schema_id(&self) -> &str {
+        &self.schema_id
+    }
+
+    /// Returns the id of the imported schema
+    pub fn imported_schema_id(&self) -> &str {
+        &self.imported_schema_id
+    }
+
+    /// Returns the imports of the importing schema from the imported schema, in the order they appear in the schema
+    pub fn imports(&self) -> &[IslImport] {
+        &self.imports
+    }
+}
@@ -1,6 +1,85 @@
-// TODO: Implement Import for schema
+//! Represents an [`Import`] of a [`Schema`], i.e. all the types imported by a schema from one schema id.
+//!
+//! [`Schema`]: crate::schema::Schema
+
+use crate::system::{TypeId, TypeStore};
+use crate::types::TypeDefinition;
+use std::sync::Arc;
+
 /// Implementation of [Import] that represents all the types imported by a Schema from one schema id.
 #[derive(Debug, Clone)]
-pub struct Import {}
+pub struct Import {
+    id: String,
+    types: Vec<ImportedType>,
+}
+
+impl Import {
+    pub(crate) fn new<A: AsRef<str>>(id: A, types: Vec<ImportedType>) -> Self {
+        Self {
+            id: id.as_ref().to_owned(),
+            types,
+        }
+    }
+
+    /// Returns the id of the imported schema
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the imported type with given name, where the name is the alias of the type if it was imported with one.
+    pub fn get_type<A: AsRef<str>>(&self, name: A) -> Option<&ImportedType> {
+        self.types
+            .iter()
+            .find(|imported_type| imported_type.local_name() == name.as_ref())
+    }
+
+    /// Returns the types imported from the schema
+    pub fn types(&self) -> &[ImportedType] {
+        &self.types
+    }
+}
+
+/// Represents a single type imported by a schema, along with the alias it was imported with
+#[derive(Debug, Clone)]
+pub struct ImportedType {
+    name: String,
+    alias: Option<String>,
+    type_id: TypeId,
+    type_store: Arc<TypeStore>,
+}
+
+impl ImportedType {
+    pub(crate) fn new(
+        name: String,
+        alias: Option<String>,
+        type_id: TypeId,
+        type_store: Arc<TypeStore>,
+    ) -> Self {
+        Self {
+            name,
+            alias,
+            type_id,
+            type_store,
+        }
+    }
+
+    /// Returns the name of the type in the imported schema
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the alias of the type, if it was imported with one using `as`
+    pub fn alias(&self) -> Option<&str> {
+        self.alias.as_deref()
+    }
+
+    /// Returns the name that refers to this type within the importing schema
+    pub fn local_name(&self) -> &str {
+        self.alias.as_deref().unwrap_or(&self.name)
+    }
 
-impl Import {}
+    /// Returns the [`TypeDefinition`] of the imported type
+    pub fn type_definition(&self) -> TypeDefinition {
+        TypeDefinition::new(self.type_id, Arc::clone(&self.type_store))
+    }
+}
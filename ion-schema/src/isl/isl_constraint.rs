@@ -109,7 +109,7 @@ pub mod v_1_0 {
     {
         IslConstraint::new(
             IslVersion::V1_0,
-            IslConstraintImpl::Fields(fields.map(|(s, t)| (s, t)).collect(), false),
+            IslConstraintImpl::Fields(fields.collect(), false),
         )
     }
 
@@ -353,7 +353,7 @@ pub mod v_2_0 {
     {
         IslConstraint::new(
             IslVersion::V2_0,
-            IslConstraintImpl::Fields(fields.map(|(s, t)| (s, t)).collect(), false),
+            IslConstraintImpl::Fields(fields.collect(), false),
         )
     }
 
@@ -569,6 +569,37 @@ pub(crate) enum IslConstraintImpl {
 }
 
 impl IslConstraintImpl {
+    /// Returns the field name that is used to define this constraint in a type definition
+    pub(crate) fn field_name(&self) -> &str {
+        match self {
+            IslConstraintImpl::AllOf(_) => "all_of",
+            IslConstraintImpl::Annotations(_) => "annotations",
+            IslConstraintImpl::AnyOf(_) => "any_of",
+            IslConstraintImpl::ByteLength(_) => "byte_length",
+            IslConstraintImpl::CodepointLength(_) => "codepoint_length",
+            IslConstraintImpl::Contains(_) => "contains",
+            IslConstraintImpl::ContentClosed => "content",
+            IslConstraintImpl::ContainerLength(_) => "container_length",
+            IslConstraintImpl::Element(_, _) => "element",
+            IslConstraintImpl::Exponent(_) => "exponent",
+            IslConstraintImpl::Fields(_, _) => "fields",
+            IslConstraintImpl::FieldNames(_, _) => "field_names",
+            IslConstraintImpl::Ieee754Float(_) => "ieee754_float",
+            IslConstraintImpl::Not(_) => "not",
+            IslConstraintImpl::OneOf(_) => "one_of",
+            IslConstraintImpl::OrderedElements(_) => "ordered_elements",
+            IslConstraintImpl::Precision(_) => "precision",
+            IslConstraintImpl::Regex(_) => "regex",
+            IslConstraintImpl::Scale(_) => "scale",
+            IslConstraintImpl::TimestampOffset(_) => "timestamp_offset",
+            IslConstraintImpl::TimestampPrecision(_) => "timestamp_precision",
+            IslConstraintImpl::Type(_) => "type",
+            IslConstraintImpl::Unknown(field_name, _) => field_name,
+            IslConstraintImpl::Utf8ByteLength(_) => "utf8_byte_length",
+            IslConstraintImpl::ValidValues(_) => "valid_values",
+        }
+    }
+
     /// Parse constraints inside an [Element] to an [IslConstraint]
     pub fn from_ion_element(
         isl_version: IslVersion,
@@ -613,10 +644,10 @@ impl IslConstraintImpl {
                         IslAnnotationsConstraint::StandardAnnotations(type_reference),
                     ))
                 } else {
-                    return invalid_schema_error(format!(
+                    invalid_schema_error(format!(
                         "annotations constraint was a {:?} instead of a list",
                         value.ion_type()
-                    ));
+                    ))
                 }
             }
             "any_of" => {
@@ -92,6 +92,17 @@ impl Range {
         }
     }
 
+    /// Provides a boolean value to specify whether the precision of the given timestamp is within the range or not,
+    /// like [`contains`](Self::contains) without converting the timestamp into an [`Element`]
+    pub(crate) fn contains_timestamp_precision(&self, value: &Timestamp) -> bool {
+        match self {
+            Range::TimestampPrecision(timestamp_precision_range) => {
+                timestamp_precision_range.contains(TimestampPrecision::from_timestamp(value))
+            }
+            _ => self.contains(&value.to_owned().into()),
+        }
+    }
+
     /// Provides a boolean value to specify whether the given value is within the range or not
     pub fn contains(&self, value: &Element) -> bool {
         if value.is_null() {
@@ -134,13 +145,7 @@ impl Range {
                             return false;
                         }
                     }
-                    IonType::Decimal => {
-                        if let Ok(number_val) = value.as_decimal().unwrap().try_into() {
-                            number_val
-                        } else {
-                            return false;
-                        }
-                    }
+                    IonType::Decimal => value.as_decimal().unwrap().into(),
                     _ => {
                         return false;
                     }
@@ -182,7 +187,7 @@ impl Range {
     ) -> IonSchemaResult<Range> {
         // if an integer value is passed here then convert it into a range
         // eg. if `1` is passed as value then return a range [1,1]
-        return if let Some(integer_value) = value.as_int() {
+        if let Some(integer_value) = value.as_int() {
             match range_type {
                 RangeType::Precision | RangeType::NonNegativeInteger => {
                     let non_negative_integer_value =
@@ -267,7 +272,7 @@ impl Range {
                 "Ranges can not be constructed for type {}",
                 value.ion_type()
             ))
-        };
+        }
     }
 
     // helper method to which validates a non negative integer range boundary value
@@ -821,34 +826,30 @@ impl TypedRangeBoundaryValue {
                     )),
                 }
             }
-            IonType::Int => {
-                return match range_type {
-                     RangeType::Precision | RangeType::NonNegativeInteger => {
-                         Ok(TypedRangeBoundaryValue::NonNegativeInteger(
-                             RangeBoundaryValue::Value(
-                                 Range::validate_non_negative_integer_range_boundary_value(
-                                     boundary.as_int().unwrap(),
-                                     &range_type,
-                                 )?,
-                                 range_boundary_type,
-                             ),
-                         ))
-                     },
-                     RangeType::Any => {
-                         Ok(TypedRangeBoundaryValue::Integer(RangeBoundaryValue::Value(
-                             boundary.as_int().unwrap().to_owned(),
-                             range_boundary_type,
-                         )))
-                     },
-                     RangeType::TimestampPrecision => invalid_schema_error(
-                    "Timestamp precision ranges can not be constructed for integer boundary values",
+            IonType::Int => match range_type {
+                RangeType::Precision | RangeType::NonNegativeInteger => Ok(
+                    TypedRangeBoundaryValue::NonNegativeInteger(RangeBoundaryValue::Value(
+                        Range::validate_non_negative_integer_range_boundary_value(
+                            boundary.as_int().unwrap(),
+                            &range_type,
+                        )?,
+                        range_boundary_type,
+                    )),
                 ),
-                RangeType::NumberOrTimestamp => Ok(TypedRangeBoundaryValue::Number(RangeBoundaryValue::Value(
-                    boundary.as_int().unwrap().into(),
+                RangeType::Any => Ok(TypedRangeBoundaryValue::Integer(RangeBoundaryValue::Value(
+                    boundary.as_int().unwrap().to_owned(),
                     range_boundary_type,
                 ))),
-                 };
-            }
+                RangeType::TimestampPrecision => invalid_schema_error(
+                    "Timestamp precision ranges can not be constructed for integer boundary values",
+                ),
+                RangeType::NumberOrTimestamp => {
+                    Ok(TypedRangeBoundaryValue::Number(RangeBoundaryValue::Value(
+                        boundary.as_int().unwrap().into(),
+                        range_boundary_type,
+                    )))
+                }
+            },
             IonType::Decimal => match range_type {
                 RangeType::NumberOrTimestamp => {
                     Ok(TypedRangeBoundaryValue::Number(RangeBoundaryValue::Value(
@@ -2,7 +2,10 @@ use crate::isl::isl_constraint::{IslConstraint, IslConstraintImpl};
 use crate::isl::isl_import::IslImportType;
 use crate::isl::IslVersion;
 use crate::isl::WriteToIsl;
-use crate::result::{invalid_schema_error, invalid_schema_error_raw, IonSchemaResult};
+use crate::result::{
+    invalid_schema_error, invalid_schema_error_raw, DiagnosticCollector, IonSchemaError,
+    IonSchemaResult,
+};
 use ion_rs::element::Element;
 use ion_rs::{IonType, IonWriter};
 
@@ -145,6 +148,22 @@ impl IslTypeImpl {
         isl_version: IslVersion,
         ion: &Element,
         inline_imported_types: &mut Vec<IslImportType>, // stores the inline_imports that are discovered while loading this ISL type
+    ) -> IonSchemaResult<Self> {
+        IslTypeImpl::from_owned_element_with(
+            isl_version,
+            ion,
+            inline_imported_types,
+            &mut DiagnosticCollector::fail_fast(),
+        )
+    }
+
+    /// Parse constraints inside an [Element] to an [IslTypeImpl], an invalid constraint is reported
+    /// to the given [DiagnosticCollector] which decides whether the remaining constraints are parsed.
+    pub(crate) fn from_owned_element_with(
+        isl_version: IslVersion,
+        ion: &Element,
+        inline_imported_types: &mut Vec<IslImportType>,
+        collector: &mut DiagnosticCollector,
     ) -> IonSchemaResult<Self> {
         let mut constraints = vec![];
         let contains_annotations = ion.annotations().contains("type");
@@ -202,14 +221,33 @@ impl IslTypeImpl {
                 }
             };
 
-            let constraint = IslConstraintImpl::from_ion_element(
+            let with_location = |error: IonSchemaError| {
+                let error = error.with_constraint(constraint_name);
+                match &type_name {
+                    Some(name) => error.with_type_name(name),
+                    None => error,
+                }
+            };
+            match IslConstraintImpl::from_ion_element(
                 isl_version,
                 constraint_name,
                 value,
                 &isl_type_name,
                 inline_imported_types,
-            )?;
-            constraints.push(constraint);
+            ) {
+                Ok(constraint) => {
+                    if isl_version == IslVersion::V1_0
+                        && matches!(constraint, IslConstraintImpl::Unknown(_, _))
+                    {
+                        // ISL 1.0 allows any field as open content, hence this is most likely a misspelled constraint
+                        collector.warn(with_location(invalid_schema_error_raw(format!(
+                            "`{constraint_name}` is not an ISL 1.0 constraint and is treated as open content"
+                        ))));
+                    }
+                    constraints.push(constraint);
+                }
+                Err(error) => collector.recover(with_location(error))?,
+            }
         }
         Ok(IslTypeImpl::new(
             type_name,
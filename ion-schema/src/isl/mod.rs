@@ -3,16 +3,16 @@
 //! This module consists of three submodules that help constructing ISL types/constraint:
 //!
 //! * `isl_type` module represents a schema type [IslType] which converts given ion content in the schema file
-//! into an ISL types(not-yet-resolved types). It stores `IslConstraint`s defined within the given type.
+//!   into an ISL types(not-yet-resolved types). It stores `IslConstraint`s defined within the given type.
 //!
 //! * `isl_import` module represents a schema import [IslImport] which converts given ion content in the schema file
-//! into an ISL import. It stores schema id, an optional type that needs to be imported and an optional alias to that type.
+//!   into an ISL import. It stores schema id, an optional type that needs to be imported and an optional alias to that type.
 //!
 //! * `isl_constraint` module represents schema constraints [IslConstraint]
-//! which converts given ion content in the schema file into an ISL constraint(not-yet-resolved constraints).
+//!   which converts given ion content in the schema file into an ISL constraint(not-yet-resolved constraints).
 //!
 //! * `isl_type_reference` module provides a schema type reference.
-//! The type reference grammar is defined in the [Ion Schema Specification]
+//!   The type reference grammar is defined in the [Ion Schema Specification]
 //!
 //! [IslConstraint]: isl_constraint::IslConstraint
 //! [Ion Schema Specification]: https://amazon-ion.github.io/ion-schema/docs/isl-1-0/spec#grammar
@@ -395,7 +395,7 @@ mod isl_tests {
     use ion_rs::{IonType, TextWriterBuilder};
     use rstest::*;
     use std::io::Write;
-    use std::path::{Path, MAIN_SEPARATOR};
+    use std::path::{Path, MAIN_SEPARATOR_STR};
     use test_generator::test_resources;
 
     // helper function to create NamedIslType for isl tests using ISL 1.0
@@ -800,7 +800,7 @@ mod isl_tests {
             ).unwrap(),
             NumberRange::new(
                 Number::from(&IntegerValue::I64(1)),
-                Number::try_from(&Decimal::new(55, -1)).unwrap()
+                Number::from(&Decimal::new(55, -1))
             ).unwrap()
         )
     )]
@@ -1051,7 +1051,7 @@ mod isl_tests {
     case::range_with_number(
         NumberRange::new(
             Number::from(&IntegerValue::I64(1)),
-            Number::try_from(&Decimal::new(55, -1)).unwrap()
+            Number::from(&Decimal::new(55, -1))
         ).unwrap(),
         "range::[ 1, 5.5 ]"
     )
@@ -1075,14 +1075,14 @@ mod isl_tests {
     fn is_skip_list_path(file_name: &str) -> bool {
         SKIP_LIST
             .iter()
-            .map(|p| p.replace('/', &MAIN_SEPARATOR.to_string()))
+            .map(|p| p.replace('/', MAIN_SEPARATOR_STR))
             .any(|p| p == file_name)
     }
 
     #[test_resources("ion-schema-tests/**/*.isl")]
     #[test_resources("ion-schema-schemas/**/*.isl")]
     fn test_write_to_isl(file_name: &str) {
-        if is_skip_list_path(&file_name) {
+        if is_skip_list_path(file_name) {
             return;
         }
 
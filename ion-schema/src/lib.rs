@@ -28,15 +28,19 @@ macro_rules! try_to {
 
 // TODO: consider changing some of these modules to public if required
 pub mod authority;
-mod constraint;
-mod import;
+pub mod batch;
+pub mod constraint;
+pub mod dependency_graph;
+pub mod import;
 mod ion_path;
 pub mod isl;
+pub mod limits;
 mod nfa;
 pub mod result;
 pub mod schema;
+pub mod streaming;
 pub mod system;
-mod type_reference;
+pub mod type_reference;
 pub mod types;
 pub mod violation;
 
@@ -45,6 +49,10 @@ pub mod external {
     pub use ion_rs;
 }
 
+/// Embeds a directory tree of schemas in the binary as an [`EmbeddedDocumentAuthority`](authority::EmbeddedDocumentAuthority).
+#[cfg(feature = "embed")]
+pub use ion_schema_macros::include_schemas;
+
 static ISL_VERSION_MARKER_REGEX: OnceLock<Regex> = OnceLock::new();
 static RESERVED_WORD_REGEX: OnceLock<Regex> = OnceLock::new();
 
@@ -212,6 +220,76 @@ impl From<&Vec<Element>> for IonSchemaElement {
     }
 }
 
+/// Provides a borrowed view of an Ion value which can be checked against a type without being cloned
+///
+/// ## Example:
+/// ```
+/// use ion_rs::element::Element;
+/// use ion_schema::IonSchemaElementRef;
+///
+/// // create an IonSchemaElementRef from an Element
+/// let owned_element: Element = 4.into();
+/// let element_ref: IonSchemaElementRef = (&owned_element).into();
+///
+/// // create an IonSchemaElementRef for document type based on vector of owned elements
+/// let elements = vec![owned_element];
+/// let document: IonSchemaElementRef = (&elements).into();
+/// ```
+/// Unlike [`IonSchemaElement`], an [`Element`] annotated with `document` is not converted into a document,
+/// as the document can't be borrowed from a string. [`TypeDefinition::is_valid`] still checks such an element
+/// as a document, like [`TypeDefinition::validate`] does.
+///
+/// [`TypeDefinition::is_valid`]: crate::types::TypeDefinition::is_valid
+/// [`TypeDefinition::validate`]: crate::types::TypeDefinition::validate
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IonSchemaElementRef<'a> {
+    SingleElement(&'a Element),
+    Document(&'a [Element]),
+}
+
+impl<'a> IonSchemaElementRef<'a> {
+    pub fn as_element(&self) -> Option<&'a Element> {
+        match self {
+            IonSchemaElementRef::SingleElement(element) => Some(element),
+            IonSchemaElementRef::Document(_) => None,
+        }
+    }
+
+    pub fn as_document(&self) -> Option<&'a [Element]> {
+        match self {
+            IonSchemaElementRef::SingleElement(_) => None,
+            IonSchemaElementRef::Document(document) => Some(document),
+        }
+    }
+
+    /// Returns the element if it is a non null value of one of the given `types`
+    pub(crate) fn element_of_type(&self, types: &[IonType]) -> Option<&'a Element> {
+        self.as_element()
+            .filter(|element| types.contains(&element.ion_type()) && !element.is_null())
+    }
+}
+
+impl<'a> From<&'a Element> for IonSchemaElementRef<'a> {
+    fn from(value: &'a Element) -> Self {
+        IonSchemaElementRef::SingleElement(value)
+    }
+}
+
+impl<'a> From<&'a Vec<Element>> for IonSchemaElementRef<'a> {
+    fn from(value: &'a Vec<Element>) -> Self {
+        IonSchemaElementRef::Document(value)
+    }
+}
+
+impl<'a> From<&'a IonSchemaElement> for IonSchemaElementRef<'a> {
+    fn from(value: &'a IonSchemaElement) -> Self {
+        match value {
+            IonSchemaElement::SingleElement(element) => IonSchemaElementRef::SingleElement(element),
+            IonSchemaElement::Document(document) => IonSchemaElementRef::Document(document),
+        }
+    }
+}
+
 // helper function to be used by schema tests
 fn load(text: &str) -> Vec<Element> {
     Element::read_all(text.as_bytes()).expect("parsing failed unexpectedly")
@@ -0,0 +1,220 @@
+//! Provides the [`LoadLimits`] that bound the resources used to load a schema.
+//!
+//! The schemas loaded by a [`SchemaSystem`] are not limited by default, limits can be set for schemas
+//! that come from untrusted sources, whose huge lists of values, deeply nested types, giant regexes or huge
+//! number of imports could otherwise exhaust the memory or the stack of the process loading them.
+//! A schema that exceeds a limit fails to load with an [`IonSchemaError::LimitExceededError`].
+//!
+//! The size of a schema is checked by its [`DocumentAuthority`] before the schema is parsed, and the nesting depth is
+//! checked while the schema is parsed so that a deeply nested value doesn't exhaust the stack of the parser.
+//! The other limits are checked on the parsed schema, hence a limit on the size should be set along with the limits
+//! on the number of values. Authorities that don't parse Ion content (e.g. custom authorities that only implement
+//! [`DocumentAuthority::elements`]) can't enforce the size and the nesting depth before the schema is parsed.
+//!
+//! ## Example usage of `limits` module to load a schema from an untrusted source:
+//! ```
+//! use ion_schema::authority::MapDocumentAuthority;
+//! use ion_schema::limits::LoadLimits;
+//! use ion_schema::result::IonSchemaError;
+//! use ion_schema::system::SchemaSystem;
+//!
+//! let authority = MapDocumentAuthority::new([(
+//!     "sample.isl",
+//!     "type::{ name: my_type, type: int, valid_values: [1, 2, 3, 4, 5, 6, 7, 8, 9, 10] }",
+//! )]);
+//! let mut schema_system = SchemaSystem::new(vec![Box::new(authority)]);
+//! schema_system.with_load_limits(
+//!     LoadLimits::default()
+//!         .with_max_total_elements(10)
+//!         .with_max_nesting_depth(8),
+//! );
+//!
+//! let error = schema_system.load_schema("sample.isl").unwrap_err();
+//! assert!(matches!(error, IonSchemaError::LimitExceededError { .. }));
+//! ```
+//!
+//! [`SchemaSystem`]: crate::system::SchemaSystem
+//! [`DocumentAuthority`]: crate::authority::DocumentAuthority
+//! [`DocumentAuthority::elements`]: crate::authority::DocumentAuthority::elements
+//! [`IonSchemaError::LimitExceededError`]: crate::result::IonSchemaError::LimitExceededError
+
+use crate::result::{limit_exceeded_error_raw, IonSchemaResult};
+use crate::streaming::read_current_element_up_to;
+use ion_rs::element::Element;
+use ion_rs::{IonReader, ReaderBuilder, StreamItem};
+
+/// Represents the limits that are enforced while loading a schema, all the limits are disabled by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LoadLimits {
+    max_types: Option<usize>,
+    max_nesting_depth: Option<usize>,
+    max_regex_size: Option<usize>,
+    max_imports: Option<usize>,
+    max_total_elements: Option<usize>,
+    max_schema_size: Option<usize>,
+}
+
+impl LoadLimits {
+    /// Limits the number of type definitions of a schema, including its anonymous type definitions
+    /// but excluding its imported types
+    pub fn with_max_types(mut self, max_types: usize) -> Self {
+        self.max_types = Some(max_types);
+        self
+    }
+
+    /// Limits the nesting depth of the Ion values of a schema, where top level values have a depth of 1
+    /// (e.g. `type::{ name: a, type: int }` has a depth of 2 and `type::{ name: a, element: { type: int } }` has a depth of 3).
+    /// The authorities that parse the Ion content of a schema stop parsing it at the first container nested deeper than
+    /// that, see [`DocumentAuthority::read_elements`].
+    ///
+    /// [`DocumentAuthority::read_elements`]: crate::authority::DocumentAuthority::read_elements
+    pub fn with_max_nesting_depth(mut self, max_nesting_depth: usize) -> Self {
+        self.max_nesting_depth = Some(max_nesting_depth);
+        self
+    }
+
+    /// Limits the length in bytes of the patterns of the `regex` constraints of a schema
+    pub fn with_max_regex_size(mut self, max_regex_size: usize) -> Self {
+        self.max_regex_size = Some(max_regex_size);
+        self
+    }
+
+    /// Limits the number of imports of a schema, including its inline imports
+    pub fn with_max_imports(mut self, max_imports: usize) -> Self {
+        self.max_imports = Some(max_imports);
+        self
+    }
+
+    /// Limits the number of Ion values of a schema, including the nested values
+    /// (e.g. `type::{ name: a, valid_values: [1, 2] }` has 5 values)
+    pub fn with_max_total_elements(mut self, max_total_elements: usize) -> Self {
+        self.max_total_elements = Some(max_total_elements);
+        self
+    }
+
+    /// Limits the size in bytes of the Ion content of a schema. The size is checked by the authority that
+    /// resolves the schema before the content is parsed, see [`DocumentAuthority::read_elements`].
+    ///
+    /// [`DocumentAuthority::read_elements`]: crate::authority::DocumentAuthority::read_elements
+    pub fn with_max_schema_size(mut self, max_schema_size: usize) -> Self {
+        self.max_schema_size = Some(max_schema_size);
+        self
+    }
+
+    /// Returns the maximum number of type definitions of a schema, if it is limited
+    pub fn max_types(&self) -> Option<usize> {
+        self.max_types
+    }
+
+    /// Returns the maximum nesting depth of the Ion values of a schema, if it is limited
+    pub fn max_nesting_depth(&self) -> Option<usize> {
+        self.max_nesting_depth
+    }
+
+    /// Returns the maximum length of the patterns of the `regex` constraints, if it is limited
+    pub fn max_regex_size(&self) -> Option<usize> {
+        self.max_regex_size
+    }
+
+    /// Returns the maximum number of imports of a schema, if it is limited
+    pub fn max_imports(&self) -> Option<usize> {
+        self.max_imports
+    }
+
+    /// Returns the maximum number of Ion values of a schema, if it is limited
+    pub fn max_total_elements(&self) -> Option<usize> {
+        self.max_total_elements
+    }
+
+    /// Returns the maximum size in bytes of the Ion content of a schema, if it is limited
+    pub fn max_schema_size(&self) -> Option<usize> {
+        self.max_schema_size
+    }
+
+    /// Checks given top level value of a schema against the limits on the number of values, the nesting depth
+    /// and the regex size, `total_elements` is the number of values of the schema that were checked so far.
+    pub(crate) fn check_element(
+        &self,
+        element: &Element,
+        total_elements: &mut usize,
+    ) -> IonSchemaResult<()> {
+        // the values are visited with an explicit stack rather than recursively,
+        // as the nesting depth is not known to be limited yet
+        let mut pending_elements = vec![(element, 1)];
+        while let Some((element, depth)) = pending_elements.pop() {
+            *total_elements += 1;
+            check_limit("number of values", *total_elements, self.max_total_elements)?;
+            check_limit("nesting depth", depth, self.max_nesting_depth)?;
+            if let Some(sequence) = element.as_sequence() {
+                pending_elements.extend(sequence.elements().map(|child| (child, depth + 1)));
+            } else if let Some(ion_struct) = element.as_struct() {
+                for (field_name, value) in ion_struct.fields() {
+                    if field_name.text() == Some("regex") {
+                        if let Some(pattern) = value.as_text() {
+                            check_limit("regex size", pattern.len(), self.max_regex_size)?;
+                        }
+                    }
+                    pending_elements.push((value, depth + 1));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks given number of imports of a schema against the limit on the number of imports
+    pub(crate) fn check_imports(&self, imports: usize) -> IonSchemaResult<()> {
+        check_limit("number of imports", imports, self.max_imports)
+    }
+
+    /// Checks given number of type definitions of a schema against the limit on the number of types
+    pub(crate) fn check_types(&self, types: usize) -> IonSchemaResult<()> {
+        check_limit("number of types", types, self.max_types)
+    }
+}
+
+/// Parses all the top level values of the Ion content of the schema with given id. When a maximum nesting depth is
+/// given, the values are parsed without recursion and the parsing stops with a [`LoadLimits::max_nesting_depth`] error
+/// at the first container that is nested deeper than that.
+pub(crate) fn read_all_elements(
+    id: &str,
+    ion_content: &[u8],
+    max_nesting_depth: Option<usize>,
+) -> IonSchemaResult<Vec<Element>> {
+    let max_nesting_depth = match max_nesting_depth {
+        None => return Ok(Element::read_all(ion_content)?),
+        Some(max_nesting_depth) => max_nesting_depth,
+    };
+    let mut reader = ReaderBuilder::new().build(ion_content)?;
+    let mut elements = vec![];
+    while let StreamItem::Value(_) | StreamItem::Null(_) = reader.next()? {
+        match read_current_element_up_to(&mut reader, max_nesting_depth)? {
+            Some(element) => elements.push(element),
+            None => {
+                return Err(limit_exceeded_error_raw(format!(
+                    "Schema {id} exceeds the maximum nesting depth of {max_nesting_depth}"
+                )))
+            }
+        }
+    }
+    Ok(elements)
+}
+
+/// Checks the size in bytes of the Ion content of the schema with given id against given maximum size,
+/// this is used by the authorities to enforce [`LoadLimits::max_schema_size`] before parsing the schema.
+pub(crate) fn check_schema_size(id: &str, size: usize, max_size: usize) -> IonSchemaResult<()> {
+    if size > max_size {
+        return Err(limit_exceeded_error_raw(format!(
+            "Schema {id} exceeds the maximum size of {max_size} bytes"
+        )));
+    }
+    Ok(())
+}
+
+fn check_limit(name: &str, value: usize, limit: Option<usize>) -> IonSchemaResult<()> {
+    match limit {
+        Some(limit) if value > limit => Err(limit_exceeded_error_raw(format!(
+            "Schema exceeds the maximum {name} of {limit}"
+        ))),
+        _ => Ok(()),
+    }
+}
@@ -1,12 +1,8 @@
-use crate::ion_path::IonPath;
 use crate::system::TypeStore;
 use crate::type_reference::TypeReference;
-use crate::types::TypeValidator;
-use crate::IonSchemaElement;
+use crate::types::{TypeValidator, ValidationContext};
 use ion_rs::element::Element;
 use std::collections::{HashMap, HashSet};
-use std::iter::Peekable;
-use std::slice::Iter;
 use std::sync::Arc;
 
 /// Represents an id for a state in NFA
@@ -29,16 +25,13 @@ pub struct Transition {
 
 impl Transition {
     /// Verify if the given Ion value is valid for the transition's type_ref or not
-    pub fn is_valid_for_ion_value(&self, element: &Element, type_store: &TypeStore) -> bool {
-        let schema_element: IonSchemaElement = element.into();
-
-        match self
-            .type_ref
-            .validate(&schema_element, type_store, &mut IonPath::default())
-        {
-            Ok(_) => true,
-            Err(violation) => false,
-        }
+    pub fn is_valid_for_ion_value(
+        &self,
+        element: &Element,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        self.type_ref.is_valid(element.into(), type_store, context)
     }
 
     /// Verifies if a destination state is optional state or not
@@ -77,21 +70,26 @@ impl FinalState {
 pub struct Nfa {
     pub(crate) transitions: HashMap<StateId, HashSet<Transition>>, // represents transitions between states
     pub(crate) final_states: HashSet<FinalState>, // represents all the final states for NFA
+    pub(crate) occurrences: HashMap<StateId, (usize, usize)>, // represents (min, max) occurrences for each state
 }
 
 impl Nfa {
     /// Provides all final states for the [Nfa]
-    pub fn get_final_states(&self) -> HashSet<FinalState> {
-        self.final_states.to_owned()
+    pub fn get_final_states(&self) -> &HashSet<FinalState> {
+        &self.final_states
     }
 
-    // TODO: make get_transitions() less expensive, avoid allocating a new HashSet for each call to the method
     /// Provides all the possible transitions for given state
-    pub fn get_transitions(&self, state_id: StateId) -> HashSet<Transition> {
-        self.transitions
+    pub fn get_transitions(&self, state_id: StateId) -> impl Iterator<Item = &Transition> {
+        self.transitions.get(&state_id).into_iter().flatten()
+    }
+
+    /// Verifies if the minimum occurrence requirement of given state is met for given visits count
+    pub fn allows_exit_after_n_visits(&self, state_id: StateId, visits: usize) -> bool {
+        self.occurrences
             .get(&state_id)
-            .map(|s| s.to_owned())
-            .unwrap_or(HashSet::new())
+            .map(|(min, _)| *min <= visits)
+            .unwrap_or(false)
     }
 }
 
@@ -120,6 +118,8 @@ impl NfaRun {
 #[derive(Debug, Clone)]
 pub struct NfaEvaluation {
     pub(crate) visits: HashSet<NfaRun>,
+    // buffer for the visits of the next step, this is reused across steps to avoid allocating a new set for each element
+    next_visits: HashSet<NfaRun>,
     pub(crate) nfa: Arc<Nfa>,
 }
 
@@ -133,6 +133,7 @@ impl NfaEvaluation {
                 }
                 visits
             },
+            next_visits: HashSet::new(),
             nfa,
         }
     }
@@ -156,26 +157,35 @@ impl NfaEvaluation {
     }
 
     /// Validates provided ordered elements against referenced [Nfa]
-    pub fn validate_ordered_elements(&mut self, elements: &[Element], type_store: &TypeStore) {
-        let mut elements_iter = elements.iter().peekable();
+    pub fn validate_ordered_elements(
+        &mut self,
+        elements: &[Element],
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) {
         // given elements are actually events for the `Nfa` referenced in this `NfaEvaluation`.
-        // iterate through all elements and update state-visit count(`NfaRun`) for all possible transitions for given element(event).
-        while let Some(element) = elements_iter.next() {
-            let mut next_states = HashSet::new();
-            for nfa_run in self.visits.iter() {
-                let transitions: HashSet<Transition> = self.nfa.get_transitions(nfa_run.state_id);
-                // evaluate all possible transitions for nfa_run
-                self.evaluate_transitions(
-                    nfa_run,
-                    transitions,
-                    element,
-                    &mut elements_iter,
-                    type_store,
-                    &mut next_states,
-                );
-            }
-            self.visits = next_states;
+        for element in elements {
+            self.validate_next_element(element, type_store, context);
+        }
+    }
+
+    /// Advances this [NfaEvaluation] by a single element.
+    /// This allows evaluating ordered elements one at a time as they are read (e.g. from a stream),
+    /// without requiring all the elements to be available upfront.
+    pub fn validate_next_element(
+        &mut self,
+        element: &Element,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) {
+        // update state-visit count(`NfaRun`) for all possible transitions for given element(event).
+        let mut next_states = std::mem::take(&mut self.next_visits);
+        for nfa_run in self.visits.iter() {
+            // evaluate all possible transitions for nfa_run
+            self.evaluate_transitions(nfa_run, element, type_store, context, &mut next_states);
         }
+        self.next_visits = std::mem::replace(&mut self.visits, next_states);
+        self.next_visits.clear();
     }
 
     /// Evaluates given transitions using referenced [Nfa]
@@ -183,10 +193,9 @@ impl NfaEvaluation {
     fn evaluate_transitions(
         &self,
         nfa_run: &NfaRun,
-        transitions: HashSet<Transition>,
         current_element: &Element,
-        elements: &mut Peekable<Iter<Element>>,
         type_store: &TypeStore,
+        context: &mut ValidationContext,
         nfa_runs: &mut HashSet<NfaRun>,
     ) {
         let source_state_id = nfa_run.state_id;
@@ -197,110 +206,58 @@ impl NfaEvaluation {
         // - Transition loops back to same state
         // - Transition moves to the next state
         // - Transition moves to optional state
-        for transition in transitions {
-            let destination_state_id = transition.destination;
-
-            // transition which loops back to same state
-            if destination_state_id == source_state_id {
-                if !&self.evaluate_transition_to_self(
-                    visits,
-                    &transition,
+        for transition in self.nfa.get_transitions(source_state_id) {
+            if transition.destination == source_state_id {
+                // transition which loops back to same state
+                if transition.allows_n_visits(visits + 1)
+                    && transition.is_valid_for_ion_value(current_element, type_store, context)
+                {
+                    nfa_runs.insert(NfaRun::new(source_state_id, visits + 1));
+                }
+            } else if self.nfa.allows_exit_after_n_visits(source_state_id, visits) {
+                // transition to next state is only possible once the minimum occurrence of source state is met
+                self.evaluate_transition_to_next_state(
+                    transition,
                     current_element,
-                    elements,
                     type_store,
+                    context,
                     nfa_runs,
-                ) {
-                    // given ordered elements are invalid because it didn't satisfy required minimum occurrence constraint
-                    return;
-                }
-            } else if transition.is_valid_for_ion_value(current_element, type_store) {
-                // if transition is valid, add destination state to next states
-                nfa_runs.insert(NfaRun::new(destination_state_id, 1));
+                );
             }
-
-            // transition to optional state
-            // if destination state is optional then add transitions to next states skipping the optional state
-            self.evaluate_transition_to_optional_state(
-                visits,
-                &transition,
-                current_element,
-                elements,
-                type_store,
-                nfa_runs,
-            );
         }
     }
 
-    // This is a helper method that is used by `evaluate_transitions()` to resolve destination states that are optional
-    // for optional destination states, add transitions to next states skipping the optional state
-    fn evaluate_transition_to_optional_state(
+    // This is a helper method that is used by `evaluate_transitions()` to resolve transitions that move to the next state
+    // for optional destination states, it also adds transitions to next states skipping the optional state
+    fn evaluate_transition_to_next_state(
         &self,
-        visits: usize,
         transition: &Transition,
         element: &Element,
-        elements: &mut Peekable<Iter<Element>>,
         type_store: &TypeStore,
+        context: &mut ValidationContext,
         next_states: &mut HashSet<NfaRun>,
     ) {
-        let mut destination_states_for_optional_state: HashSet<Transition> = HashSet::new();
-
-        if transition.is_destination_state_optional() {
-            let mut transitions = self.nfa.get_transitions(transition.destination);
-
-            // skip the optional state itself
-            transitions.remove(transition);
-
-            destination_states_for_optional_state.extend(transitions);
-
-            self.evaluate_transitions(
-                &NfaRun::new(transition.destination, visits),
-                destination_states_for_optional_state.to_owned(),
-                element,
-                elements,
-                type_store,
-                next_states,
-            );
+        if transition.is_valid_for_ion_value(element, type_store, context) {
+            // if transition is valid, add destination state to next states
+            next_states.insert(NfaRun::new(transition.destination, 1));
         }
-    }
 
-    // this is a  helper method used by `evaluate_transitions` to evaluate transitions that loops back to the same state
-    // this method iterates through elements to satisfy minimum required occurrences for given transition
-    // It will return false if an invalid Ion value is found which doesn't satisfy minimum occurrence requirement for given transition
-    // Otherwise it will return true
-    fn evaluate_transition_to_self(
-        &self,
-        visits: usize,
-        transition: &Transition,
-        element: &Element,
-        elements: &mut Peekable<Iter<Element>>,
-        type_store: &TypeStore,
-        next_states: &mut HashSet<NfaRun>,
-    ) -> bool {
-        let mut visit_count = visits + 1;
-        let mut element = element;
-        if transition.allows_n_visits(visit_count) {
-            if transition.is_valid_for_ion_value(element, type_store) {
-                // if transition is valid, add destination state to next states
-                next_states.insert(NfaRun::new(transition.destination, visit_count));
-            }
-            // iterate through elements for at least minimum n visits of the given transition
-            while !transition.allows_exit_after_n_visits(visit_count) {
-                element = match elements.next() {
-                    None => {
-                        // if the minimum required occurrences for given transition is not met,
-                        // and if the elements iterator is empty then the given Ion value is invalid
-                        return false;
-                    }
-                    Some(element) => element,
-                };
-                if transition.is_valid_for_ion_value(element, type_store) {
-                    visit_count += 1;
-                    // if transition is valid, add destination state to next states
-                    next_states.insert(NfaRun::new(transition.destination, visit_count));
+        // transition to optional state
+        // if destination state is optional then add transitions to next states skipping the optional state
+        if transition.is_destination_state_optional() {
+            for next_transition in self.nfa.get_transitions(transition.destination) {
+                // skip the optional state itself
+                if next_transition.destination != transition.destination {
+                    self.evaluate_transition_to_next_state(
+                        next_transition,
+                        element,
+                        type_store,
+                        context,
+                        next_states,
+                    );
                 }
             }
         }
-        true
     }
 }
 
@@ -329,11 +286,9 @@ impl NfaBuilder {
         min: usize,
         max: usize,
     ) {
-        let end_states = self
-            .nfa
-            .transitions
-            .entry(start_id)
-            .or_insert_with(HashSet::new);
+        let end_states = self.nfa.transitions.entry(start_id).or_default();
+
+        self.nfa.occurrences.insert(end_id, (min, max));
 
         end_states.insert(Transition {
             destination: end_id,
@@ -5,6 +5,8 @@
 
 use crate::violation::Violation;
 use ion_rs::result::IonError;
+use std::fmt;
+use std::fmt::Formatter;
 use std::io;
 use thiserror::Error;
 
@@ -15,7 +17,10 @@ pub type IonSchemaResult<T> = Result<T, IonSchemaError>;
 pub type ValidationResult = Result<(), Violation>;
 
 /// Represents the different types of high-level failures that might occur when reading Ion Schema.
+///
+/// New variants may be added in minor releases, hence a `match` on this enum needs a wildcard arm.
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum IonSchemaError {
     /// Indicates that an io error occurred while loading a schema
     #[error("{source:?}")]
@@ -25,12 +30,45 @@ pub enum IonSchemaError {
     },
 
     /// Indicates failure for schema which has unresolvable imports/types
-    #[error("{description}")]
-    UnresolvableSchemaError { description: String },
+    #[error("{description}{location}")]
+    UnresolvableSchemaError {
+        description: String,
+        location: Box<SchemaLocation>,
+    },
+
+    /// Indicates that none of the document authorities resolves the id of a schema, where `schema_id` is the id of
+    /// the schema that was loaded or of the import that can't be found
+    #[error("Unable to load schema: {schema_id}{location}")]
+    SchemaNotFoundError {
+        schema_id: String,
+        location: Box<SchemaLocation>,
+    },
 
     /// Indicates failure due to invalid schema syntax
-    #[error("{description}")]
-    InvalidSchemaError { description: String },
+    #[error("{description}{location}")]
+    InvalidSchemaError {
+        description: String,
+        location: Box<SchemaLocation>,
+    },
+
+    /// Indicates that a document authority refused to load a schema, e.g. because its id refers to a file
+    /// outside of the base directory of a sandboxed [`FileSystemDocumentAuthority`]
+    ///
+    /// [`FileSystemDocumentAuthority`]: crate::authority::FileSystemDocumentAuthority
+    #[error("{description}{location}")]
+    AccessDeniedError {
+        description: String,
+        location: Box<SchemaLocation>,
+    },
+
+    /// Indicates that a schema exceeds one of the [`LoadLimits`] of the schema system that loads it
+    ///
+    /// [`LoadLimits`]: crate::limits::LoadLimits
+    #[error("{description}{location}")]
+    LimitExceededError {
+        description: String,
+        location: Box<SchemaLocation>,
+    },
 
     /// Indicates failure due to ion-rust error defined by IonError
     #[error("{source:?}")]
@@ -48,23 +86,388 @@ impl PartialEq for IonSchemaError {
             // We can compare the io::Errors' ErrorKinds, offering a weak definition of equality.
             (IoError { source: s1 }, IoError { source: s2 }) => s1.kind() == s2.kind(),
             (
-                UnresolvableSchemaError { description: s1 },
-                UnresolvableSchemaError { description: s2 },
-            ) => s1 == s2,
-            (InvalidSchemaError { description: s1 }, InvalidSchemaError { description: s2 }) => {
-                s1 == s2
-            }
+                UnresolvableSchemaError {
+                    description: s1,
+                    location: l1,
+                },
+                UnresolvableSchemaError {
+                    description: s2,
+                    location: l2,
+                },
+            ) => s1 == s2 && l1 == l2,
+            (
+                SchemaNotFoundError {
+                    schema_id: s1,
+                    location: l1,
+                },
+                SchemaNotFoundError {
+                    schema_id: s2,
+                    location: l2,
+                },
+            ) => s1 == s2 && l1 == l2,
+            (
+                InvalidSchemaError {
+                    description: s1,
+                    location: l1,
+                },
+                InvalidSchemaError {
+                    description: s2,
+                    location: l2,
+                },
+            ) => s1 == s2 && l1 == l2,
+            (
+                AccessDeniedError {
+                    description: s1,
+                    location: l1,
+                },
+                AccessDeniedError {
+                    description: s2,
+                    location: l2,
+                },
+            ) => s1 == s2 && l1 == l2,
+            (
+                LimitExceededError {
+                    description: s1,
+                    location: l1,
+                },
+                LimitExceededError {
+                    description: s2,
+                    location: l2,
+                },
+            ) => s1 == s2 && l1 == l2,
             (IonError { source: s1 }, IonError { source: s2 }) => s1 == s2,
             _ => false,
         }
     }
 }
 
+impl IonSchemaError {
+    /// Returns the location in the schema where this error occurred.
+    /// This is only available for [`IonSchemaError::InvalidSchemaError`], [`IonSchemaError::UnresolvableSchemaError`],
+    /// [`IonSchemaError::SchemaNotFoundError`], [`IonSchemaError::AccessDeniedError`] and
+    /// [`IonSchemaError::LimitExceededError`].
+    pub fn location(&self) -> Option<&SchemaLocation> {
+        match self {
+            IonSchemaError::InvalidSchemaError { location, .. }
+            | IonSchemaError::UnresolvableSchemaError { location, .. }
+            | IonSchemaError::SchemaNotFoundError { location, .. }
+            | IonSchemaError::AccessDeniedError { location, .. }
+            | IonSchemaError::LimitExceededError { location, .. } => Some(location),
+            _ => None,
+        }
+    }
+
+    fn location_mut(&mut self) -> Option<&mut SchemaLocation> {
+        match self {
+            IonSchemaError::InvalidSchemaError { location, .. }
+            | IonSchemaError::UnresolvableSchemaError { location, .. }
+            | IonSchemaError::SchemaNotFoundError { location, .. }
+            | IonSchemaError::AccessDeniedError { location, .. }
+            | IonSchemaError::LimitExceededError { location, .. } => Some(location),
+            _ => None,
+        }
+    }
+
+    // The `with_*` methods below only set the location fields that are not set yet,
+    // hence the innermost schema, type and constraint where an error occurred is preserved
+    // while the error is propagated through enclosing types and imports.
+
+    /// Sets the id of the schema where this error occurred
+    pub(crate) fn with_schema_id(mut self, schema_id: &str) -> Self {
+        if let Some(location) = self.location_mut() {
+            location
+                .schema_id
+                .get_or_insert_with(|| schema_id.to_owned());
+        }
+        self
+    }
+
+    /// Sets the name of the type where this error occurred
+    pub(crate) fn with_type_name(mut self, type_name: &str) -> Self {
+        if let Some(location) = self.location_mut() {
+            location
+                .type_name
+                .get_or_insert_with(|| type_name.to_owned());
+        }
+        self
+    }
+
+    /// Sets the field name of the constraint where this error occurred
+    pub(crate) fn with_constraint(mut self, constraint: &str) -> Self {
+        if let Some(location) = self.location_mut() {
+            location
+                .constraint
+                .get_or_insert_with(|| constraint.to_owned());
+        }
+        self
+    }
+
+    /// Sets the line and column in the schema text where this error occurred
+    pub(crate) fn with_line_column(mut self, line_column: (usize, usize)) -> Self {
+        if let Some(location) = self.location_mut() {
+            location.line_column.get_or_insert(line_column);
+        }
+        self
+    }
+}
+
+/// Represents the location in a schema where an [`IonSchemaError`] occurred while loading the schema.
+///
+/// The line and column are only available when the [`DocumentAuthority`] that loaded the schema
+/// can provide the text of the schema, see [`DocumentAuthority::source_text`], and when the error occurred in a
+/// type definition. They point at the constraint where the error occurred if that constraint is a field of the type
+/// definition exactly once, and at the type definition otherwise.
+///
+/// [`DocumentAuthority`]: crate::authority::DocumentAuthority
+/// [`DocumentAuthority::source_text`]: crate::authority::DocumentAuthority::source_text
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaLocation {
+    schema_id: Option<String>,
+    type_name: Option<String>,
+    constraint: Option<String>,
+    line_column: Option<(usize, usize)>,
+}
+
+impl SchemaLocation {
+    /// Returns the id of the schema where the error occurred
+    pub fn schema_id(&self) -> Option<&str> {
+        self.schema_id.as_deref()
+    }
+
+    /// Returns the name of the closest named type that encloses the definition where the error occurred
+    pub fn type_name(&self) -> Option<&str> {
+        self.type_name.as_deref()
+    }
+
+    /// Returns the field name of the constraint where the error occurred
+    pub fn constraint(&self) -> Option<&str> {
+        self.constraint.as_deref()
+    }
+
+    /// Returns the 1-based line number in the schema text where the error occurred
+    pub fn line(&self) -> Option<usize> {
+        self.line_column.map(|(line, _)| line)
+    }
+
+    /// Returns the 1-based column number in the schema text where the error occurred
+    pub fn column(&self) -> Option<usize> {
+        self.line_column.map(|(_, column)| column)
+    }
+
+    /// Returns `true` if nothing is known about where the error occurred
+    pub fn is_empty(&self) -> bool {
+        self == &SchemaLocation::default()
+    }
+}
+
+/// Displays the location as a suffix for the description of an [`IonSchemaError`],
+/// e.g. ` (schema: sample.isl, type: my_type, constraint: fields, line: 3, column: 5)`.
+/// Nothing is displayed for an empty location.
+impl fmt::Display for SchemaLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return Ok(());
+        }
+        let mut parts = vec![];
+        if let Some(schema_id) = &self.schema_id {
+            parts.push(format!("schema: {schema_id}"));
+        }
+        if let Some(type_name) = &self.type_name {
+            parts.push(format!("type: {type_name}"));
+        }
+        if let Some(constraint) = &self.constraint {
+            parts.push(format!("constraint: {constraint}"));
+        }
+        if let Some((line, column)) = self.line_column {
+            parts.push(format!("line: {line}, column: {column}"));
+        }
+        write!(f, " ({})", parts.join(", "))
+    }
+}
+
+/// Represents the different failures of validating a value against a type that is looked up by its name,
+/// see [`SchemaSystem::validate`].
+///
+/// [`SchemaSystem::validate`]: crate::system::SchemaSystem::validate
+#[derive(Debug, Error, PartialEq)]
+pub enum ValidationError {
+    /// Indicates that none of the authorities of the schema system can resolve the schema id
+    #[error("Unable to find schema: {schema_id}")]
+    SchemaNotFound { schema_id: String },
+
+    /// Indicates that the schema was found but it failed to load
+    #[error("{source}")]
+    SchemaError {
+        #[source]
+        source: IonSchemaError,
+    },
+
+    /// Indicates that the schema doesn't define a type with given name, nor is it a built-in type
+    #[error("Unable to find type {type_name} in schema {schema_id}")]
+    TypeNotFound {
+        schema_id: String,
+        type_name: String,
+    },
+
+    /// Indicates that a fully qualified type name is not of the form `schema_id#type_name`
+    #[error("Expected a type name of the form schema_id#type_name, found: {name}")]
+    InvalidTypeName { name: String },
+
+    /// Indicates that the value is not valid for the type
+    #[error("{0}")]
+    Violation(#[from] Violation),
+}
+
+impl ValidationError {
+    /// Returns the [`Violation`] if the value was validated and found to be invalid, otherwise `None`
+    pub fn violation(&self) -> Option<&Violation> {
+        match self {
+            ValidationError::Violation(violation) => Some(violation),
+            _ => None,
+        }
+    }
+}
+
+/// Represents the severity of a [`SchemaDiagnostic`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticSeverity {
+    /// Indicates a problem that prevents the schema from being loaded
+    Error,
+    /// Indicates a likely mistake that doesn't prevent the schema from being loaded,
+    /// e.g. a misspelled constraint in an ISL 1.0 type that is treated as open content
+    Warning,
+}
+
+impl fmt::Display for DiagnosticSeverity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DiagnosticSeverity::Error => write!(f, "error"),
+            DiagnosticSeverity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// Represents a problem found in a schema by [`SchemaSystem::check_schema`].
+/// The [`SchemaLocation`] of the underlying [`IonSchemaError`] describes where the problem was found.
+///
+/// [`SchemaSystem::check_schema`]: crate::system::SchemaSystem::check_schema
+#[derive(Debug, PartialEq)]
+pub struct SchemaDiagnostic {
+    severity: DiagnosticSeverity,
+    error: IonSchemaError,
+}
+
+impl SchemaDiagnostic {
+    pub fn new(severity: DiagnosticSeverity, error: IonSchemaError) -> Self {
+        Self { severity, error }
+    }
+
+    pub fn severity(&self) -> DiagnosticSeverity {
+        self.severity
+    }
+
+    pub fn error(&self) -> &IonSchemaError {
+        &self.error
+    }
+
+    pub fn location(&self) -> Option<&SchemaLocation> {
+        self.error.location()
+    }
+}
+
+impl fmt::Display for SchemaDiagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.severity, self.error)
+    }
+}
+
+/// Decides what happens to a recoverable error found while loading a schema.
+/// When failing fast, the error is returned and stops the loading as usual.
+/// Otherwise the error is collected as a [`SchemaDiagnostic`] and the loading continues with
+/// the remaining definitions of the schema, which is used by [`SchemaSystem::check_schema`].
+///
+/// [`SchemaSystem::check_schema`]: crate::system::SchemaSystem::check_schema
+#[derive(Debug)]
+pub(crate) struct DiagnosticCollector {
+    // `None` represents failing fast on the first error
+    diagnostics: Option<Vec<SchemaDiagnostic>>,
+}
+
+impl DiagnosticCollector {
+    pub(crate) fn fail_fast() -> Self {
+        Self { diagnostics: None }
+    }
+
+    pub(crate) fn collecting() -> Self {
+        Self {
+            diagnostics: Some(vec![]),
+        }
+    }
+
+    pub(crate) fn is_collecting(&self) -> bool {
+        self.diagnostics.is_some()
+    }
+
+    /// Returns the given error when failing fast, otherwise collects it as an error diagnostic
+    pub(crate) fn recover(&mut self, error: IonSchemaError) -> IonSchemaResult<()> {
+        match &mut self.diagnostics {
+            None => Err(error),
+            Some(diagnostics) => {
+                diagnostics.push(SchemaDiagnostic::new(DiagnosticSeverity::Error, error));
+                Ok(())
+            }
+        }
+    }
+
+    /// Collects the given warning, warnings are ignored when failing fast
+    pub(crate) fn warn(&mut self, warning: IonSchemaError) {
+        if let Some(diagnostics) = &mut self.diagnostics {
+            diagnostics.push(SchemaDiagnostic::new(DiagnosticSeverity::Warning, warning));
+        }
+    }
+
+    /// Returns the number of diagnostics collected so far
+    pub(crate) fn len(&self) -> usize {
+        self.diagnostics
+            .as_ref()
+            .map_or(0, |diagnostics| diagnostics.len())
+    }
+
+    /// Updates the errors of the diagnostics collected after the given number of diagnostics,
+    /// this is used to add the location of the schema to the diagnostics that were found in it
+    pub(crate) fn map_errors_since<F: Fn(IonSchemaError) -> IonSchemaError>(
+        &mut self,
+        start: usize,
+        f: F,
+    ) {
+        if let Some(diagnostics) = &mut self.diagnostics {
+            let updated: Vec<SchemaDiagnostic> = diagnostics
+                .drain(start..)
+                .map(|diagnostic| SchemaDiagnostic::new(diagnostic.severity, f(diagnostic.error)))
+                .collect();
+            diagnostics.extend(updated);
+        }
+    }
+
+    /// Discards the diagnostics collected after the given number of diagnostics,
+    /// this is used when the types of a schema are resolved again
+    pub(crate) fn discard_since(&mut self, start: usize) {
+        if let Some(diagnostics) = &mut self.diagnostics {
+            diagnostics.truncate(start);
+        }
+    }
+
+    pub(crate) fn into_diagnostics(self) -> Vec<SchemaDiagnostic> {
+        self.diagnostics.unwrap_or_default()
+    }
+}
+
 /// A convenience method for creating an IonSchemaResult containing an IonSchemaError::UnresolvableSchemaError
 /// with the provided description text.
 pub fn unresolvable_schema_error<T, S: AsRef<str>>(description: S) -> IonSchemaResult<T> {
     Err(IonSchemaError::UnresolvableSchemaError {
         description: description.as_ref().to_string(),
+        location: Box::default(),
     })
 }
 
@@ -73,6 +476,7 @@ pub fn unresolvable_schema_error<T, S: AsRef<str>>(description: S) -> IonSchemaR
 pub fn invalid_schema_error_raw<S: AsRef<str>>(description: S) -> IonSchemaError {
     IonSchemaError::InvalidSchemaError {
         description: description.as_ref().to_string(),
+        location: Box::default(),
     }
 }
 
@@ -81,6 +485,16 @@ pub fn invalid_schema_error_raw<S: AsRef<str>>(description: S) -> IonSchemaError
 pub fn invalid_schema_error<T, S: AsRef<str>>(description: S) -> IonSchemaResult<T> {
     Err(IonSchemaError::InvalidSchemaError {
         description: description.as_ref().to_string(),
+        location: Box::default(),
+    })
+}
+
+/// A convenience method for creating an IonSchemaResult containing an IonSchemaError::SchemaNotFoundError
+/// for the provided schema id.
+pub fn schema_not_found_error<T, S: AsRef<str>>(schema_id: S) -> IonSchemaResult<T> {
+    Err(IonSchemaError::SchemaNotFoundError {
+        schema_id: schema_id.as_ref().to_string(),
+        location: Box::default(),
     })
 }
 
@@ -89,5 +503,22 @@ pub fn invalid_schema_error<T, S: AsRef<str>>(description: S) -> IonSchemaResult
 pub fn unresolvable_schema_error_raw<S: AsRef<str>>(description: S) -> IonSchemaError {
     IonSchemaError::UnresolvableSchemaError {
         description: description.as_ref().to_string(),
+        location: Box::default(),
+    }
+}
+
+/// A convenience method for creating an IonSchemaError::AccessDeniedError with the provided description text.
+pub fn access_denied_error_raw<S: AsRef<str>>(description: S) -> IonSchemaError {
+    IonSchemaError::AccessDeniedError {
+        description: description.as_ref().to_string(),
+        location: Box::default(),
+    }
+}
+
+/// A convenience method for creating an IonSchemaError::LimitExceededError with the provided description text.
+pub fn limit_exceeded_error_raw<S: AsRef<str>>(description: S) -> IonSchemaError {
+    IonSchemaError::LimitExceededError {
+        description: description.as_ref().to_string(),
+        location: Box::default(),
     }
 }
@@ -3,12 +3,17 @@
 //!
 //! * `get_types`: This function returns an [`SchemaTypeIterator`] which can be used to iterate over the [`TypeDefinition`]s.
 //! * `get_type`: This function requires to pass the name of a type definition that you want to use for validation.
-//! It returns the [`TypeDefinition`] if it is defined in the [`Schema`] otherwise returns [`None`].
+//!   It returns the [`TypeDefinition`] if it is defined in the [`Schema`] otherwise returns [`None`].
 //!
 
-use crate::import::Import;
-use crate::system::{TypeId, TypeStore};
-use crate::types::{TypeDefinition, TypeDefinitionImpl};
+use crate::import::{Import, ImportedType};
+use crate::isl::isl_import::IslImport;
+use crate::isl::isl_type::IslType;
+use crate::isl::IslVersion;
+use crate::result::{invalid_schema_error, IonSchemaResult};
+use crate::system::{PendingTypes, TypeId, TypeStore};
+use crate::types::{TypeDefinition, TypeDefinitionImpl, TypeDefinitionKind};
+use std::collections::HashSet;
 use std::sync::Arc;
 
 /// A Schema is a collection of zero or more [`TypeDefinition`]s.
@@ -22,6 +27,7 @@ use std::sync::Arc;
 pub struct Schema {
     id: String,
     types: Arc<TypeStore>,
+    isl_imports: Vec<IslImport>, // the imports declared in the header of this schema
 }
 
 impl Schema {
@@ -29,23 +35,127 @@ impl Schema {
         Self {
             id: id.as_ref().to_owned(),
             types,
+            isl_imports: vec![],
         }
     }
 
+    /// Sets the imports declared in the header of this schema
+    pub(crate) fn with_isl_imports(self, isl_imports: Vec<IslImport>) -> Self {
+        Self {
+            isl_imports,
+            ..self
+        }
+    }
+
+    /// Returns the [`TypeStore`] that contains the types of this schema
+    pub(crate) fn type_store(&self) -> &TypeStore {
+        &self.types
+    }
+
     /// Returns the id for this Schema
     pub fn id(&self) -> &str {
         &self.id
     }
 
     /// Returns an [Import] representing all the types imported from
-    /// the specified schema [id].
-    fn import(&self, id: String) -> Option<Import> {
-        todo!()
+    /// the specified schema id.
+    ///
+    /// Only the imports declared in the schema header are considered, the types imported by
+    /// an imported schema are not imported into this schema.
+    /// ```
+    /// use ion_rs::element::Element;
+    /// use ion_schema::authority::MapDocumentAuthority;
+    /// use ion_schema::system::SchemaSystem;
+    ///
+    /// let authority = MapDocumentAuthority::new([
+    ///     (
+    ///         "sample.isl",
+    ///         r#"
+    ///             schema_header::{ imports: [{ id: "numbers.isl", type: positive_int, as: count }] }
+    ///             type::{ name: counts, type: list, element: count }
+    ///             schema_footer::{}
+    ///         "#,
+    ///     ),
+    ///     ("numbers.isl", "type::{ name: positive_int, type: int, valid_values: range::[1, max] }"),
+    /// ]);
+    /// let mut schema_system = SchemaSystem::new(vec![Box::new(authority)]);
+    /// let schema = schema_system.load_schema("sample.isl").unwrap();
+    ///
+    /// let import = schema.import("numbers.isl").unwrap();
+    /// let imported_type = import.get_type("count").unwrap();
+    /// assert_eq!(imported_type.name(), "positive_int");
+    /// assert_eq!(imported_type.alias(), Some("count"));
+    /// assert!(imported_type.type_definition().validate(&Element::from(5)).is_ok());
+    /// ```
+    pub fn import<A: AsRef<str>>(&self, id: A) -> Option<Import> {
+        let id = id.as_ref();
+        let mut imported_types: Vec<ImportedType> = vec![];
+        let mut imported_type_names: HashSet<String> = HashSet::new();
+        for isl_import in self.isl_imports.iter().filter(|i| i.id() == id) {
+            let types = match isl_import {
+                IslImport::Schema(_) => self.imported_types_of_schema(id),
+                IslImport::Type(isl_import_type) | IslImport::TypeAlias(isl_import_type) => {
+                    let alias = isl_import_type.alias().to_owned();
+                    let local_name = alias.as_ref().unwrap_or(isl_import_type.type_name());
+                    self.types
+                        .get_imported_type_id_by_name(local_name)
+                        .map(|type_id| {
+                            ImportedType::new(
+                                isl_import_type.type_name().to_owned(),
+                                alias,
+                                type_id,
+                                Arc::clone(&self.types),
+                            )
+                        })
+                        .into_iter()
+                        .collect()
+                }
+            };
+            for imported_type in types {
+                // a type can be imported by more than one import of the same schema
+                if imported_type_names.insert(imported_type.local_name().to_owned()) {
+                    imported_types.push(imported_type);
+                }
+            }
+        }
+        if imported_types.is_empty() && !self.isl_imports.iter().any(|i| i.id() == id) {
+            return None;
+        }
+        Some(Import::new(id, imported_types))
     }
 
-    /// Returns an iterator over the imports of this [`Schema`].
-    fn imports(&self) -> SchemaTypeIterator {
-        todo!()
+    /// Returns an iterator over the imports of this [`Schema`], in the order that the imported
+    /// schema ids appear in the schema header.
+    pub fn imports(&self) -> impl Iterator<Item = Import> + '_ {
+        let mut ids: Vec<&str> = vec![];
+        for isl_import in &self.isl_imports {
+            if !ids.contains(&isl_import.id().as_str()) {
+                ids.push(isl_import.id());
+            }
+        }
+        ids.into_iter().filter_map(|id| self.import(id))
+    }
+
+    // Returns the types imported by a schema import for given schema id, sorted by name
+    fn imported_types_of_schema(&self, id: &str) -> Vec<ImportedType> {
+        let mut imported_types: Vec<ImportedType> = self
+            .types
+            .get_imported_types()
+            .filter(
+                |(name, type_id)| match self.types.get_type_by_id(**type_id) {
+                    Some(TypeDefinitionKind::Named(type_def)) => {
+                        type_def.schema_id() == Some(id)
+                            && type_def.name().as_deref() == Some(name.as_str())
+                    }
+                    _ => false,
+                },
+            )
+            .map(|(name, type_id)| {
+                ImportedType::new(name.to_owned(), None, *type_id, Arc::clone(&self.types))
+            })
+            .collect();
+        imported_types.sort_by(|a, b| a.name().cmp(b.name()));
+        imported_types
     }
 
     /// Returns an iterator over the imported types of this [`Schema`].
@@ -72,8 +182,73 @@ impl Schema {
     /// instance plus the provided type.  Note that the added type
     /// in the returned instance will hide a type of the same name
     /// from this instance.
-    fn plus_type(&self, schema_type: TypeDefinitionImpl) -> Self {
-        todo!()
+    ///
+    /// The types of this instance that refer to the hidden type keep referring to it, while the
+    /// references to the type name within the provided type refer to the provided type itself.
+    /// The provided type can refer to any type of this instance, including its imported types.
+    /// ```
+    /// use ion_rs::element::Element;
+    /// use ion_schema::authority::MapDocumentAuthority;
+    /// use ion_schema::isl::{isl_constraint::v_1_0::*, isl_type::v_1_0::*, isl_type_reference::v_1_0::*};
+    /// use ion_schema::isl::IslVersion;
+    /// use ion_schema::system::SchemaSystem;
+    ///
+    /// let authority = MapDocumentAuthority::new([(
+    ///     "sample.isl",
+    ///     "type::{ name: my_int, type: int }",
+    /// )]);
+    /// let mut schema_system = SchemaSystem::new(vec![Box::new(authority)]);
+    /// let schema = schema_system.load_schema("sample.isl").unwrap();
+    ///
+    /// // a derived schema where `my_int` only allows positive integers
+    /// let derived_schema = schema
+    ///     .plus_type(
+    ///         IslVersion::V1_0,
+    ///         named_type("my_int", [
+    ///             type_constraint(named_type_ref("int")),
+    ///             valid_values_with_values(vec![1.into(), 2.into(), 3.into()]).unwrap(),
+    ///         ]),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert!(schema.get_type("my_int").unwrap().validate(&Element::from(-1)).is_ok());
+    /// assert!(derived_schema.get_type("my_int").unwrap().validate(&Element::from(-1)).is_err());
+    /// ```
+    pub fn plus_type(&self, isl_version: IslVersion, isl_type: IslType) -> IonSchemaResult<Self> {
+        let isl_type_name = match isl_type.name() {
+            Some(isl_type_name) => isl_type_name,
+            None => return invalid_schema_error("Top level types must be named type definitions"),
+        };
+        // verify that the ISL type doesn't contain constraints from another ISL version
+        if isl_type
+            .constraints()
+            .iter()
+            .any(|c| c.version != isl_version)
+        {
+            return invalid_schema_error(format!("ISL type: {isl_type_name} contains constraints from another ISL version. Only use {isl_version} constraints for this method."));
+        }
+
+        let mut type_store = self.types.as_ref().clone();
+        type_store.hide_named_type(isl_type_name);
+        let pending_types = &mut PendingTypes::default();
+        let isl_type_names = HashSet::from([isl_type_name.as_str()]);
+        TypeDefinitionImpl::parse_from_isl_type_and_update_pending_types(
+            isl_version,
+            &isl_type.type_definition,
+            &mut type_store,
+            pending_types,
+        )
+        .and_then(|_| {
+            pending_types.set_schema_id(&self.id);
+            pending_types.update_type_store(&mut type_store, None, &isl_type_names)
+        })
+        .map_err(|error| error.with_type_name(isl_type_name).with_schema_id(&self.id))?;
+
+        Ok(Self {
+            id: self.id.to_owned(),
+            types: Arc::new(type_store),
+            isl_imports: self.isl_imports.to_owned(),
+        })
     }
 }
 
@@ -113,7 +288,10 @@ impl Iterator for SchemaTypeIterator {
 mod schema_tests {
     use super::*;
     use crate::authority::MapDocumentAuthority;
+    use crate::isl::{isl_constraint, isl_type, isl_type_reference};
     use crate::system::{Resolver, SchemaSystem};
+    use crate::types::ValidationOptions;
+    use crate::violation::{Violation, ViolationCode};
     use ion_rs::element::Element;
     use rstest::*;
     use std::sync::Arc;
@@ -732,6 +910,23 @@ mod schema_tests {
                 "#),
                 "ordered_elements_type"
         ),
+        case::ordered_elements_constraint_with_repeated_types(
+                load(r#"
+                     [1, 2, true]
+                     [1, 2, 3, true]
+                     [1, 2, 3, true, false]
+                "#),
+                load(r#"
+                     [1, true]
+                     [1, 2, 3, 4, true]
+                     [1, 2]
+                     [true]
+                "#),
+                load_schema_from_text(r#" // For a schema with ordered_elements constraint as below:
+                        type:: { name: ordered_elements_type, ordered_elements:[{ type: int, occurs: range::[2, 3] }, { type: bool, occurs: range::[1, 2] }] }
+                "#),
+                "ordered_elements_type"
+        ),
         case::fields_constraint(
                 load(r#"
                      { name: "Ion", id: 1 }
@@ -1305,6 +1500,22 @@ mod schema_tests {
                             type::{ name: standard_annotations_type, annotations: { element: { regex: "^[a-z]$" }, container_length: 1 } }
                     "#),
             "standard_annotations_type"
+        ),
+        case::annotations_constraint_for_elements(
+            load(r#"
+                   [a::0, b::1, a::b::2]
+                   []
+                "#),
+            load(r#"
+                   [a::0, 1]
+                   [a::b::c::0]
+                   [a::0, A::1]
+                "#),
+            load_schema_from_text(r#" // For a schema with annotations constraint on the elements of a list as below:
+                            $ion_schema_2_0
+                            type::{ name: annotated_elements_type, type: list, element: { annotations: { element: { regex: "^[a-z]$" }, container_length: range::[1, 2] } } }
+                    "#),
+            "annotated_elements_type"
         )
     )]
     fn type_validation(
@@ -1319,12 +1530,541 @@ mod schema_tests {
             // there is only a single type in each schema defined above hence validate with that type
             let validation_result = type_ref.validate(valid_value);
             assert!(validation_result.is_ok());
+            assert!(type_ref.is_valid(valid_value));
         }
         // check for violations due to invalid values
         for invalid_value in invalid_values.iter() {
             // there is only a single type in each schema defined above hence validate with that type
             let validation_result = type_ref.validate(invalid_value);
             assert!(validation_result.is_err());
+            assert!(!type_ref.is_valid(invalid_value));
+        }
+    }
+
+    #[rstest(
+        type_name,
+        case::document("document"),
+        case::document_with_constraints("short_document"),
+        case::single_element("my_int")
+    )]
+    fn is_valid_matches_validate_for_document_annotation(type_name: &str) {
+        let schema = load_schema_from_text(
+            r#"
+                type::{ name: short_document, type: document, container_length: range::[0, 2] }
+                type::{ name: my_int, type: int }
+            "#,
+        );
+        let type_ref: TypeDefinition = schema.get_type(type_name).unwrap();
+        for value in
+            load(r#"document::"1 2" document::[1, 2] document::(1 2 3) document::"" [1, 2] 1"#)
+        {
+            assert_eq!(
+                type_ref.is_valid(&value),
+                type_ref.validate(&value).is_ok(),
+                "{type_name} disagrees for {value}"
+            );
+        }
+    }
+
+    #[rstest(
+        schema, type_name, value, options, expected_violations,
+        case::all_violations_by_default(
+            load_schema_from_text("type::{ name: my_list, element: int }"),
+            "my_list",
+            "[a, b, c, 1, d]",
+            ValidationOptions::default(),
+            4
+        ),
+        case::fail_fast_for_element_constraint(
+            load_schema_from_text("type::{ name: my_list, element: int }"),
+            "my_list",
+            "[a, b, c, 1, d]",
+            ValidationOptions::default().with_fail_fast(true),
+            1
+        ),
+        case::max_violations_for_element_constraint(
+            load_schema_from_text("type::{ name: my_list, element: int }"),
+            "my_list",
+            "[a, b, c, 1, d]",
+            ValidationOptions::default().with_max_violations(2),
+            2
+        ),
+        case::fail_fast_for_fields_constraint(
+            load_schema_from_text("type::{ name: my_struct, fields: { a: int, b: int, c: { type: int, occurs: required } } }"),
+            "my_struct",
+            "{ a: x, b: y }",
+            ValidationOptions::default().with_fail_fast(true),
+            1
+        ),
+        case::max_violations_for_fields_constraint(
+            load_schema_from_text("type::{ name: my_struct, fields: { a: int, b: int, c: { type: int, occurs: required } } }"),
+            "my_struct",
+            "{ a: x, b: y }",
+            ValidationOptions::default().with_max_violations(2),
+            2
+        ),
+        case::fail_fast_for_multiple_constraints(
+            load_schema_from_text("type::{ name: my_list, container_length: 1, element: int }"),
+            "my_list",
+            "[a, b]",
+            ValidationOptions::default().with_fail_fast(true),
+            1
+        ),
+        case::max_violations_ignores_satisfied_any_of_branches(
+            load_schema_from_text("type::{ name: my_list, element: { any_of: [int, string] } }"),
+            "my_list",
+            r#"[a, "hi", "hi", "hi", b]"#,
+            ValidationOptions::default().with_max_violations(4),
+            4
+        ),
+        case::max_violations_across_nested_violations(
+            load_schema_from_text("type::{ name: my_list, element: { any_of: [int, string] } }"),
+            "my_list",
+            r#"[a, b, c]"#,
+            ValidationOptions::default().with_max_violations(3),
+            3
+        ),
+    )]
+    fn type_validation_with_options(
+        schema: Arc<Schema>,
+        type_name: &str,
+        value: &str,
+        options: ValidationOptions,
+        expected_violations: usize,
+    ) {
+        let type_ref: TypeDefinition = schema.get_type(type_name).unwrap();
+        let value = Element::read_one(value.as_bytes()).unwrap();
+        let violation = type_ref.validate_with(&value, options).unwrap_err();
+        assert_eq!(violation.flattened_violations().len(), expected_violations);
+        // options only affect the violations that are reported and never whether the value is valid
+        assert_eq!(
+            type_ref.validate(&value).is_ok(),
+            type_ref.validate_with(&value, options).is_ok()
+        );
+    }
+
+    // returns a container that is nested `depth` times around the given innermost value
+    fn nested_value(open: &str, innermost: &str, close: &str, depth: usize) -> String {
+        format!("{}{innermost}{}", open.repeat(depth), close.repeat(depth))
+    }
+
+    #[rstest(
+        schema, type_name, value, options, expected,
+        case::within_max_depth(
+            load_schema_from_text("type::{ name: nested_list, type: list, element: nested_list }"),
+            "nested_list",
+            nested_value("[", "", "]", 100),
+            ValidationOptions::default().with_max_depth(101),
+            Ok(())
+        ),
+        case::exceeds_max_depth(
+            load_schema_from_text("type::{ name: nested_list, type: list, element: nested_list }"),
+            "nested_list",
+            nested_value("[", "", "]", 100),
+            ValidationOptions::default().with_max_depth(10),
+            Err(ViolationCode::ValidationLimitExceeded)
+        ),
+        case::exceeds_max_depth_with_fail_fast(
+            load_schema_from_text("type::{ name: nested_list, type: list, element: nested_list }"),
+            "nested_list",
+            nested_value("[", "", "]", 100),
+            ValidationOptions::default().with_max_depth(10).with_fail_fast(true),
+            Err(ViolationCode::ValidationLimitExceeded)
+        ),
+        case::exceeds_max_depth_for_ordered_elements(
+            load_schema_from_text("type::{ name: nested_sexp, type: sexp, ordered_elements: [{ type: nested_sexp, occurs: optional }] }"),
+            "nested_sexp",
+            nested_value("(", "", ")", 100),
+            ValidationOptions::default().with_max_depth(10),
+            Err(ViolationCode::ValidationLimitExceeded)
+        ),
+        case::exceeds_max_depth_is_not_hidden_by_not_constraint(
+            load_schema_from_text("type::{ name: nested_list, type: list, element: nested_list } type::{ name: not_nested_list, not: nested_list }"),
+            "not_nested_list",
+            nested_value("[", "a", "]", 100),
+            ValidationOptions::default().with_max_depth(10),
+            Err(ViolationCode::ValidationLimitExceeded)
+        ),
+        case::within_max_steps(
+            load_schema_from_text("type::{ name: nested_list, type: list, element: nested_list }"),
+            "nested_list",
+            format!("[{}]", vec!["[]"; 50].join(", ")),
+            ValidationOptions::default().with_max_steps(101),
+            Ok(())
+        ),
+        case::exceeds_max_steps(
+            load_schema_from_text("type::{ name: nested_list, type: list, element: nested_list }"),
+            "nested_list",
+            format!("[{}]", vec!["[]"; 50].join(", ")),
+            ValidationOptions::default().with_max_steps(20),
+            Err(ViolationCode::ValidationLimitExceeded)
+        ),
+    )]
+    fn type_validation_with_limits(
+        schema: Arc<Schema>,
+        type_name: &str,
+        value: String,
+        options: ValidationOptions,
+        expected: Result<(), ViolationCode>,
+    ) {
+        let type_ref: TypeDefinition = schema.get_type(type_name).unwrap();
+        let value = Element::read_one(value.as_bytes()).unwrap();
+        let result = type_ref.validate_with(&value, options);
+        assert_eq!(
+            result.as_ref().map_err(|violation| violation.code()),
+            expected.as_ref()
+        );
+        assert_eq!(type_ref.is_valid_with(&value, options), expected.is_ok());
+        // the violation for an exceeded limit is reported on its own
+        if let Err(violation) = result {
+            assert_eq!(violation.flattened_violations().len(), 1);
+        }
+    }
+
+    // collects the constraint, type name and schema id of the given violation and its nested violations
+    fn violation_types<'a>(
+        violation: &'a Violation,
+        types: &mut Vec<(&'a str, Option<&'a str>, Option<&'a str>)>,
+    ) {
+        types.push((
+            violation.constraint(),
+            violation.type_name(),
+            violation.schema_id(),
+        ));
+        for nested_violation in violation.violations() {
+            violation_types(nested_violation, types);
+        }
+    }
+
+    #[test]
+    fn violation_type_and_schema() {
+        let map_authority = [
+            (
+                "sample.isl",
+                r#"
+                    schema_header::{ imports: [{ id: "numbers.isl", type: positive_int }] }
+                    type::{
+                        name: person,
+                        fields: {
+                            age: positive_int,
+                            name: { type: string, codepoint_length: range::[1, max] },
+                        },
+                    }
+                    schema_footer::{}
+                "#,
+            ),
+            (
+                "numbers.isl",
+                "type::{ name: positive_int, type: int, valid_values: range::[1, max] }",
+            ),
+        ];
+        let mut schema_system =
+            SchemaSystem::new(vec![Box::new(MapDocumentAuthority::new(map_authority))]);
+        let schema = schema_system.load_schema("sample.isl").unwrap();
+        let person = schema.get_type("person").unwrap();
+
+        let value = Element::read_one(r#"{ age: 0, name: "" }"#).unwrap();
+        let violation = person.validate(&value).unwrap_err();
+        assert_eq!(violation.type_id(), Some(person.id()));
+
+        let mut types = vec![];
+        violation_types(&violation, &mut types);
+        assert_eq!(
+            types,
+            vec![
+                ("person", Some("person"), Some("sample.isl")),
+                ("fields", Some("person"), Some("sample.isl")),
+                ("positive_int", Some("positive_int"), Some("numbers.isl")),
+                ("valid_values", Some("positive_int"), Some("numbers.isl")),
+                (
+                    "{type: string, codepoint_length: range::[1, max]}",
+                    Some("person"),
+                    Some("sample.isl")
+                ),
+                ("codepoint_length", Some("person"), Some("sample.isl")),
+            ]
+        );
+
+        // validating a stream attributes the violations to the same types
+        let mut reader = ion_rs::ReaderBuilder::new()
+            .build(r#"{ age: 0, name: "" }"#)
+            .unwrap();
+        let streamed_violation = person
+            .validate_stream(&mut reader)
+            .next()
+            .unwrap()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(streamed_violation, violation);
+    }
+
+    #[test]
+    fn schema_imports() {
+        let map_authority = [
+            (
+                "sample.isl",
+                r#"
+                    schema_header::{
+                        imports: [
+                            { id: "numbers.isl", type: positive_int },
+                            { id: "numbers.isl", type: negative_int, as: below_zero },
+                            { id: "strings.isl" },
+                        ],
+                    }
+                    type::{ name: my_int, type: int }
+                    schema_footer::{}
+                "#,
+            ),
+            (
+                "numbers.isl",
+                r#"
+                    type::{ name: positive_int, type: int, valid_values: range::[1, max] }
+                    type::{ name: negative_int, type: int, valid_values: range::[min, -1] }
+                "#,
+            ),
+            (
+                "strings.isl",
+                r#"
+                    schema_header::{ imports: [{ id: "symbols.isl" }] }
+                    type::{ name: short_string, type: string, codepoint_length: range::[0, 10] }
+                    type::{ name: long_string, type: string, codepoint_length: range::[11, max] }
+                    schema_footer::{}
+                "#,
+            ),
+            ("symbols.isl", "type::{ name: short_symbol, type: symbol }"),
+        ];
+        let mut schema_system =
+            SchemaSystem::new(vec![Box::new(MapDocumentAuthority::new(map_authority))]);
+        let schema = schema_system.load_schema("sample.isl").unwrap();
+
+        let imports: Vec<Import> = schema.imports().collect();
+        let import_ids: Vec<&str> = imports.iter().map(|import| import.id()).collect();
+        assert_eq!(import_ids, vec!["numbers.isl", "strings.isl"]);
+        let imported_types = |import: &Import| -> Vec<(String, Option<String>)> {
+            import
+                .types()
+                .iter()
+                .map(|t| (t.name().to_owned(), t.alias().map(|a| a.to_owned())))
+                .collect()
+        };
+        assert_eq!(
+            imported_types(&imports[0]),
+            vec![
+                ("positive_int".to_owned(), None),
+                ("negative_int".to_owned(), Some("below_zero".to_owned()))
+            ]
+        );
+        assert_eq!(
+            imported_types(&imports[1]),
+            vec![
+                ("long_string".to_owned(), None),
+                ("short_string".to_owned(), None)
+            ]
+        );
+
+        let import = schema.import("numbers.isl").unwrap();
+        assert!(import.get_type("negative_int").is_none());
+        let below_zero = import.get_type("below_zero").unwrap().type_definition();
+        assert!(below_zero.validate(&Element::from(-1)).is_ok());
+        assert!(below_zero.validate(&Element::from(1)).is_err());
+        // the imports of an imported schema are not imports of this schema
+        assert!(schema.import("symbols.isl").is_none());
+        assert!(schema.import("missing.isl").is_none());
+        assert_eq!(
+            load_schema_from_text("type::{ name: my_int, type: int }")
+                .imports()
+                .count(),
+            0
+        );
+    }
+
+    #[rstest(
+        isl_type, valid_value, invalid_value,
+        case::new_type(
+            isl_type::v_1_0::named_type(
+                "my_string",
+                [isl_constraint::v_1_0::type_constraint(isl_type_reference::v_1_0::named_type_ref("string"))]
+            ),
+            Element::from("hello"),
+            Element::from(5)
+        ),
+        case::hiding_type(
+            isl_type::v_1_0::named_type(
+                "my_int",
+                [isl_constraint::v_1_0::type_constraint(isl_type_reference::v_1_0::named_type_ref("positive_int"))]
+            ),
+            Element::from(5),
+            Element::from(-5)
+        ),
+        case::self_referencing_type(
+            isl_type::v_1_0::named_type(
+                "int_tree",
+                [
+                    isl_constraint::v_1_0::type_constraint(isl_type_reference::v_1_0::named_type_ref("list")),
+                    isl_constraint::v_1_0::element(isl_type_reference::v_1_0::anonymous_type_ref([
+                        isl_constraint::v_1_0::any_of([
+                            isl_type_reference::v_1_0::named_type_ref("int"),
+                            isl_type_reference::v_1_0::named_type_ref("int_tree"),
+                        ])
+                    ])),
+                ]
+            ),
+            Element::read_one("[1, [2, [3]]]").unwrap(),
+            Element::read_one("[1, [2, [a]]]").unwrap()
+        ),
+    )]
+    fn plus_type(isl_type: IslType, valid_value: Element, invalid_value: Element) {
+        let schema = load_schema_from_text(
+            r#"
+                type::{ name: positive_int, type: int, valid_values: range::[1, max] }
+                type::{ name: my_int, type: int }
+                type::{ name: my_ints, type: list, element: my_int }
+            "#,
+        );
+        let type_name = isl_type.name().to_owned().unwrap();
+        let derived_schema = schema.plus_type(IslVersion::V1_0, isl_type).unwrap();
+        let derived_type = derived_schema.get_type(&type_name).unwrap();
+        assert!(derived_type.validate(&valid_value).is_ok());
+        assert!(derived_type.validate(&invalid_value).is_err());
+
+        // the original schema and the types that refer to the hidden type are not changed
+        assert_eq!(
+            derived_schema.get_types().count(),
+            3 + usize::from(type_name != "my_int")
+        );
+        assert!(schema
+            .get_type("my_int")
+            .unwrap()
+            .validate(&Element::from(-5))
+            .is_ok());
+        let my_ints = Element::read_one("[-5, 0]").unwrap();
+        assert!(derived_schema
+            .get_type("my_ints")
+            .unwrap()
+            .validate(&my_ints)
+            .is_ok());
+    }
+
+    #[test]
+    fn plus_type_invalid() {
+        let schema = load_schema_from_text("type::{ name: my_int, type: int }");
+        let error = schema
+            .plus_type(
+                IslVersion::V1_0,
+                isl_type::v_1_0::named_type(
+                    "my_list",
+                    [isl_constraint::v_1_0::element(
+                        isl_type_reference::v_1_0::named_type_ref("unknown_type"),
+                    )],
+                ),
+            )
+            .unwrap_err();
+        let location = error.location().unwrap();
+        assert_eq!(location.schema_id(), Some("sample.isl"));
+        assert_eq!(location.type_name(), Some("my_list"));
+
+        // anonymous types can not be added to a schema
+        assert!(schema
+            .plus_type(
+                IslVersion::V1_0,
+                isl_type::v_1_0::anonymous_type([isl_constraint::v_1_0::type_constraint(
+                    isl_type_reference::v_1_0::named_type_ref("int")
+                )])
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn type_definition_introspection() {
+        use crate::constraint::Constraint;
+        use crate::isl::isl_type_reference::NullabilityModifier;
+        use crate::isl::util::ValidValue;
+
+        let schema = load_schema_from_text(
+            r#"
+                type::{
+                    name: person,
+                    type: struct,
+                    fields: {
+                        name: { type: string, regex: "^[A-Z]" },
+                        title: { valid_values: [mr, ms] },
+                        age: nullable::int,
+                    },
+                }
+            "#,
+        );
+        let person = schema.get_type("person").unwrap();
+        assert_eq!(person.name(), Some("person".to_owned()));
+        assert_eq!(person.schema_id(), Some("sample.isl"));
+        assert!(!person.is_built_in());
+
+        let fields = person
+            .constraints()
+            .iter()
+            .find_map(|constraint| match constraint {
+                Constraint::Fields(fields) => Some(fields),
+                _ => None,
+            })
+            .unwrap();
+        assert!(fields.open_content());
+
+        // navigate from the field type references to the field types
+        let field_type = |name: &str| {
+            let type_ref = fields.fields().get(name).unwrap().type_ref();
+            (type_ref, person.referenced_type(&type_ref))
+        };
+
+        let (name_ref, name_type) = field_type("name");
+        assert_eq!(
+            name_ref.nullability_modifier(),
+            NullabilityModifier::Nothing
+        );
+        assert_eq!(name_type.name(), None);
+        assert_eq!(name_type.schema_id(), Some("sample.isl"));
+        let regex = name_type
+            .constraints()
+            .iter()
+            .find_map(|constraint| match constraint {
+                Constraint::Regex(regex) => Some(regex),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(regex.expression(), "^[A-Z]");
+        assert!(!regex.case_insensitive());
+        assert!(!regex.multiline());
+
+        let (_, title_type) = field_type("title");
+        match title_type.constraints() {
+            // ISL 1.0 adds `type: any` when a type doesn't have a type constraint
+            [Constraint::ValidValues(valid_values), Constraint::Type(any)] => {
+                assert_eq!(
+                    title_type.referenced_type(&any.type_reference()).name(),
+                    Some("any".to_owned())
+                );
+                assert_eq!(
+                    valid_values.valid_values(),
+                    &[
+                        ValidValue::Element(Element::symbol("mr")),
+                        ValidValue::Element(Element::symbol("ms")),
+                    ]
+                );
+            }
+            constraints => panic!("unexpected constraints {constraints:?}"),
         }
+
+        let (age_ref, age_type) = field_type("age");
+        assert_eq!(
+            age_ref.nullability_modifier(),
+            NullabilityModifier::Nullable
+        );
+        assert_eq!(age_type.name(), Some("int".to_owned()));
+        assert!(age_type.is_built_in());
+        assert_eq!(age_type.schema_id(), None);
+        assert!(age_type.constraints().is_empty());
+
+        // derived built-in types provide their constraints
+        let number = schema.get_type("number").unwrap();
+        assert!(number.is_built_in());
+        assert!(matches!(number.constraints(), [Constraint::OneOf(_)]));
     }
 }
@@ -0,0 +1,1109 @@
+//! Provides validation of Ion values read directly from an [`IonReader`], without materializing
+//! a top level container (or the whole stream) as an [`Element`] tree.
+//!
+//! Only one level is streamed: a top level container (or the entire stream, when validating it as a
+//! document) is walked one child value at a time, but each child value is materialized as a whole
+//! [`Element`], including all the values nested in it, before it is handed to the constraints of the
+//! type being validated. Hence the memory needed for validation is bounded by the size of the largest
+//! child value of the top level container rather than the size of the whole container, and a single
+//! large or deeply nested child value still needs to fit in memory.
+//!
+//! Constraints that depend on the child values of a container (`container_length`, `element`,
+//! `fields`, `field_names`, `contains`, `ordered_elements` and the logical constraints `type`,
+//! `all_of`, `any_of`, `one_of` and `not`) are evaluated incrementally as the child values of the
+//! top level container are read.
+//! All the other constraints only need the container's type and annotations.
+//! The only exception is `valid_values`, which compares the container as a whole and hence
+//! buffers the child values of any container that it is validated against.
+//!
+//! The child values are only materialized up to the maximum depth of the [`ValidationOptions`],
+//! a child value that is nested deeper than that exceeds the limit like a value validated with
+//! [`TypeDefinition::validate_with`].
+//!
+//! ## Example:
+//! ```
+//! use ion_rs::ReaderBuilder;
+//! use ion_schema::authority::{DocumentAuthority, MapDocumentAuthority};
+//! use ion_schema::system::SchemaSystem;
+//! use ion_schema::result::IonSchemaResult;
+//!
+//! fn main() -> IonSchemaResult<()> {
+//!     let map_authority = [(
+//!         "sample.isl",
+//!         r#"
+//!             type::{
+//!                 name: my_list,
+//!                 type: list,
+//!                 element: int,
+//!                 container_length: range::[1, 3],
+//!             }
+//!         "#,
+//!     )];
+//!     let authorities: Vec<Box<dyn DocumentAuthority>> =
+//!         vec![Box::new(MapDocumentAuthority::new(map_authority))];
+//!     let mut schema_system = SchemaSystem::new(authorities);
+//!     let schema = schema_system.load_schema("sample.isl")?;
+//!     let my_list = schema.get_type("my_list").unwrap();
+//!
+//!     let mut reader = ReaderBuilder::new().build("[1, 2] [1, a] [1, 2, 3, 4]")?;
+//!     let results = my_list
+//!         .validate_stream(&mut reader)
+//!         .collect::<IonSchemaResult<Vec<_>>>()?;
+//!
+//!     assert!(results[0].is_ok());
+//!     assert!(results[1].is_err()); // `a` is not an int
+//!     assert!(results[2].is_err()); // container length is more than 3
+//!     Ok(())
+//! }
+//! ```
+
+use crate::constraint::{
+    AllOfConstraint, AnyOfConstraint, Constraint, ConstraintValidator, ContainerLengthConstraint,
+    ContainsValidation, ElementValidation, FieldNamesValidation, FieldsValidation, NotConstraint,
+    OneOfConstraint, OrderedElementsValidation, ValidValuesConstraint,
+};
+use crate::ion_path::{IonPath, IonPathElement};
+use crate::result::{IonSchemaResult, ValidationResult};
+use crate::system::{TypeId, TypeStore};
+use crate::type_reference::TypeReference;
+use crate::types::{
+    BuiltInTypeDefinition, TypeDefinition, TypeDefinitionImpl, TypeDefinitionKind, TypeValidator,
+    ValidationContext, ValidationOptions,
+};
+use crate::IonSchemaElement;
+use ion_rs::element::{Element, Sequence, Struct, Value};
+use ion_rs::result::{decoding_error, IonResult};
+use ion_rs::{IonReader, IonType, StreamItem, Symbol};
+use std::fmt::{Display, Formatter};
+
+/// An iterator over the validation results for each of the top level values read from an [`IonReader`].
+///
+/// Each item is either an [`IonSchemaError`] if the underlying reader failed to read the value,
+/// or the [`ValidationResult`] for the value.
+/// This is created by [`TypeDefinition::validate_stream`] and [`TypeDefinition::validate_stream_with`].
+///
+/// [`IonSchemaError`]: crate::result::IonSchemaError
+pub struct StreamValidationIterator<'a, R> {
+    type_definition: &'a TypeDefinition,
+    reader: &'a mut R,
+    options: ValidationOptions,
+    // the reader can't make progress after an error, so the iteration ends after the first error
+    failed: bool,
+}
+
+impl<'a, R> StreamValidationIterator<'a, R> {
+    pub(crate) fn new(
+        type_definition: &'a TypeDefinition,
+        reader: &'a mut R,
+        options: ValidationOptions,
+    ) -> Self {
+        Self {
+            type_definition,
+            reader,
+            options,
+            failed: false,
+        }
+    }
+}
+
+impl<'a, R: IonReader<Item = StreamItem, Symbol = Symbol>> Iterator
+    for StreamValidationIterator<'a, R>
+{
+    type Item = IonSchemaResult<ValidationResult>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let result = match self.reader.next() {
+            Err(error) => Err(error.into()),
+            Ok(StreamItem::Nothing) => return None,
+            Ok(_) => validate_current_value(
+                self.reader,
+                self.type_definition.id(),
+                self.type_definition.type_store(),
+                self.options,
+            ),
+        };
+        self.failed = result.is_err();
+        Some(result)
+    }
+}
+
+/// Validates the value that the given reader is currently positioned on against the type with given [`TypeId`].
+/// If the value is a container, its child values are read and validated one at a time.
+pub(crate) fn validate_current_value<R: IonReader<Item = StreamItem, Symbol = Symbol>>(
+    reader: &mut R,
+    type_id: TypeId,
+    type_store: &TypeStore,
+    options: ValidationOptions,
+) -> IonSchemaResult<ValidationResult> {
+    let mut ion_path = IonPath::default();
+    let mut context = ValidationContext::new(options);
+    let type_def = type_store.get_type_by_id(type_id).unwrap();
+
+    let result = match reader.current() {
+        StreamItem::Value(ion_type) if ion_type.is_container() => {
+            let container = StreamedContainer::Value(ion_type, read_annotations(reader)?);
+            let mut validation =
+                TypeValidation::new(type_id, &container, type_store, &mut ion_path, &mut context);
+
+            // there is no need to read the child values if the result is already known,
+            // the next call to `next()` on the reader will skip over this container
+            if let TypeValidation::Complete(result) = validation {
+                result
+            } else {
+                reader.step_in()?;
+                validation.validate_children(
+                    reader,
+                    &container,
+                    type_store,
+                    &mut ion_path,
+                    &mut context,
+                )?;
+                reader.step_out()?;
+
+                validation.finish(&container, type_store, &mut ion_path, &mut context)
+            }
+        }
+        // the containers are handled above, hence the value is never nested too deeply to be read
+        _ => match read_current_element(reader, context.max_depth())? {
+            Some(element) => {
+                let value = IonSchemaElement::SingleElement(element);
+                type_def.validate(&value, type_store, &mut ion_path, &mut context)
+            }
+            None => unreachable!("scalar values are not nested"),
+        },
+    };
+    Ok(type_def.set_violation_type(type_id, context.finish(result)))
+}
+
+/// Validates all the remaining top level values of the given reader as a document,
+/// against the type with given [`TypeId`].
+pub(crate) fn validate_document<R: IonReader<Item = StreamItem, Symbol = Symbol>>(
+    reader: &mut R,
+    type_id: TypeId,
+    type_store: &TypeStore,
+    options: ValidationOptions,
+) -> IonSchemaResult<ValidationResult> {
+    let mut ion_path = IonPath::default();
+    let mut context = ValidationContext::new(options);
+    let type_def = type_store.get_type_by_id(type_id).unwrap();
+
+    let container = StreamedContainer::Document;
+    let mut validation =
+        TypeValidation::new(type_id, &container, type_store, &mut ion_path, &mut context);
+    let result = if let TypeValidation::Complete(result) = validation {
+        result
+    } else {
+        validation.validate_children(
+            reader,
+            &container,
+            type_store,
+            &mut ion_path,
+            &mut context,
+        )?;
+        validation.finish(&container, type_store, &mut ion_path, &mut context)
+    };
+    Ok(type_def.set_violation_type(type_id, context.finish(result)))
+}
+
+/// Represents the container whose child values are being read and validated one at a time
+#[derive(Debug, Clone, PartialEq)]
+enum StreamedContainer {
+    // a list, sexp or struct value with its annotations
+    Value(IonType, Vec<Symbol>),
+    Document,
+}
+
+impl StreamedContainer {
+    /// Returns an empty container with the same type and annotations as this container.
+    /// This is used to validate the constraints that don't depend on the child values of the container.
+    fn empty_value(&self) -> IonSchemaElement {
+        self.with_children(vec![])
+    }
+
+    /// Returns a materialized container with the same type and annotations as this container
+    /// which contains the given child values.
+    fn with_children(&self, children: Vec<(IonPathElement, Element)>) -> IonSchemaElement {
+        match self {
+            StreamedContainer::Value(ion_type, annotations) => {
+                let value = match ion_type {
+                    IonType::Struct => Value::Struct(Struct::from_iter(children.into_iter().map(
+                        |(ion_path_element, child)| match ion_path_element {
+                            IonPathElement::Field(field_name) => (field_name, child),
+                            IonPathElement::Index(_) => unreachable!("struct fields have names"),
+                        },
+                    ))),
+                    IonType::List => Value::List(Self::sequence(children)),
+                    _ => Value::SExp(Self::sequence(children)),
+                };
+                IonSchemaElement::SingleElement(
+                    Element::from(value).with_annotations(annotations.to_owned()),
+                )
+            }
+            StreamedContainer::Document => {
+                IonSchemaElement::Document(children.into_iter().map(|(_, child)| child).collect())
+            }
+        }
+    }
+
+    fn sequence(children: Vec<(IonPathElement, Element)>) -> Sequence {
+        children
+            .into_iter()
+            .map(|(_, child)| child)
+            .collect::<Vec<Element>>()
+            .into()
+    }
+
+    fn is_struct(&self) -> bool {
+        matches!(self, StreamedContainer::Value(IonType::Struct, _))
+    }
+
+    fn is_sequence(&self) -> bool {
+        !self.is_struct()
+    }
+}
+
+impl Display for StreamedContainer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamedContainer::Value(ion_type, _) => write!(f, "{ion_type}"),
+            StreamedContainer::Document => write!(f, "document"),
+        }
+    }
+}
+
+/// Represents an in-progress validation of a type definition against a container
+/// whose child values are provided one at a time.
+enum TypeValidation<'a> {
+    // the result doesn't depend on the child values of the container
+    Complete(ValidationResult),
+    Constraints {
+        type_id: TypeId,
+        type_def: &'a TypeDefinitionKind,
+        type_def_impl: &'a TypeDefinitionImpl,
+        constraint_validations: Vec<ConstraintValidation<'a>>,
+    },
+}
+
+impl<'a> TypeValidation<'a> {
+    fn new(
+        type_id: TypeId,
+        container: &StreamedContainer,
+        type_store: &'a TypeStore,
+        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
+    ) -> Self {
+        let type_def = type_store.get_type_by_id(type_id).unwrap();
+        let type_def_impl = match type_def {
+            TypeDefinitionKind::Named(type_def_impl)
+            | TypeDefinitionKind::Anonymous(type_def_impl) => type_def_impl,
+            TypeDefinitionKind::BuiltIn(BuiltInTypeDefinition::Derived(type_def_impl))
+                if type_def_impl.name() != &Some("document".to_owned()) =>
+            {
+                type_def_impl
+            }
+            // atomic types and `document` only depend on the type of the container
+            TypeDefinitionKind::BuiltIn(_) => {
+                let result =
+                    type_def.validate(&container.empty_value(), type_store, ion_path, context);
+                return TypeValidation::Complete(type_def.set_violation_type(type_id, result));
+            }
+        };
+
+        TypeValidation::Constraints {
+            type_id,
+            type_def,
+            type_def_impl,
+            constraint_validations: type_def_impl
+                .constraints()
+                .iter()
+                .map(|constraint| {
+                    ConstraintValidation::new(constraint, container, type_store, ion_path, context)
+                })
+                .collect(),
+        }
+    }
+
+    fn for_type_reference(
+        type_reference: &TypeReference,
+        container: &StreamedContainer,
+        type_store: &'a TypeStore,
+        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
+    ) -> Self {
+        // the container being validated is never null, hence the nullability modifier of the
+        // type reference doesn't affect the result
+        TypeValidation::new(
+            type_reference.type_id(),
+            container,
+            type_store,
+            ion_path,
+            context,
+        )
+    }
+
+    /// Reads the child values of the container from the given reader and validates them one at a time.
+    /// The reader must be positioned at the start of the container's child values, i.e. after stepping in
+    /// or at the start of the stream for a document.
+    fn validate_children<R: IonReader<Item = StreamItem, Symbol = Symbol>>(
+        &mut self,
+        reader: &mut R,
+        container: &StreamedContainer,
+        type_store: &TypeStore,
+        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
+    ) -> IonResult<()> {
+        let mut index = 0;
+        while let StreamItem::Value(_) | StreamItem::Null(_) = reader.next()? {
+            let ion_path_element = if container.is_struct() {
+                IonPathElement::Field(field_name_text(reader)?)
+            } else {
+                IonPathElement::Index(index)
+            };
+            let child = match read_current_element(reader, context.max_depth())? {
+                Some(child) => child,
+                None => {
+                    // the remaining child values are skipped, as the result is the violation for the exceeded limit
+                    ion_path.push(ion_path_element);
+                    context.exceed_max_depth(ion_path);
+                    ion_path.pop();
+                    while let StreamItem::Value(_) | StreamItem::Null(_) = reader.next()? {
+                        skip_current_value(reader, 0)?;
+                    }
+                    return Ok(());
+                }
+            };
+            self.validate_next(&ion_path_element, &child, type_store, ion_path, context);
+            index += 1;
+        }
+        Ok(())
+    }
+
+    fn validate_next(
+        &mut self,
+        ion_path_element: &IonPathElement,
+        child: &Element,
+        type_store: &TypeStore,
+        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
+    ) {
+        if let TypeValidation::Constraints {
+            constraint_validations,
+            ..
+        } = self
+        {
+            for constraint_validation in constraint_validations {
+                constraint_validation.validate_next(
+                    ion_path_element,
+                    child,
+                    type_store,
+                    ion_path,
+                    context,
+                );
+            }
+        }
+    }
+
+    fn finish(
+        self,
+        container: &StreamedContainer,
+        type_store: &TypeStore,
+        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
+    ) -> ValidationResult {
+        match self {
+            TypeValidation::Complete(result) => result,
+            TypeValidation::Constraints {
+                type_id,
+                type_def,
+                type_def_impl,
+                constraint_validations,
+            } => {
+                let results: Vec<ValidationResult> = constraint_validations
+                    .into_iter()
+                    .map(|c| c.finish(container, type_store, ion_path, context))
+                    .collect();
+                let result = type_def_impl.combine_results(results, ion_path, context);
+                type_def.set_violation_type(type_id, result)
+            }
+        }
+    }
+}
+
+/// Represents an in-progress validation of a constraint against a container
+/// whose child values are provided one at a time.
+enum ConstraintValidation<'a> {
+    // the result doesn't depend on the child values of the container
+    Complete(ValidationResult),
+    AllOf(&'a AllOfConstraint, Vec<TypeValidation<'a>>),
+    AnyOf(&'a AnyOfConstraint, Vec<TypeValidation<'a>>),
+    OneOf(&'a OneOfConstraint, Vec<TypeValidation<'a>>),
+    Not(&'a NotConstraint, Box<TypeValidation<'a>>),
+    Type(Box<TypeValidation<'a>>),
+    ContainerLength(&'a ContainerLengthConstraint, usize),
+    Element(ElementValidation<'a>),
+    Fields(FieldsValidation<'a>),
+    FieldNames(FieldNamesValidation<'a>),
+    Contains(ContainsValidation<'a>),
+    OrderedElements(OrderedElementsValidation),
+    // `valid_values` compares the container as a whole, hence the child values are buffered
+    ValidValues(&'a ValidValuesConstraint, Vec<(IonPathElement, Element)>),
+}
+
+impl<'a> ConstraintValidation<'a> {
+    fn new(
+        constraint: &'a Constraint,
+        container: &StreamedContainer,
+        type_store: &'a TypeStore,
+        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
+    ) -> Self {
+        let type_validations = |type_references: &[TypeReference],
+                                ion_path: &mut IonPath,
+                                context: &mut ValidationContext| {
+            type_references
+                .iter()
+                .map(|t| {
+                    TypeValidation::for_type_reference(t, container, type_store, ion_path, context)
+                })
+                .collect()
+        };
+
+        match constraint {
+            Constraint::AllOf(all_of) => ConstraintValidation::AllOf(
+                all_of,
+                type_validations(all_of.type_references(), ion_path, context),
+            ),
+            Constraint::AnyOf(any_of) => ConstraintValidation::AnyOf(
+                any_of,
+                type_validations(any_of.type_references(), ion_path, context),
+            ),
+            Constraint::OneOf(one_of) => ConstraintValidation::OneOf(
+                one_of,
+                type_validations(one_of.type_references(), ion_path, context),
+            ),
+            Constraint::Not(not) => ConstraintValidation::Not(
+                not,
+                Box::new(TypeValidation::for_type_reference(
+                    &not.type_reference(),
+                    container,
+                    type_store,
+                    ion_path,
+                    context,
+                )),
+            ),
+            Constraint::Type(type_constraint) => {
+                ConstraintValidation::Type(Box::new(TypeValidation::for_type_reference(
+                    &type_constraint.type_reference,
+                    container,
+                    type_store,
+                    ion_path,
+                    context,
+                )))
+            }
+            Constraint::ContainerLength(container_length) => {
+                ConstraintValidation::ContainerLength(container_length, 0)
+            }
+            Constraint::Element(element) => {
+                ConstraintValidation::Element(element.start_validation())
+            }
+            Constraint::Fields(fields) if container.is_struct() => {
+                ConstraintValidation::Fields(fields.start_validation())
+            }
+            Constraint::FieldNames(field_names) if container.is_struct() => {
+                ConstraintValidation::FieldNames(field_names.start_validation())
+            }
+            Constraint::Contains(contains) if container.is_sequence() => {
+                ConstraintValidation::Contains(contains.start_validation())
+            }
+            Constraint::OrderedElements(ordered_elements) if container.is_sequence() => {
+                ConstraintValidation::OrderedElements(ordered_elements.start_validation())
+            }
+            Constraint::ValidValues(valid_values) => {
+                ConstraintValidation::ValidValues(valid_values, vec![])
+            }
+            // all the other constraints either don't depend on child values or
+            // don't apply to this kind of container, which only depends on the type of the container
+            _ => ConstraintValidation::Complete(constraint.validate(
+                &container.empty_value(),
+                type_store,
+                ion_path,
+                context,
+            )),
+        }
+    }
+
+    fn validate_next(
+        &mut self,
+        ion_path_element: &IonPathElement,
+        child: &Element,
+        type_store: &TypeStore,
+        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
+    ) {
+        match self {
+            ConstraintValidation::Complete(_) => {}
+            ConstraintValidation::AllOf(_, type_validations)
+            | ConstraintValidation::AnyOf(_, type_validations)
+            | ConstraintValidation::OneOf(_, type_validations) => {
+                for type_validation in type_validations {
+                    type_validation.validate_next(
+                        ion_path_element,
+                        child,
+                        type_store,
+                        ion_path,
+                        context,
+                    );
+                }
+            }
+            ConstraintValidation::Not(_, type_validation)
+            | ConstraintValidation::Type(type_validation) => type_validation.validate_next(
+                ion_path_element,
+                child,
+                type_store,
+                ion_path,
+                context,
+            ),
+            ConstraintValidation::ContainerLength(_, size) => *size += 1,
+            ConstraintValidation::Element(validation) => validation.validate_next(
+                ion_path_element.to_owned(),
+                child,
+                type_store,
+                ion_path,
+                context,
+            ),
+            ConstraintValidation::Fields(validation) => {
+                if let IonPathElement::Field(field_name) = ion_path_element {
+                    validation.validate_next(field_name, child, type_store, ion_path, context)
+                }
+            }
+            ConstraintValidation::FieldNames(validation) => {
+                if let IonPathElement::Field(field_name) = ion_path_element {
+                    validation.validate_next(field_name, type_store, ion_path, context)
+                }
+            }
+            ConstraintValidation::Contains(validation) => validation.validate_next(child),
+            ConstraintValidation::OrderedElements(validation) => {
+                validation.validate_next(child, type_store, context)
+            }
+            ConstraintValidation::ValidValues(_, children) => {
+                children.push((ion_path_element.to_owned(), child.to_owned()))
+            }
+        }
+    }
+
+    fn finish(
+        self,
+        container: &StreamedContainer,
+        type_store: &TypeStore,
+        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
+    ) -> ValidationResult {
+        let finish_all = |type_validations: Vec<TypeValidation>,
+                          ion_path: &mut IonPath,
+                          context: &mut ValidationContext| {
+            type_validations
+                .into_iter()
+                .map(|t| t.finish(container, type_store, ion_path, context))
+                .collect::<Vec<ValidationResult>>()
+        };
+
+        match self {
+            ConstraintValidation::Complete(result) => result,
+            ConstraintValidation::AllOf(all_of, type_validations) => {
+                let results = finish_all(type_validations, ion_path, context);
+                all_of.combine_results(results, ion_path, context)
+            }
+            ConstraintValidation::AnyOf(any_of, type_validations) => {
+                let results = finish_all(type_validations, ion_path, context);
+                any_of.combine_results(results, ion_path, context)
+            }
+            ConstraintValidation::OneOf(one_of, type_validations) => {
+                let results = finish_all(type_validations, ion_path, context);
+                one_of.combine_results(results, ion_path, context)
+            }
+            ConstraintValidation::Not(not, type_validation) => {
+                let result = type_validation.finish(container, type_store, ion_path, context);
+                not.negate_result(result, ion_path, context)
+            }
+            ConstraintValidation::Type(type_validation) => {
+                type_validation.finish(container, type_store, ion_path, context)
+            }
+            ConstraintValidation::ContainerLength(container_length, size) => {
+                container_length.validate_length(size, ion_path)
+            }
+            ConstraintValidation::Element(validation) => validation.finish(ion_path),
+            ConstraintValidation::Fields(validation) => validation.finish(ion_path, context),
+            ConstraintValidation::FieldNames(validation) => validation.finish(ion_path),
+            ConstraintValidation::Contains(validation) => validation.finish(container, ion_path),
+            ConstraintValidation::OrderedElements(validation) => {
+                validation.finish(type_store, ion_path)
+            }
+            ConstraintValidation::ValidValues(valid_values, children) => valid_values.validate(
+                &container.with_children(children),
+                type_store,
+                ion_path,
+                context,
+            ),
+        }
+    }
+}
+
+/// Reads the annotations of the value that the given reader is currently positioned on
+fn read_annotations<R: IonReader<Item = StreamItem, Symbol = Symbol>>(
+    reader: &mut R,
+) -> IonResult<Vec<Symbol>> {
+    if !reader.has_annotations() {
+        return Ok(vec![]);
+    }
+    reader.annotations().collect()
+}
+
+/// Returns the text of the field name of the value that the given reader is currently positioned on.
+/// A field name with unknown text can't be matched with the fields of a type, hence it is reported as an error.
+fn field_name_text<R: IonReader<Item = StreamItem, Symbol = Symbol>>(
+    reader: &mut R,
+) -> IonResult<String> {
+    match reader.field_name()?.text() {
+        Some(text) => Ok(text.to_owned()),
+        None => decoding_error("field name has unknown text"),
+    }
+}
+
+/// Represents a container whose child values are being materialized by [`read_current_element`]
+struct PartialContainer {
+    ion_type: IonType,
+    annotations: Vec<Symbol>,
+    // the field name of the container within its parent struct, if any
+    field_name: Option<Symbol>,
+    children: Vec<(Option<Symbol>, Element)>,
+}
+
+impl PartialContainer {
+    fn into_element(self) -> (Option<Symbol>, Element) {
+        let children = self.children.into_iter();
+        let value = match self.ion_type {
+            IonType::List => {
+                Value::List(children.map(|(_, child)| child).collect::<Vec<_>>().into())
+            }
+            IonType::SExp => {
+                Value::SExp(children.map(|(_, child)| child).collect::<Vec<_>>().into())
+            }
+            _ => Value::Struct(Struct::from_iter(children.map(|(field_name, child)| {
+                (
+                    field_name.expect("the child values of a struct have field names"),
+                    child,
+                )
+            }))),
+        };
+        (
+            self.field_name,
+            Element::from(value).with_annotations(self.annotations),
+        )
+    }
+}
+
+/// Materializes the value that the given reader is currently positioned on as an [`Element`].
+/// The nested containers are kept on an explicit stack rather than read recursively,
+/// so that a deeply nested value can't overflow the stack.
+///
+/// Returns `None` if the value has more than `max_depth` nested containers, in which case the reader is left
+/// positioned on the value as if it was read, since such a value can't be validated within the same depth anyway.
+pub(crate) fn read_current_element<R: IonReader<Item = StreamItem, Symbol = Symbol>>(
+    reader: &mut R,
+    max_depth: usize,
+) -> IonResult<Option<Element>> {
+    let depth = reader.depth();
+    let element = read_current_element_up_to(reader, max_depth)?;
+    if element.is_none() {
+        skip_current_value(reader, reader.depth() - depth)?;
+    }
+    Ok(element)
+}
+
+/// Materializes the value that the given reader is currently positioned on like [`read_current_element`],
+/// but leaves the reader positioned on the container that is nested too deeply when `None` is returned,
+/// for callers that give up on reading as soon as the maximum depth is exceeded.
+pub(crate) fn read_current_element_up_to<R: IonReader<Item = StreamItem, Symbol = Symbol>>(
+    reader: &mut R,
+    max_depth: usize,
+) -> IonResult<Option<Element>> {
+    // the containers that are being read, the innermost container is the last one
+    let mut containers: Vec<PartialContainer> = vec![];
+    loop {
+        // the reader is positioned on the value to read or on a child value of the innermost container
+        let field_name = match containers.last() {
+            Some(container) if container.ion_type == IonType::Struct => Some(reader.field_name()?),
+            _ => None,
+        };
+        let annotations = read_annotations(reader)?;
+        let mut element = match reader.current() {
+            StreamItem::Value(ion_type) if ion_type.is_container() => {
+                if containers.len() >= max_depth {
+                    return Ok(None);
+                }
+                reader.step_in()?;
+                containers.push(PartialContainer {
+                    ion_type,
+                    annotations,
+                    field_name,
+                    children: vec![],
+                });
+                None
+            }
+            _ => Some((
+                field_name,
+                Element::from(read_scalar_value(reader)?).with_annotations(annotations),
+            )),
+        };
+
+        // moves on to the next child value, completing the containers that don't have any more child values
+        loop {
+            let container = match containers.last_mut() {
+                Some(container) => container,
+                None => {
+                    let (_, element) = element.expect("the outermost value was read");
+                    return Ok(Some(element));
+                }
+            };
+            if let Some(child) = element.take() {
+                container.children.push(child);
+            }
+            match reader.next()? {
+                StreamItem::Value(_) | StreamItem::Null(_) => break,
+                StreamItem::Nothing => {
+                    reader.step_out()?;
+                    element = containers.pop().map(PartialContainer::into_element);
+                }
+            }
+        }
+    }
+}
+
+/// Skips the value that the given reader is currently positioned on, along with the remaining child values of the
+/// `open_containers` innermost containers that the reader stepped into, and steps out of these containers.
+/// The nested containers are stepped into rather than skipped by stepping out of them,
+/// as the reader skips the nested containers recursively.
+fn skip_current_value<R: IonReader<Item = StreamItem, Symbol = Symbol>>(
+    reader: &mut R,
+    open_containers: usize,
+) -> IonResult<()> {
+    let mut depth = open_containers;
+    loop {
+        if matches!(reader.current(), StreamItem::Value(ion_type) if ion_type.is_container()) {
+            reader.step_in()?;
+            depth += 1;
+        }
+        loop {
+            if depth == 0 {
+                return Ok(());
+            }
+            match reader.next()? {
+                StreamItem::Value(_) | StreamItem::Null(_) => break,
+                StreamItem::Nothing => {
+                    reader.step_out()?;
+                    depth -= 1;
+                }
+            }
+        }
+    }
+}
+
+/// Reads the null or scalar value that the given reader is currently positioned on
+fn read_scalar_value<R: IonReader<Item = StreamItem, Symbol = Symbol>>(
+    reader: &mut R,
+) -> IonResult<Value> {
+    Ok(match reader.current() {
+        StreamItem::Nothing => unreachable!("reader must be positioned on a value"),
+        StreamItem::Null(ion_type) => Value::Null(ion_type),
+        StreamItem::Value(ion_type) => match ion_type {
+            IonType::Null => unreachable!("non-null value had IonType::Null"),
+            IonType::Bool => Value::Bool(reader.read_bool()?),
+            IonType::Int => Value::Int(reader.read_int()?),
+            IonType::Float => Value::Float(reader.read_f64()?),
+            IonType::Decimal => Value::Decimal(reader.read_decimal()?),
+            IonType::Timestamp => Value::Timestamp(reader.read_timestamp()?),
+            IonType::Symbol => Value::Symbol(reader.read_symbol()?),
+            IonType::String => Value::String(reader.read_string()?),
+            IonType::Clob => Value::Clob(reader.read_clob()?.into()),
+            IonType::Blob => Value::Blob(reader.read_blob()?.into()),
+            IonType::List | IonType::SExp | IonType::Struct => {
+                unreachable!("containers are read by read_current_element")
+            }
+        },
+    })
+}
+
+#[cfg(test)]
+mod streaming_tests {
+    use super::*;
+    use crate::authority::MapDocumentAuthority;
+    use crate::result::IonSchemaError;
+    use crate::schema::Schema;
+    use crate::system::SchemaSystem;
+    use crate::violation::ViolationCode;
+    use ion_rs::ReaderBuilder;
+    use rstest::*;
+    use std::sync::Arc;
+
+    // helper function to be used by streaming validation tests
+    fn load_schema_from_text(text: &str) -> Arc<Schema> {
+        // map with (id, ion content)
+        let map_authority = [("sample.isl", text)];
+        let mut schema_system =
+            SchemaSystem::new(vec![Box::new(MapDocumentAuthority::new(map_authority))]);
+        schema_system.load_schema("sample.isl").unwrap()
+    }
+
+    fn root_causes(result: &ValidationResult) -> Vec<(ViolationCode, IonPath)> {
+        match result {
+            Ok(_) => vec![],
+            Err(violation) => violation
+                .flattened_violations()
+                .iter()
+                .map(|v| (v.code().to_owned(), v.ion_path().to_owned()))
+                .collect(),
+        }
+    }
+
+    #[rstest(
+        schema, type_name, values,
+        case::container_length_and_element(
+            load_schema_from_text(r#"
+                type::{ name: my_type, type: list, element: int, container_length: range::[1, 3] }
+            "#),
+            "my_type",
+            r#"[1, 2] [1, a] [] [1, 2, 3, 4] (1 2) null.list 5 [[1], 2]"#
+        ),
+        case::distinct_elements(
+            load_schema_from_text(r#"
+                $ion_schema_2_0
+                type::{ name: my_type, element: distinct::int }
+            "#),
+            "my_type",
+            r#"[1, 2] [1, 1] { a: 1, b: 1 } { a: 1, b: 2 }"#
+        ),
+        case::fields(
+            load_schema_from_text(r#"
+                type::{ name: my_type, type: struct, fields: closed::{ name: { type: string, occurs: required }, id: int } }
+            "#),
+            "my_type",
+            r#"{ name: "Ion", id: 1 } { id: 1 } { name: "Ion", id: a } { name: "Ion", greetings: "hello" } { name: "Ion", name: "Schema" } [1]"#
+        ),
+        case::field_names(
+            load_schema_from_text(r#"
+                $ion_schema_2_0
+                type::{ name: my_type, field_names: distinct::{ regex: "^[a-z]+$" } }
+            "#),
+            "my_type",
+            r#"{ a: 1, b: 2 } { a: 1, a: 2 } { A: 1 } [a]"#
+        ),
+        case::contains(
+            load_schema_from_text(r#"
+                type::{ name: my_type, contains: [true, 1, "hello"] }
+            "#),
+            "my_type",
+            r#"[true, 1, "hello"] ("hello" 1 true 2) [true, 1] { a: true } null.list"#
+        ),
+        case::ordered_elements(
+            load_schema_from_text(r#"
+                type::{ name: my_type, ordered_elements: [bool, { type: int, occurs: range::[1, 3] }, { type: string, occurs: optional }] }
+            "#),
+            "my_type",
+            r#"[true, 5, 6, 7, "hey"] [false, 7] [true] [5, true, "hey"] [true, "hey"] (true 1) { a: true }"#
+        ),
+        case::logical_constraints(
+            load_schema_from_text(r#"
+                type::{ name: short_list, type: list, container_length: range::[0, 2] }
+                type::{ name: int_list, type: list, element: int }
+                type::{ name: my_type, any_of: [short_list, int_list], not: { contains: [0] }, all_of: [{ type: list }], one_of: [short_list, { ordered_elements: [int, int, int] }] }
+            "#),
+            "my_type",
+            r#"[a, b] [1, 2, 3] [1, 2, 3, 4] [a, b, c] [0] [1, 2] (1 2)"#
+        ),
+        case::annotations_and_valid_values(
+            load_schema_from_text(r#"
+                type::{ name: my_type, annotations: closed::[a], valid_values: [[1, 2], (1 2), 5] }
+            "#),
+            "my_type",
+            r#"a::[1, 2] a::(1 2) b::[1, 2] a::[1] a::5 [1, 2]"#
+        ),
+        case::nested_types(
+            load_schema_from_text(r#"
+                type::{ name: my_type, type: $any, element: { type: list, element: { type: struct, fields: { a: int } } } }
+            "#),
+            "my_type",
+            r#"[[{ a: 1 }], [{ a: 2 }, { a: 3 }]] [[{ a: b }]] { a: [{ a: 1 }] } [[1]] null "hello""#
+        ),
+    )]
+    fn validate_stream_matches_validate(schema: Arc<Schema>, type_name: &str, values: &str) {
+        let type_def = schema.get_type(type_name).unwrap();
+        let elements = Element::read_all(values.as_bytes()).unwrap();
+
+        let mut reader = ReaderBuilder::new().build(values).unwrap();
+        let stream_results = type_def
+            .validate_stream(&mut reader)
+            .collect::<IonSchemaResult<Vec<ValidationResult>>>()
+            .unwrap();
+
+        assert_eq!(stream_results.len(), elements.len());
+        for (element, stream_result) in elements.iter().zip(stream_results) {
+            let result = type_def.validate(element);
+            // violation messages may describe the container differently for streamed containers,
+            // hence only the codes and paths of the root cause violations are compared
+            assert_eq!(
+                root_causes(&result),
+                root_causes(&stream_result),
+                "for value {element}"
+            );
+        }
+
+        // validate the same values as a document
+        let mut reader = ReaderBuilder::new().build(values).unwrap();
+        let stream_result = type_def.validate_document_stream(&mut reader).unwrap();
+        assert_eq!(type_def.validate(&elements).is_ok(), stream_result.is_ok());
+    }
+
+    #[rstest(
+        schema, valid_documents, invalid_documents,
+        case::document_constraints(
+            load_schema_from_text(r#"
+                type::{ name: my_doc, type: document, container_length: range::[1, 3], element: int, ordered_elements: [int, { type: int, occurs: range::[0, 2] }] }
+            "#),
+            vec!["1", "1 2 3"],
+            vec!["", "1 2 3 4", "1 a", "[1, 2]"]
+        ),
+    )]
+    fn validate_document_stream(
+        schema: Arc<Schema>,
+        valid_documents: Vec<&str>,
+        invalid_documents: Vec<&str>,
+    ) {
+        let type_def = schema.get_type("my_doc").unwrap();
+        for document in valid_documents {
+            let mut reader = ReaderBuilder::new().build(document).unwrap();
+            let result = type_def.validate_document_stream(&mut reader).unwrap();
+            assert!(result.is_ok(), "for document {document}");
+        }
+        for document in invalid_documents {
+            let mut reader = ReaderBuilder::new().build(document).unwrap();
+            let result = type_def.validate_document_stream(&mut reader).unwrap();
+            let elements = Element::read_all(document.as_bytes()).unwrap();
+            assert_eq!(
+                type_def.validate(&elements),
+                result,
+                "for document {document}"
+            );
+        }
+    }
+
+    #[test]
+    fn child_values_are_read_as_whole_elements() {
+        let mut reader = ReaderBuilder::new()
+            .build("[[1, [2, 3]], { a: [4] }, 5]")
+            .unwrap();
+        reader.next().unwrap();
+        // only the top level container is streamed, the containers nested in its child values are read as a whole
+        reader.step_in().unwrap();
+        let mut children = vec![];
+        while let StreamItem::Value(_) | StreamItem::Null(_) = reader.next().unwrap() {
+            children.push(read_current_element(&mut reader, 10).unwrap().unwrap());
+            assert_eq!(reader.depth(), 1);
+        }
+        reader.step_out().unwrap();
+        assert_eq!(
+            children,
+            Element::read_all("[1, [2, 3]] { a: [4] } 5".as_bytes()).unwrap()
+        );
+    }
+
+    #[test]
+    fn validate_stream_binary_reader() {
+        let schema = load_schema_from_text(
+            r#"type::{ name: my_type, type: struct, fields: { a: { type: list, element: int } } }"#,
+        );
+        let type_def = schema.get_type("my_type").unwrap();
+        let element =
+            Element::read_one(r#"annotation::{ a: [1, 2, 3], b: "hello" }"#.as_bytes()).unwrap();
+        let binary = element.to_binary().unwrap();
+
+        let mut reader = ReaderBuilder::new().build(binary).unwrap();
+        let results = type_def
+            .validate_stream(&mut reader)
+            .collect::<IonSchemaResult<Vec<ValidationResult>>>()
+            .unwrap();
+        assert_eq!(results, vec![Ok(())]);
+    }
+
+    #[test]
+    fn validate_stream_with_invalid_ion() {
+        let schema = load_schema_from_text(r#"type::{ name: my_type, type: list }"#);
+        let type_def = schema.get_type("my_type").unwrap();
+        let mut reader = ReaderBuilder::new().build("[1, 2] [1, ").unwrap();
+        let results: Vec<IonSchemaResult<ValidationResult>> =
+            type_def.validate_stream(&mut reader).collect();
+        assert!(matches!(results[0], Ok(Ok(()))));
+        assert!(results.iter().any(|r| r.is_err()));
+    }
+
+    #[test]
+    fn validate_stream_with_unknown_field_name() {
+        let schema = load_schema_from_text(r#"type::{ name: my_type, type: struct }"#);
+        let type_def = schema.get_type("my_type").unwrap();
+        // `$0` is a symbol with unknown text
+        let mut reader = ReaderBuilder::new().build("{ a: 1 } { $0: 1 }").unwrap();
+        let results: Vec<IonSchemaResult<ValidationResult>> =
+            type_def.validate_stream(&mut reader).collect();
+        assert!(matches!(results[0], Ok(Ok(()))));
+        assert!(matches!(results[1], Err(IonSchemaError::IonError { .. })));
+    }
+
+    #[rstest(
+        max_depth, expected,
+        case::within_max_depth(1_001, Ok(())),
+        case::exceeds_max_depth(1_000, Err(ViolationCode::ValidationLimitExceeded)),
+        case::default_max_depth(ValidationOptions::default().max_depth(), Err(ViolationCode::ValidationLimitExceeded))
+    )]
+    fn validate_stream_with_deeply_nested_value(
+        max_depth: usize,
+        expected: Result<(), ViolationCode>,
+    ) {
+        let schema = load_schema_from_text(
+            r#"
+                type::{ name: my_type, type: list, element: list }
+                type::{ name: my_document, type: document, element: list }
+            "#,
+        );
+        let options = ValidationOptions::default().with_max_depth(max_depth);
+        let depth = 1_000;
+        // a value with 1001 nested lists, used as a child value of the top level values below
+        let nested = format!("[{}{}]", "[".repeat(depth), "]".repeat(depth));
+
+        let my_type = schema.get_type("my_type").unwrap();
+        let stream = format!("[{nested}, {nested}] [[]]");
+        let mut reader = ReaderBuilder::new().build(stream.as_str()).unwrap();
+        let results: Vec<ValidationResult> = my_type
+            .validate_stream_with(&mut reader, options)
+            .collect::<IonSchemaResult<_>>()
+            .unwrap();
+        assert_eq!(
+            results[0].as_ref().map_err(|violation| violation.code()),
+            expected.as_ref()
+        );
+        // the value after a value that exceeds the maximum depth is still validated
+        assert_eq!(results[1], Ok(()));
+
+        let my_document = schema.get_type("my_document").unwrap();
+        let stream = format!("{nested} {nested} []");
+        let mut reader = ReaderBuilder::new().build(stream.as_str()).unwrap();
+        let result = my_document
+            .validate_document_stream_with(&mut reader, options)
+            .unwrap();
+        assert_eq!(
+            result.as_ref().map_err(|violation| violation.code()),
+            expected.as_ref()
+        );
+    }
+}
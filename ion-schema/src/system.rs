@@ -19,25 +19,35 @@
 //! let schema = schema_system.load_schema("sample.isl");
 //! ```
 
-use crate::authority::DocumentAuthority;
+use crate::authority::{read_ion_content, DocumentAuthority, ReadOptions};
+use crate::dependency_graph::{Dependency, DependencyGraph};
 use crate::isl::isl_constraint::IslConstraint;
 use crate::isl::isl_import::{IslImport, IslImportType};
 use crate::isl::isl_type::{IslType, IslTypeImpl};
 use crate::isl::{IslSchema, IslVersion};
+use crate::limits::LoadLimits;
 use crate::result::{
-    invalid_schema_error, invalid_schema_error_raw, unresolvable_schema_error,
-    unresolvable_schema_error_raw, IonSchemaError, IonSchemaResult,
+    invalid_schema_error, invalid_schema_error_raw, schema_not_found_error,
+    unresolvable_schema_error, unresolvable_schema_error_raw, DiagnosticCollector, IonSchemaError,
+    IonSchemaResult, SchemaDiagnostic, ValidationError,
 };
 use crate::schema::Schema;
-use crate::types::{BuiltInTypeDefinition, Nullability, TypeDefinitionImpl, TypeDefinitionKind};
-use crate::{is_isl_version_marker, is_reserved_word, UserReservedFields};
+use crate::types::{
+    BuiltInTypeDefinition, Nullability, TypeDefinition, TypeDefinitionImpl, TypeDefinitionKind,
+    ValidationOptions,
+};
+use crate::{is_isl_version_marker, is_reserved_word, IonSchemaElement, UserReservedFields};
 use ion_rs::element::{Annotations, Element};
 use ion_rs::types::IonType::Struct;
 use ion_rs::IonType;
 use regex::Regex;
 use std::collections::{HashMap, HashSet};
 use std::io::ErrorKind;
-use std::sync::Arc;
+use std::iter::Peekable;
+use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, OnceLock, PoisonError};
+use std::thread::{self, ThreadId};
 
 // TODO: Shift PendingTypes and TypeStore implementations to a separate module
 /// Stores information about types that are in the process of being defined.
@@ -49,6 +59,7 @@ use std::sync::Arc;
 /// * A nested anonymous type definition.
 /// * A reference to type definition that is followed by current type definition. These type references
 ///   will be deferred to later check if a type definition with that name exists in the schema.
+///
 /// Because the [`SchemaSystem`] does not yet know the complete definition
 /// of these types, it cannot find them in the [`TypeStore`].
 /// An instance of [`PendingTypes`] is used to track information about types
@@ -87,13 +98,14 @@ impl PendingTypes {
     /// This method is used after a schema named type/root type is loaded entirely into [`PendingTypes`]
     /// * `type_store` - The TypeStore which will be updated with the types within this PendingType
     /// * `load_isl_import` - If this argument is Some(isl_import), then we are not within an import process of schema.
-    ///                       Based on given enum variant isl_import we will add the types to type_store.
-    ///                       Otherwise we will add all the types from this PendingTypes to TypeStore.
+    ///   Based on given enum variant isl_import we will add the types to type_store.
+    ///   Otherwise we will add all the types from this PendingTypes to TypeStore.
     /// * `isl_type_names` - The isl type names defined within the schema. This will be used to determine
-    ///                      if a type definition actually exists within the schema. If a type definition from this list
-    ///                      exists in [`PendingTypes`] it would have been added as a deferred type definition.
-    ///                      This deferred type will be loaded into [`TypeStore`] as it is and will be replaced with a type definition
-    ///                      once it is resolved.
+    ///   if a type definition actually exists within the schema. If a type definition from this list
+    ///   exists in [`PendingTypes`] it would have been added as a deferred type definition.
+    ///   This deferred type will be loaded into [`TypeStore`] as it is and will be replaced with a type definition
+    ///   once it is resolved.
+    ///
     /// Returns true, if this update is not for an isl import type or it is for an isl import type but it is added to the type_store
     /// Otherwise, returns false if this update is for an isl import type and it is not yet added to the type_store.
     pub fn update_type_store(
@@ -175,11 +187,11 @@ impl PendingTypes {
         import_type_name: &str,
         type_store: &mut TypeStore,
     ) -> Option<IonSchemaResult<TypeDefinitionImpl>> {
-        return match self.ids_by_name.get(import_type_name) {
+        match self.ids_by_name.get(import_type_name) {
             Some(id) => self.types_by_id[*id]
                 .to_owned()
-                .and_then(|type_def| match type_def {
-                    TypeDefinitionKind::Named(named_type_def) => Some(Ok(named_type_def)),
+                .map(|type_def| match type_def {
+                    TypeDefinitionKind::Named(named_type_def) => Ok(named_type_def),
                     TypeDefinitionKind::Anonymous(_) => {
                         unreachable!(
                             "The TypeDefinition for the imported type '{}' was Anonymous.",
@@ -194,7 +206,7 @@ impl PendingTypes {
                     }
                 }),
             None => match type_store.get_type_id_by_name(import_type_name) {
-                Some(id) => match type_store.types_by_id[*id].to_owned() {
+                Some(id) => match type_store.get_type_by_id(*id).unwrap().to_owned() {
                     TypeDefinitionKind::Named(named_type_def) => Some(Ok(named_type_def)),
                     TypeDefinitionKind::Anonymous(_) => {
                         unreachable!(
@@ -211,7 +223,7 @@ impl PendingTypes {
                 },
                 None => None,
             },
-        };
+        }
     }
 
     // helper method to update type store with all the types from this PendingTypes
@@ -321,9 +333,22 @@ impl PendingTypes {
         Ok(())
     }
 
-    /// Returns total number of types stored in the [`TypeStore`]
+    /// Sets the given schema id for all the named and anonymous types in this [`PendingTypes`],
+    /// this is used before the types are added to the [`TypeStore`] to keep track of the schema that defines them
+    pub(crate) fn set_schema_id(&mut self, schema_id: &str) {
+        for type_def in self.types_by_id.iter_mut().flatten() {
+            match type_def {
+                TypeDefinitionKind::Named(type_def) | TypeDefinitionKind::Anonymous(type_def) => {
+                    type_def.set_schema_id(schema_id)
+                }
+                TypeDefinitionKind::BuiltIn(_) => {}
+            }
+        }
+    }
+
+    /// Returns the [`TypeId`] of the next type that is added to the [`PendingTypes`]
     pub(crate) fn get_total_types(&self, type_store: &mut TypeStore) -> usize {
-        self.types_by_id.len() + type_store.types_by_id.len()
+        self.types_by_id.len() + type_store.next_type_id()
     }
 
     /// Provides the [`TypeId`] associated with given name if it exists in the [`TypeStore`] or [`PendingTypes`]  
@@ -334,7 +359,7 @@ impl PendingTypes {
         type_store: &mut TypeStore,
     ) -> Option<TypeId> {
         match self.ids_by_name.get(name) {
-            Some(id) => Some(*id + type_store.types_by_id.len()),
+            Some(id) => Some(*id + type_store.next_type_id()),
             None => type_store.get_type_id_by_name(name).copied(),
         }
     }
@@ -349,14 +374,16 @@ impl PendingTypes {
         type_store: &mut TypeStore,
     ) -> TypeId {
         if let Some(exists) = self.ids_by_name.get(name) {
-            return exists.to_owned() + type_store.types_by_id.len();
+            return exists.to_owned() + type_store.next_type_id();
         }
         match type_store.update_deferred_type_def(type_def.to_owned(), name) {
+            // the type already exists in the type_store, e.g. a type of a schema that was loaded by an earlier import
+            None if type_id < type_store.next_type_id() => type_id,
             None => {
-                let type_id = type_id - type_store.types_by_id.len();
+                let type_id = type_id - type_store.next_type_id();
                 self.ids_by_name.insert(name.to_owned(), type_id);
                 self.types_by_id[type_id] = Some(TypeDefinitionKind::Named(type_def));
-                type_id + type_store.types_by_id.len()
+                type_id + type_store.next_type_id()
             }
             Some(exists) => exists,
         }
@@ -370,7 +397,7 @@ impl PendingTypes {
         type_def: TypeDefinitionImpl,
         type_store: &mut TypeStore,
     ) -> TypeId {
-        self.types_by_id[type_id - type_store.types_by_id.len()] =
+        self.types_by_id[type_id - type_store.next_type_id()] =
             Some(TypeDefinitionKind::Anonymous(type_def));
         type_id
     }
@@ -381,7 +408,7 @@ impl PendingTypes {
         // while we resolve a type using the PendingTypes (a temporary type store used while we resolve a type definition)
         // the type id for any type definition should be the PendingType's types_by_id length in  + TypeStore's types_by_id length
         // This gives a correct type id when all the types within PendingTypes are shifted to TypeStore
-        self.parent = Some((name, self.types_by_id.len() + type_store.types_by_id.len()))
+        self.parent = Some((name, self.types_by_id.len() + type_store.next_type_id()))
     }
 
     /// Provides parent information: (parent name, type id)
@@ -402,7 +429,7 @@ impl PendingTypes {
     ) -> TypeId {
         if let Some(name) = type_name {
             if let Some(exists) = self.ids_by_name.get(&name) {
-                return exists.to_owned() + type_store.types_by_id.len();
+                return exists.to_owned() + type_store.next_type_id();
             }
             if let Some(exists) = type_store.get_type_id_by_name(&name) {
                 return exists.to_owned();
@@ -410,7 +437,7 @@ impl PendingTypes {
         }
         let type_id = self.types_by_id.len();
         self.types_by_id.push(None);
-        type_id + type_store.types_by_id.len()
+        type_id + type_store.next_type_id()
     }
 
     /// Adds the unresolved type as None before it gets resolved and gets the associated [`TypeId`]
@@ -424,7 +451,7 @@ impl PendingTypes {
         self.types_by_id.push(Some(TypeDefinitionKind::Named(
             TypeDefinitionImpl::new_deferred_type_def(alias.to_owned()),
         )));
-        type_id + type_store.types_by_id.len()
+        type_id + type_store.next_type_id()
     }
 }
 
@@ -451,27 +478,61 @@ static DERIVED_ISL_TYPES: [&str; 10] = [
 
 pub type TypeId = usize;
 
+// the number of TypeIds reserved for each type definition and import of a schema, which is usually enough for the
+// anonymous types defined within the type definitions
+const TYPE_IDS_RESERVED_PER_DEFINITION: usize = 16;
+
 /// Defines a cache that can be used to store resolved type definitions of a [`Schema`]
+///
+/// Besides the types that are added to it, a [`TypeStore`] refers to [`SharedTypes`] that are never copied:
+/// the built-in types, which are shared by all the type stores, and the types of the imported schemas,
+/// which are shared with the type stores of those schemas.
 #[derive(Debug, Clone)]
 pub struct TypeStore {
-    builtin_type_ids_by_name: HashMap<String, TypeId>, // stores all the builtin types used within this schema
+    builtin_type_ids_by_name: Arc<HashMap<String, TypeId>>, // stores all the builtin types, shared by all the type stores
     imported_type_ids_by_name: HashMap<String, TypeId>, // stores all the imported types of a schema
     ids_by_name: HashMap<String, TypeId>, // stores named types defined within the schema
+    shared_types: Vec<SharedTypes>,       // sorted by the TypeId of their first type
+    first_type_id: TypeId,                // the TypeId of the first type in types_by_id
     types_by_id: Vec<TypeDefinitionKind>,
 }
 
+/// Represents the types of a [`TypeStore`] that were frozen so that they can be shared with other type stores.
+/// [`TypeId`]s of the shared types never overlap within a [`Resolver`], so a type store can refer to the shared types
+/// of any number of imported schemas with the [`TypeId`]s that were assigned to them by their own schema.
+#[derive(Debug, Clone)]
+struct SharedTypes {
+    first_type_id: TypeId,
+    types: Arc<[TypeDefinitionKind]>,
+}
+
+impl SharedTypes {
+    fn end_type_id(&self) -> TypeId {
+        self.first_type_id + self.types.len()
+    }
+}
+
 impl Default for TypeStore {
     fn default() -> Self {
-        let mut type_store = Self {
-            builtin_type_ids_by_name: HashMap::new(),
-            imported_type_ids_by_name: HashMap::new(),
-            ids_by_name: HashMap::new(),
-            types_by_id: Vec::new(),
-        };
-        type_store
-            .preload()
-            .expect("The type store didn't preload with built-in types correctly");
-        type_store
+        // the built-in types are only loaded once, and then shared by all the type stores
+        static BUILT_IN_TYPE_STORE: OnceLock<TypeStore> = OnceLock::new();
+        BUILT_IN_TYPE_STORE
+            .get_or_init(|| {
+                let mut type_store = Self {
+                    builtin_type_ids_by_name: Arc::new(HashMap::new()),
+                    imported_type_ids_by_name: HashMap::new(),
+                    ids_by_name: HashMap::new(),
+                    shared_types: vec![],
+                    first_type_id: 0,
+                    types_by_id: Vec::new(),
+                };
+                type_store
+                    .preload()
+                    .expect("The type store didn't preload with built-in types correctly");
+                type_store.freeze();
+                type_store
+            })
+            .clone()
     }
 }
 
@@ -526,6 +587,69 @@ impl TypeStore {
         Ok(())
     }
 
+    /// Returns the [`TypeId`] that will be assigned to the next type added to the [`TypeStore`]
+    pub(crate) fn next_type_id(&self) -> TypeId {
+        self.first_type_id + self.types_by_id.len()
+    }
+
+    /// Moves the types added to the [`TypeStore`] into [`SharedTypes`], so that they can be shared with
+    /// the type stores of the schemas that import them
+    pub(crate) fn freeze(&mut self) {
+        if self.types_by_id.is_empty() {
+            return;
+        }
+        let shared_types = SharedTypes {
+            first_type_id: self.first_type_id,
+            types: std::mem::take(&mut self.types_by_id).into(),
+        };
+        self.first_type_id = shared_types.end_type_id();
+        self.shared_types.push(shared_types);
+    }
+
+    /// Adds the [`SharedTypes`] of the given [`TypeStore`] of an imported schema, so that the imported types can be
+    /// referred to by their [`TypeId`]s. This must be done before any type is added to this [`TypeStore`].
+    /// Returns an error if the shared types conflict with other shared types starting at the same [`TypeId`],
+    /// e.g. when they come from schemas that were loaded by different [`Resolver`]s.
+    pub(crate) fn add_shared_types(
+        &mut self,
+        imported_type_store: &TypeStore,
+    ) -> IonSchemaResult<()> {
+        if !self.types_by_id.is_empty() || !imported_type_store.types_by_id.is_empty() {
+            return invalid_schema_error(
+                "Imported types must be resolved before the types defined within the schema",
+            );
+        }
+        for shared_types in &imported_type_store.shared_types {
+            match self
+                .shared_types
+                .binary_search_by_key(&shared_types.first_type_id, |s| s.first_type_id)
+            {
+                // e.g. the built-in types, or the types of a schema that was imported twice
+                Ok(index) if Arc::ptr_eq(&self.shared_types[index].types, &shared_types.types) => {}
+                Ok(_) => {
+                    return invalid_schema_error(format!(
+                        "Imported types starting at type id {} conflict with other imported types",
+                        shared_types.first_type_id
+                    ));
+                }
+                Err(index) => {
+                    self.first_type_id = self.first_type_id.max(shared_types.end_type_id());
+                    self.shared_types.insert(index, shared_types.to_owned());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Makes the types that are added to the [`TypeStore`] start from given [`TypeId`], unless it would make them
+    /// overlap with the shared types. This is used to give unique [`TypeId`]s to the types of each schema
+    /// loaded by a [`Resolver`].
+    pub(crate) fn start_types_at(&mut self, type_id: TypeId) {
+        if self.types_by_id.is_empty() {
+            self.first_type_id = self.first_type_id.max(type_id);
+        }
+    }
+
     /// Returns [`TypeId`]s stored in the [`TypeStore`] to be used by [`SchemaTypeIterator`]
     pub(crate) fn get_types(&self) -> Vec<TypeId> {
         self.ids_by_name.values().cloned().collect()
@@ -536,17 +660,30 @@ impl TypeStore {
         self.imported_type_ids_by_name.values().cloned().collect()
     }
 
+    /// Returns the names and [`TypeId`]s of the imported types stored in the [`TypeStore`]
+    pub(crate) fn get_imported_types(&self) -> impl Iterator<Item = (&String, &TypeId)> {
+        self.imported_type_ids_by_name.iter()
+    }
+
+    /// Provides the [`TypeId`] associated with given name if it exists in the [`TypeStore`] as an imported type
+    /// Otherwise returns None
+    pub(crate) fn get_imported_type_id_by_name(&self, name: &str) -> Option<TypeId> {
+        self.imported_type_ids_by_name.get(name).copied()
+    }
+
+    /// Removes the given name from the named types defined within the schema, so that a new type can be added with it.
+    /// The hidden type stays in the [`TypeStore`] for the types that refer to it by its [`TypeId`].
+    pub(crate) fn hide_named_type(&mut self, name: &str) {
+        self.ids_by_name.remove(name);
+    }
+
     /// Provides the [`Type`] associated with given name if it exists in the [`TypeStore`]  
     /// Otherwise returns None
     pub(crate) fn get_type_by_name(&self, name: &str) -> Option<&TypeDefinitionKind> {
         self.ids_by_name
             .get(name)
-            .and_then(|id| self.types_by_id.get(*id))
-            .or_else(|| {
-                self.imported_type_ids_by_name
-                    .get(name)
-                    .and_then(|id| self.types_by_id.get(*id))
-            })
+            .or_else(|| self.imported_type_ids_by_name.get(name))
+            .and_then(|id| self.get_type_by_id(*id))
     }
 
     /// Provides the [`TypeId`] associated with given name if it exists in the [`TypeStore`] either as
@@ -579,7 +716,15 @@ impl TypeStore {
     /// Provides the [`Type`] associated with given [`TypeId`] if it exists in the [`TypeStore`]  
     /// Otherwise returns None
     pub(crate) fn get_type_by_id(&self, id: TypeId) -> Option<&TypeDefinitionKind> {
-        self.types_by_id.get(id)
+        if id >= self.first_type_id {
+            return self.types_by_id.get(id - self.first_type_id);
+        }
+        let index = self
+            .shared_types
+            .partition_point(|shared_types| shared_types.first_type_id <= id)
+            .checked_sub(1)?;
+        let shared_types = &self.shared_types[index];
+        shared_types.types.get(id - shared_types.first_type_id)
     }
 
     /// Adds the [`NamedTypeDefinition`] and the associated name in the [`TypeStore`] and returns the [`TypeId`] for it
@@ -589,7 +734,7 @@ impl TypeStore {
         if let Some(exists) = self.ids_by_name.get(name) {
             return exists.to_owned();
         }
-        let type_id = self.types_by_id.len();
+        let type_id = self.next_type_id();
         self.ids_by_name.insert(name.to_owned(), type_id);
         self.types_by_id.push(TypeDefinitionKind::Named(type_def));
         type_id
@@ -608,8 +753,10 @@ impl TypeStore {
             {
                 // if existing_type_def is a deferred type def then this is the definition for it,
                 // resolve the deferred type definition here by replacing with given type definition
-                if existing_type_def.is_deferred_type_def() {
-                    self.types_by_id[*exists] = TypeDefinitionKind::Named(type_def);
+                // (the shared types never contain deferred type definitions as they belong to loaded schemas)
+                if existing_type_def.is_deferred_type_def() && *exists >= self.first_type_id {
+                    self.types_by_id[*exists - self.first_type_id] =
+                        TypeDefinitionKind::Named(type_def);
                 }
             }
             return Some(*exists);
@@ -623,20 +770,13 @@ impl TypeStore {
         &mut self,
         builtin_type_definition: &BuiltInTypeDefinition,
     ) -> TypeId {
-        let builtin_type_name = match builtin_type_definition {
-            BuiltInTypeDefinition::Atomic(ion_type, is_nullable) => match is_nullable {
-                Nullability::Nullable => format!("${ion_type}"),
-                Nullability::NotNullable => format!("{ion_type}"),
-            },
-            BuiltInTypeDefinition::Derived(other_type) => other_type.name().to_owned().unwrap(),
-        };
+        let builtin_type_name = builtin_type_definition.name();
 
         if let Some(exists) = self.builtin_type_ids_by_name.get(&builtin_type_name) {
             return exists.to_owned();
         }
-        let type_id = self.types_by_id.len();
-        self.builtin_type_ids_by_name
-            .insert(builtin_type_name, type_id);
+        let type_id = self.next_type_id();
+        Arc::make_mut(&mut self.builtin_type_ids_by_name).insert(builtin_type_name, type_id);
         self.types_by_id.push(TypeDefinitionKind::BuiltIn(
             builtin_type_definition.to_owned(),
         ));
@@ -658,7 +798,7 @@ impl TypeStore {
         if let Some(exists) = self.imported_type_ids_by_name.get(name) {
             return exists.to_owned();
         }
-        let type_id = self.types_by_id.len();
+        let type_id = self.next_type_id();
         self.imported_type_ids_by_name
             .insert(name.to_owned(), type_id);
         self.types_by_id.push(TypeDefinitionKind::Named(type_def));
@@ -667,7 +807,7 @@ impl TypeStore {
 
     /// Adds the [`Type`] in the [`TypeStore`] and returns the [`TypeId`] for it
     pub(crate) fn add_anonymous_type(&mut self, type_def: TypeDefinitionImpl) -> TypeId {
-        let type_id = self.types_by_id.len();
+        let type_id = self.next_type_id();
         self.types_by_id
             .push(TypeDefinitionKind::Anonymous(type_def));
         type_id
@@ -676,16 +816,98 @@ impl TypeStore {
 
 /// Provides functions to load [`Schema`] with type definitions using authorities for [`SchemaSystem`]
 pub struct Resolver {
-    authorities: Vec<Box<dyn DocumentAuthority>>,
+    // authorities are reference counted so that a [`SharedSchemaSystem`] can use them with a resolver per load
+    authorities: Vec<Arc<dyn DocumentAuthority>>,
     resolved_schema_cache: HashMap<String, Arc<Schema>>,
+    // type definitions looked up by name in each resolved schema, removed along with the schema
+    type_definition_cache: HashMap<String, HashMap<String, TypeDefinition>>,
+    // ids of the schemas imported by each resolved schema, used to find the schemas that imported an invalidated schema
+    imported_schema_ids: HashMap<String, HashSet<String>>,
+    // fingerprints of the schemas read from authorities, used to find the schemas that were modified since they were read
+    fingerprints: HashMap<String, u64>,
+    // the TypeId after the ranges of TypeIds reserved so far, so that the types of the schemas never overlap.
+    // It is shared by the resolvers of a [`SharedSchemaSystem`], each schema reserves a range of TypeIds from it.
+    next_type_id: Arc<AtomicUsize>,
+    // the schemas loaded by a [`SharedSchemaSystem`], through which this resolver loads schemas and their imports
+    schema_slots: Option<Arc<SchemaSlots>>,
+    // ids of the schemas that are being loaded, where each schema is imported by the schema before it
+    loading_schema_ids: Vec<String>,
+    load_limits: LoadLimits,
+    // whether the fingerprints of the schemas are recorded when they are read from the authorities
+    change_detection: bool,
 }
 
 impl Resolver {
     pub fn new(authorities: Vec<Box<dyn DocumentAuthority>>) -> Self {
+        Self::with_shared_authorities(authorities.into_iter().map(Arc::from).collect())
+    }
+
+    fn with_shared_authorities(authorities: Vec<Arc<dyn DocumentAuthority>>) -> Self {
         Self {
             authorities,
             resolved_schema_cache: HashMap::new(),
+            type_definition_cache: HashMap::new(),
+            imported_schema_ids: HashMap::new(),
+            fingerprints: HashMap::new(),
+            next_type_id: Arc::new(AtomicUsize::new(TypeStore::default().next_type_id())),
+            schema_slots: None,
+            loading_schema_ids: vec![],
+            load_limits: LoadLimits::default(),
+            change_detection: false,
+        }
+    }
+
+    /// Removes all the schemas from the schema cache
+    fn clear_cache(&mut self) {
+        self.resolved_schema_cache.clear();
+        self.type_definition_cache.clear();
+        self.imported_schema_ids.clear();
+        self.fingerprints.clear();
+    }
+
+    /// Removes the schema with given id and all the schemas that imported it, directly or transitively, from the
+    /// schema cache. Returns the ids of the removed schemas.
+    fn invalidate_schema(&mut self, id: &str) -> Vec<String> {
+        let mut invalidated_ids = vec![];
+        let mut pending_ids = vec![id.to_owned()];
+        let mut visited_ids = HashSet::new();
+        while let Some(id) = pending_ids.pop() {
+            if !visited_ids.insert(id.to_owned()) {
+                continue;
+            }
+            pending_ids.extend(
+                self.imported_schema_ids
+                    .iter()
+                    .filter(|(_, imported_ids)| imported_ids.contains(&id))
+                    .map(|(importing_id, _)| importing_id.to_owned()),
+            );
+            self.imported_schema_ids.remove(&id);
+            self.fingerprints.remove(&id);
+            self.type_definition_cache.remove(&id);
+            if self.resolved_schema_cache.remove(&id).is_some() {
+                invalidated_ids.push(id);
+            }
         }
+        invalidated_ids.sort();
+        invalidated_ids
+    }
+
+    /// Invalidates the schemas whose fingerprint changed since they were read from the authorities.
+    /// Returns the ids of the schemas that were removed from the schema cache.
+    fn invalidate_modified_schemas(&mut self) -> Vec<String> {
+        let modified_ids: Vec<String> = self
+            .fingerprints
+            .iter()
+            .filter(|(id, fingerprint)| self.fingerprint(id) != Some(**fingerprint))
+            .map(|(id, _)| id.to_owned())
+            .collect();
+        let mut invalidated_ids: Vec<String> = modified_ids
+            .iter()
+            .flat_map(|id| self.invalidate_schema(id))
+            .collect();
+        invalidated_ids.sort();
+        invalidated_ids.dedup();
+        invalidated_ids
     }
 
     pub fn schema_from_isl_types<A: AsRef<str>, B: Into<Vec<IslType>>>(
@@ -727,7 +949,8 @@ impl Resolver {
                         &isl_type.type_definition,
                         &mut type_store,
                         pending_types,
-                    )?
+                    )
+                    .map_err(|error| with_schema_location(error, id.as_ref(), None))?
                 }
                 None => {
                     // top level schema types can not be anonymous
@@ -737,6 +960,7 @@ impl Resolver {
         }
 
         // add all types from pending_types to type_store
+        pending_types.set_schema_id(id.as_ref());
         pending_types.update_type_store(&mut type_store, None, &isl_type_names)?;
         Ok(Schema::new(id, Arc::new(type_store)))
     }
@@ -746,6 +970,17 @@ impl Resolver {
         &mut self,
         elements: I,
         id: &str,
+    ) -> IonSchemaResult<IslSchema> {
+        self.isl_schema_from_elements_with(elements, id, &mut DiagnosticCollector::fail_fast())
+    }
+
+    // Converts given owned elements into ISL representation, the recoverable errors in the header, types and footer
+    // are reported to the given collector which decides whether the remaining elements are converted.
+    fn isl_schema_from_elements_with<I: Iterator<Item = Element>>(
+        &mut self,
+        elements: I,
+        id: &str,
+        collector: &mut DiagnosticCollector,
     ) -> IonSchemaResult<IslSchema> {
         // properties that will be stored in the ISL representation
         let mut isl_imports: Vec<IslImport> = vec![];
@@ -754,28 +989,41 @@ impl Resolver {
         let mut open_content = vec![];
         let mut isl_user_reserved_fields = UserReservedFields::default();
         let mut isl_version = IslVersion::V1_0;
+        let mut isl_type_names: HashSet<String> = HashSet::new();
 
         let mut found_header = false;
         let mut found_footer = false;
         let mut found_isl_version_marker = false;
+        let mut total_elements = 0;
 
         for value in elements {
             let annotations: &Annotations = value.annotations();
 
+            // the limits are checked before converting the value, so that a value exceeding them is never converted
+            if let Err(error) = self.load_limits.check_element(&value, &mut total_elements) {
+                let type_name = value
+                    .as_struct()
+                    .filter(|_| annotations.contains("type"))
+                    .and_then(|isl_type| isl_type.get("name"))
+                    .and_then(|name| name.as_text());
+                return Err(match type_name {
+                    Some(type_name) => error.with_type_name(type_name),
+                    None => error,
+                });
+            }
+
             // load header for schema
             if !found_isl_version_marker
                 && value.ion_type() == IonType::Symbol
                 && is_isl_version_marker(value.as_text().unwrap())
             {
                 // This implementation supports Ion Schema 1.0 and Ion Schema 2.0
-                isl_version = match value.as_text().unwrap() {
-                    "$ion_schema_1_0" => IslVersion::V1_0,
-                    "$ion_schema_2_0" => IslVersion::V2_0,
-                    _ => {
-                        return invalid_schema_error(format!(
-                            "Unsupported Ion Schema Language version: {value}"
-                        ))
-                    }
+                match value.as_text().unwrap() {
+                    "$ion_schema_1_0" => isl_version = IslVersion::V1_0,
+                    "$ion_schema_2_0" => isl_version = IslVersion::V2_0,
+                    _ => collector.recover(invalid_schema_error_raw(format!(
+                        "Unsupported Ion Schema Language version: {value}"
+                    )))?,
                 };
                 found_isl_version_marker = true;
             } else if annotations.contains("schema_header") {
@@ -787,33 +1035,53 @@ impl Resolver {
                     found_isl_version_marker = true;
                 }
 
-                let schema_header = try_to!(value.as_struct());
+                let schema_header = match value.as_struct() {
+                    Some(schema_header) => schema_header,
+                    None => {
+                        collector.recover(invalid_schema_error_raw(
+                            "schema_header must be a non-null struct",
+                        ))?;
+                        continue;
+                    }
+                };
                 if let Some(imports) = schema_header.get("imports").and_then(|it| it.as_sequence())
                 {
                     for import in imports.elements() {
-                        let isl_import = IslImport::from_ion_element(import)?;
-                        isl_imports.push(isl_import);
+                        match IslImport::from_ion_element(import) {
+                            Ok(isl_import) => isl_imports.push(isl_import),
+                            Err(error) => collector.recover(error)?,
+                        }
                     }
                 }
                 if isl_version == IslVersion::V2_0 {
                     if let Some(user_reserved_fields_element) =
                         schema_header.get("user_reserved_fields")
                     {
-                        if !user_reserved_fields_element.annotations().is_empty() {
-                            return invalid_schema_error(
-                                "User reserved field must be an unannotated struct",
-                            )?;
+                        let user_reserved_fields =
+                            if !user_reserved_fields_element.annotations().is_empty() {
+                                invalid_schema_error(
+                                    "User reserved field must be an unannotated struct",
+                                )
+                            } else {
+                                user_reserved_fields_element
+                                    .as_struct()
+                                    .ok_or(invalid_schema_error_raw(
+                                        "User reserved field must be a non-null struct",
+                                    ))
+                                    .and_then(UserReservedFields::from_ion_elements)
+                            };
+                        match user_reserved_fields {
+                            Ok(user_reserved_fields) => {
+                                isl_user_reserved_fields = user_reserved_fields
+                            }
+                            Err(error) => collector.recover(error)?,
                         }
-                        let user_reserved_fields_struct = user_reserved_fields_element
-                            .as_struct()
-                            .ok_or(invalid_schema_error_raw(
-                                "User reserved field must be a non-null struct",
-                            ))?;
-
-                        isl_user_reserved_fields =
-                            UserReservedFields::from_ion_elements(user_reserved_fields_struct)?;
                     }
-                    isl_user_reserved_fields.validate_field_names_in_header(schema_header)?;
+                    if let Err(error) =
+                        isl_user_reserved_fields.validate_field_names_in_header(schema_header)
+                    {
+                        collector.recover(error)?;
+                    }
                 }
             }
             // load types for schema
@@ -825,24 +1093,55 @@ impl Resolver {
                 }
 
                 // convert Element to IslType
-                let isl_type: IslTypeImpl =
-                    IslTypeImpl::from_owned_element(isl_version, &value, &mut isl_inline_imports)?;
-                if isl_type.name().is_none() {
-                    // if a top level type definition doesn't contain `name` field return an error
-                    return invalid_schema_error(
-                        "Top level types must contain field `name` in their definition",
+                let isl_type: IslTypeImpl = match IslTypeImpl::from_owned_element_with(
+                    isl_version,
+                    &value,
+                    &mut isl_inline_imports,
+                    collector,
+                ) {
+                    Ok(isl_type) => isl_type,
+                    Err(error) => {
+                        collector.recover(error)?;
+                        continue;
+                    }
+                };
+                let isl_type_name = match isl_type.name() {
+                    Some(isl_type_name) => isl_type_name.to_owned(),
+                    None => {
+                        // if a top level type definition doesn't contain `name` field return an error
+                        collector.recover(invalid_schema_error_raw(
+                            "Top level types must contain field `name` in their definition",
+                        ))?;
+                        continue;
+                    }
+                };
+
+                if !isl_type_names.insert(isl_type_name.to_owned()) {
+                    collector.warn(
+                        invalid_schema_error_raw(format!(
+                            "Type `{isl_type_name}` is defined more than once in the schema"
+                        ))
+                        .with_type_name(&isl_type_name),
                     );
                 }
 
                 if isl_version == IslVersion::V2_0 {
-                    isl_user_reserved_fields.validate_field_names_in_type(&isl_type)?;
+                    if let Err(error) =
+                        isl_user_reserved_fields.validate_field_names_in_type(&isl_type)
+                    {
+                        collector.recover(error.with_type_name(&isl_type_name))?;
+                    }
                 }
 
                 // top level named type definition can not contain `occurs` field as per ISL specification
                 if value.as_struct().unwrap().get("occurs").is_some() {
-                    return invalid_schema_error(
-                        "Top level types must not contain `occurs` field in their definition",
-                    );
+                    collector.recover(
+                        invalid_schema_error_raw(
+                            "Top level types must not contain `occurs` field in their definition",
+                        )
+                        .with_type_name(&isl_type_name)
+                        .with_constraint("occurs"),
+                    )?;
                 }
 
                 let constraints = isl_type
@@ -856,8 +1155,17 @@ impl Resolver {
             else if annotations.contains("schema_footer") {
                 found_footer = true;
                 if isl_version == IslVersion::V2_0 {
-                    let schema_footer = try_to!(value.as_struct());
-                    isl_user_reserved_fields.validate_field_names_in_footer(schema_footer)?;
+                    let footer_field_names = value
+                        .as_struct()
+                        .ok_or(invalid_schema_error_raw(
+                            "schema_footer must be a non-null struct",
+                        ))
+                        .and_then(|schema_footer| {
+                            isl_user_reserved_fields.validate_field_names_in_footer(schema_footer)
+                        });
+                    if let Err(error) = footer_field_names {
+                        collector.recover(error)?;
+                    }
                 }
             } else {
                 // open content
@@ -866,9 +1174,10 @@ impl Resolver {
                     && !value.is_null()
                     && is_isl_version_marker(value.as_text().unwrap())
                 {
-                    return invalid_schema_error(
+                    collector.recover(invalid_schema_error_raw(
                         "top level open content can not be an Ion Schema version marker",
-                    );
+                    ))?;
+                    continue;
                 }
 
                 if isl_version == IslVersion::V2_0
@@ -877,9 +1186,10 @@ impl Resolver {
                         .iter()
                         .any(|a| is_reserved_word(a.text().unwrap()))
                 {
-                    return invalid_schema_error(
+                    collector.recover(invalid_schema_error_raw(
                         "top level open content may not be annotated with any reserved keyword",
-                    );
+                    ))?;
+                    continue;
                 }
 
                 open_content.push(value);
@@ -888,9 +1198,12 @@ impl Resolver {
         }
 
         if found_footer ^ found_header {
-            return invalid_schema_error("For any schema while a header and footer are both optional, a footer is required if a header is present (and vice-versa).");
+            collector.recover(invalid_schema_error_raw("For any schema while a header and footer are both optional, a footer is required if a header is present (and vice-versa)."))?;
         }
 
+        self.load_limits
+            .check_imports(isl_imports.len() + isl_inline_imports.len())?;
+
         match isl_version {
             IslVersion::V1_0 => Ok(IslSchema::schema_v_1_0(
                 id,
@@ -911,6 +1224,9 @@ impl Resolver {
     }
 
     /// Converts given ISL representation into a [`Schema`] based on given ISL version
+    ///
+    /// If `load_isl_import` is `Some(isl_import)` then the given ISL representation is the imported schema,
+    /// which is resolved on its own and then the types of the import are added to given type_store.
     pub fn schema_from_isl_schema(
         &mut self,
         isl_version: IslVersion,
@@ -918,36 +1234,45 @@ impl Resolver {
         type_store: &mut TypeStore,
         load_isl_import: Option<&IslImport>,
     ) -> IonSchemaResult<Arc<Schema>> {
-        // This is used while resolving an import, it is initialized as `false` to indicate that
-        // the type to be imported is not yet added to the type_store.
-        // This will be changed to `true` as soon as the type to be imported is resolved and is added to the type_store
-        let mut added_imported_type_to_type_store = false;
-
-        if isl_version != isl.version() {
-            return invalid_schema_error(format!(
-                "Expected {isl_version} schema but found {}",
-                isl.version()
-            ));
+        let collector = &mut DiagnosticCollector::fail_fast();
+        match load_isl_import {
+            None => self.schema_from_isl_schema_with(isl_version, isl, type_store, collector),
+            Some(isl_import) => {
+                let schema = self.schema_from_isl_schema_with(
+                    isl_version,
+                    isl,
+                    &mut TypeStore::default(),
+                    collector,
+                )?;
+                type_store.add_shared_types(schema.type_store())?;
+                // an import adds at most one type, the aliased type, to the type_store of the importing schema
+                type_store.start_types_at(self.reserve_type_ids(1)?.start);
+                Resolver::add_imported_types(type_store, isl_import, &schema)?;
+                Ok(schema)
+            }
         }
+    }
 
+    // Resolves the imported types and the ISL types of given schema into type_store, starting at the TypeId that
+    // type_store was set to start at
+    fn resolve_types(
+        &self,
+        isl_version: IslVersion,
+        isl: &IslSchema,
+        imported_schemas: &[(IslImport, Arc<Schema>)],
+        type_store: &mut TypeStore,
+        collector: &mut DiagnosticCollector,
+    ) -> IonSchemaResult<()> {
         let isl_types = isl.types();
-
-        // Resolve all inline import types if there are any
-        // this will help resolve all inline imports before they are used as a reference to another type
-        for isl_inline_imported_type in isl.inline_imported_types() {
-            let import_id = isl_inline_imported_type.id();
-            let inline_imported_type = self.load_schema(
-                import_id,
-                type_store,
-                Some(&IslImport::Type(isl_inline_imported_type.to_owned())),
-            )?;
-        }
-
-        // Resolve all ISL imports
-        for isl_import in isl.imports() {
-            let import_id = isl_import.id();
-            let imported_schema = self.load_schema(import_id, type_store, Some(isl_import))?;
+        for (isl_import, imported_schema) in imported_schemas {
+            if let Err(error) =
+                Resolver::add_imported_types(type_store, isl_import, imported_schema)
+            {
+                collector.recover(error)?;
+            }
         }
+        // the aliased imported types are added to type_store, but they are not counted as types of this schema
+        let first_type_id = type_store.next_type_id();
 
         // get all isl type names that are defined within the schema
         // this will be used to resolve type references which might not have yet resolved while loading a type definition
@@ -970,75 +1295,276 @@ impl Resolver {
                     .any(|c| c.version != isl_version);
 
                 if has_other_isl_constraints {
-                    return invalid_schema_error(format!("ISL type: {isl_type_name} contains constraints from other ISL version. Only use {isl_version} constraints for this method."));
+                    collector.recover(invalid_schema_error_raw(format!("ISL type: {isl_type_name} contains constraints from other ISL version. Only use {isl_version} constraints for this method.")))?;
+                    continue;
                 }
 
-                // convert IslType to TypeDefinitionKind
-                let type_id: TypeId =
-                    TypeDefinitionImpl::parse_from_isl_type_and_update_pending_types(
-                        isl_version,
-                        &isl_type.type_definition,
-                        type_store,
-                        pending_types,
+                if type_store
+                    .get_imported_type_id_by_name(isl_type_name)
+                    .is_some()
+                {
+                    collector.recover(
+                        invalid_schema_error_raw(format!(
+                            "Type {isl_type_name} conflicts with an imported type of the same name"
+                        ))
+                        .with_type_name(isl_type_name),
                     )?;
+                    continue;
+                }
+
+                // convert IslType to TypeDefinitionKind
+                if let Err(error) = TypeDefinitionImpl::parse_from_isl_type_and_update_pending_types(
+                    isl_version,
+                    &isl_type.type_definition,
+                    type_store,
+                    pending_types,
+                ) {
+                    collector.recover(error)?;
+                    continue;
+                }
             }
 
             // add all types from pending types to type_store
-            added_imported_type_to_type_store =
-                pending_types.update_type_store(type_store, load_isl_import, &isl_type_names)?;
+            pending_types.set_schema_id(&isl.id());
+            if let Err(error) = pending_types.update_type_store(type_store, None, &isl_type_names) {
+                collector.recover(match isl_type.name() {
+                    Some(isl_type_name) => error.with_type_name(isl_type_name),
+                    None => error,
+                })?;
+            }
+
+            // the limit on the number of types is not recoverable, unlike the errors of the type definitions
+            if let Err(error) = self
+                .load_limits
+                .check_types(type_store.next_type_id() - first_type_id)
+            {
+                return Err(match isl_type.name() {
+                    Some(isl_type_name) => error.with_type_name(isl_type_name),
+                    None => error,
+                });
+            }
         }
+        Ok(())
+    }
 
-        // if currently loading an ISL import (i.e. load_isl_import != None)
-        // then check if the type to be imported is added to the type_store or not
-        if load_isl_import.is_some() && !added_imported_type_to_type_store {
-            unreachable!(
-                "Unable to load import: {} as the type/types were not added to the type_store correctly",
-                isl.id()
-            );
+    // Adds the types imported by given import to the type_store of the importing schema.
+    // The type_store must already contain the shared types of the imported schema, so that the imported types
+    // are referred to by their TypeIds instead of being copied, except for the aliased types which are renamed.
+    fn add_imported_types(
+        type_store: &mut TypeStore,
+        isl_import: &IslImport,
+        imported_schema: &Schema,
+    ) -> IonSchemaResult<()> {
+        let imported_type_store = imported_schema.type_store();
+        match isl_import {
+            IslImport::Schema(_) => {
+                for (name, type_id) in &imported_type_store.ids_by_name {
+                    type_store
+                        .imported_type_ids_by_name
+                        .entry(name.to_owned())
+                        .or_insert(*type_id);
+                }
+            }
+            IslImport::Type(isl_import_type) | IslImport::TypeAlias(isl_import_type) => {
+                let type_name = isl_import_type.type_name();
+                let local_name = isl_import_type.alias().as_ref().unwrap_or(type_name);
+                if type_store
+                    .imported_type_ids_by_name
+                    .contains_key(local_name)
+                {
+                    // the type was already imported, e.g. by an inline import
+                    return Ok(());
+                }
+                let type_id = *imported_type_store
+                    .get_type_id_by_name(type_name)
+                    .ok_or_else(|| {
+                        unresolvable_schema_error_raw(format!(
+                            "Unable to import type {type_name} as it is not defined in schema {}",
+                            imported_schema.id()
+                        ))
+                    })?;
+                match (
+                    isl_import_type.alias(),
+                    imported_type_store.get_type_by_id(type_id),
+                ) {
+                    (Some(alias), Some(TypeDefinitionKind::Named(type_def))) => {
+                        type_store.add_isl_imported_type(
+                            Some(alias),
+                            type_def.to_owned().with_name(alias.to_owned()),
+                        );
+                    }
+                    _ => {
+                        type_store
+                            .imported_type_ids_by_name
+                            .insert(type_name.to_owned(), type_id);
+                    }
+                }
+            }
         }
+        Ok(())
+    }
 
-        let schema = Arc::new(Schema::new(isl.id(), Arc::new(type_store.clone())));
+    // Converts given ISL representation into a [`Schema`], the errors in imports and type definitions are reported to
+    // the given collector which decides whether the remaining imports and types are resolved.
+    fn schema_from_isl_schema_with(
+        &mut self,
+        isl_version: IslVersion,
+        isl: IslSchema,
+        type_store: &mut TypeStore,
+        collector: &mut DiagnosticCollector,
+    ) -> IonSchemaResult<Arc<Schema>> {
+        if isl_version != isl.version() {
+            return invalid_schema_error(format!(
+                "Expected {isl_version} schema but found {}",
+                isl.version()
+            ));
+        }
 
-        // add schema to schema cache
-        // if we are loading an import of the schema then we can only add this schema to cache if its a full schema import
-        // and can not add it to cache if we are loading specific type imports from the schema.
-        match load_isl_import {
-            None => {
-                self.resolved_schema_cache
-                    .insert(isl.id(), Arc::clone(&schema));
-            }
-            Some(isl_import) if matches!(isl_import, IslImport::Schema(_)) => {
-                self.resolved_schema_cache
-                    .insert(isl.id(), Arc::clone(&schema));
+        let isl_types = isl.types();
+
+        // Resolve all the imported schemas, starting with the inline import types if there are any
+        // this will help resolve all inline imports before they are used as a reference to another type
+        let isl_imports = isl
+            .inline_imported_types()
+            .iter()
+            .map(|isl_inline_imported_type| IslImport::Type(isl_inline_imported_type.to_owned()))
+            .chain(isl.imports().iter().cloned());
+        let mut imported_schemas = vec![];
+        for isl_import in isl_imports {
+            match self.load_schema(isl_import.id(), collector) {
+                Ok(imported_schema) => imported_schemas.push((isl_import, imported_schema)),
+                Err(error) => collector.recover(error)?,
             }
-            _ => {
-                // No op for type imports
+        }
+
+        // Add all the imported types, the types of the imported schemas are shared rather than copied into type_store
+        for (_, imported_schema) in &imported_schemas {
+            type_store.add_shared_types(imported_schema.type_store())?;
+        }
+        // the types are resolved in a range of TypeIds reserved for this schema, so that schemas are resolved concurrently
+        // without their types overlapping. The number of types is only known once they are resolved, so the types are
+        // resolved again in a range of the right size if they didn't fit in the reserved range
+        let diagnostics_start = collector.len();
+        let mut type_ids = self.reserve_type_ids(
+            (isl_types.len() + imported_schemas.len()) * TYPE_IDS_RESERVED_PER_DEFINITION,
+        )?;
+        *type_store = loop {
+            let mut resolved_type_store = type_store.clone();
+            resolved_type_store.start_types_at(type_ids.start);
+            self.resolve_types(
+                isl_version,
+                &isl,
+                &imported_schemas,
+                &mut resolved_type_store,
+                collector,
+            )?;
+            if resolved_type_store.next_type_id() <= type_ids.end {
+                break resolved_type_store;
             }
+            collector.discard_since(diagnostics_start);
+            type_ids =
+                self.reserve_type_ids(resolved_type_store.next_type_id() - type_ids.start)?;
+        };
+
+        // the types of this schema are frozen so that they can be shared with the schemas that import it
+        type_store.freeze();
+
+        self.imported_schema_ids.insert(
+            isl.id(),
+            isl.imports()
+                .iter()
+                .map(|isl_import| isl_import.id().to_owned())
+                .chain(
+                    isl.inline_imported_types()
+                        .iter()
+                        .map(|isl_import_type| isl_import_type.id().to_owned()),
+                )
+                .collect(),
+        );
+        let schema = Arc::new(
+            Schema::new(isl.id(), Arc::new(type_store.clone()))
+                .with_isl_imports(isl.imports().to_vec()),
+        );
+
+        // add schema to schema cache, which also shares its types with the other schemas that import it
+        // A schema is never cached while collecting diagnostics as it might be missing the types that have errors.
+        if !collector.is_collecting() {
+            self.resolved_schema_cache
+                .insert(isl.id(), Arc::clone(&schema));
         }
 
         Ok(schema)
     }
 
-    /// Loads a [`Schema`] with resolved [`Type`]s using authorities and type_store
-    // If we are loading the root schema then this will be set to `None` ( i.e. in the beginning when
-    // this method is called from the load_schema method of schema_system it is set to `None`)
-    // Otherwise if we are loading an import of the schema then this will be set to `Some(isl_import)`
-    // to be loaded (i.e. Inside schema_from_elements while loading imports this will be set to
-    // `Some(isl_import)`)
+    /// Loads a [`Schema`] with resolved [`Type`]s using authorities, or returns it from the schema cache.
+    /// This is used for the root schema as well as for the imported schemas, whose types are shared with the
+    /// importing schemas.
+    // The diagnostics collected while loading the schema are updated with its location, the cache is bypassed
+    // while collecting diagnostics so that every schema is checked.
     fn load_schema<A: AsRef<str>>(
         &mut self,
         id: A,
-        type_store: &mut TypeStore,
-        load_isl_import: Option<&IslImport>,
+        collector: &mut DiagnosticCollector,
     ) -> IonSchemaResult<Arc<Schema>> {
         let id: &str = id.as_ref();
 
         if let Some(schema) = self.resolved_schema_cache.get(id) {
-            return Ok(Arc::clone(schema));
+            if !collector.is_collecting() {
+                return Ok(Arc::clone(schema));
+            }
+        }
+
+        // a schema that is still being loaded can't be imported, as its types are not resolved yet
+        if self
+            .loading_schema_ids
+            .iter()
+            .any(|loading_id| loading_id == id)
+        {
+            // the chain of imports starts from the root schema that is being loaded
+            let import_chain: Vec<&str> = self
+                .loading_schema_ids
+                .iter()
+                .map(|loading_id| loading_id.as_str())
+                .chain([id])
+                .collect();
+            return unresolvable_schema_error(format!(
+                "Unable to load schema {id} due to an import cycle: {}",
+                import_chain.join(" -> ")
+            ));
         }
 
-        for authority in &self.authorities {
-            return match authority.elements(id) {
+        self.loading_schema_ids.push(id.to_owned());
+        let schema = match self.schema_slots.as_ref().map(Arc::clone) {
+            // the root schema and its imports are loaded once by a [`SharedSchemaSystem`], through their slots
+            Some(schema_slots) => schema_slots.load(id, || {
+                let schema = self.load_schema_from_authorities(id, collector)?;
+                // the imports and the fingerprint of the schema are kept along with its slot, as this resolver is
+                // dropped after the load
+                Ok(LoadedSchema {
+                    schema,
+                    imported_ids: self.imported_schema_ids.remove(id).unwrap_or_default(),
+                    fingerprint: self.fingerprints.remove(id),
+                })
+            }),
+            None => self.load_schema_from_authorities(id, collector),
+        };
+        self.loading_schema_ids.pop();
+        schema
+    }
+
+    // Loads a [`Schema`] using the first authority that resolves given id
+    fn load_schema_from_authorities(
+        &mut self,
+        id: &str,
+        collector: &mut DiagnosticCollector,
+    ) -> IonSchemaResult<Arc<Schema>> {
+        let diagnostics_start = collector.len();
+        for authority in self.authorities.iter().map(Arc::clone) {
+            // the fingerprint is only requested when change detection is enabled, along with the elements so that the
+            // authority can take it while reading the schema
+            let options = self.read_options().with_fingerprint(self.change_detection);
+            let elements = authority.read_elements(id, options);
+            return match elements {
                 Err(error) => match error {
                     IonSchemaError::IoError { source } => match source.kind() {
                         ErrorKind::NotFound => continue,
@@ -1046,13 +1572,36 @@ impl Resolver {
                     },
                     _ => Err(error),
                 },
-                Ok(schema_content) => {
-                    let isl = self.isl_schema_from_elements(schema_content.into_iter(), id)?;
-                    self.schema_from_isl_schema(isl.version(), isl, type_store, load_isl_import)
+                Ok(schema_elements) => {
+                    if let Some(fingerprint) = schema_elements.fingerprint() {
+                        self.fingerprints.insert(id.to_owned(), fingerprint);
+                    }
+                    let (elements, source_text) = schema_elements.into_elements_and_source_text();
+                    // the source text is only requested from the authority when it wasn't read along with the elements
+                    // and an error needs its location
+                    let source_text = || source_text.clone().or_else(|| authority.source_text(id));
+                    let schema = self
+                        .isl_schema_from_elements_with(elements.into_iter(), id, collector)
+                        .and_then(|isl| {
+                            self.schema_from_isl_schema_with(
+                                isl.version(),
+                                isl,
+                                &mut TypeStore::default(),
+                                collector,
+                            )
+                        });
+                    if collector.len() > diagnostics_start {
+                        let source_text = source_text();
+                        collector.map_errors_since(diagnostics_start, |error| {
+                            with_schema_location(error, id, source_text.as_deref())
+                        });
+                    }
+                    schema
+                        .map_err(|error| with_schema_location(error, id, source_text().as_deref()))
                 }
             };
         }
-        unresolvable_schema_error("Unable to load schema: ".to_owned() + id)
+        schema_not_found_error(id)
     }
 
     /// Loads an [`IslSchema`] using authorities and type_store based on ISL version.
@@ -1068,16 +1617,62 @@ impl Resolver {
     ) -> IonSchemaResult<IslSchema> {
         let id: &str = id.as_ref();
 
-        for authority in &self.authorities {
-            return match authority.elements(id) {
-                Ok(schema_content) => self.isl_schema_from_elements(schema_content.into_iter(), id),
+        for authority in self.authorities.iter().map(Arc::clone) {
+            return match authority.read_elements(id, self.read_options()) {
+                Ok(schema_elements) => self
+                    .isl_schema_from_elements(schema_elements.into_elements().into_iter(), id)
+                    .map_err(|error| {
+                        with_schema_location(error, id, authority.source_text(id).as_deref())
+                    }),
                 Err(IonSchemaError::IoError { source: e }) if e.kind() == ErrorKind::NotFound => {
                     continue
                 }
                 Err(error) => Err(error),
             };
         }
-        unresolvable_schema_error("Unable to load ISL model: ".to_owned() + id)
+        schema_not_found_error(id)
+    }
+
+    // Returns the options to read schemas with, which enforce the limits on the size and the nesting depth of schemas
+    // while they are read
+    fn read_options(&self) -> ReadOptions {
+        let mut options = ReadOptions::default();
+        if let Some(max_size) = self.load_limits.max_schema_size() {
+            options = options.with_max_size(max_size);
+        }
+        if let Some(max_nesting_depth) = self.load_limits.max_nesting_depth() {
+            options = options.with_max_nesting_depth(max_nesting_depth);
+        }
+        options
+    }
+
+    // Parses the elements of given schema content like an authority would, enforcing the limits on the size and the
+    // nesting depth of the schema
+    fn read_schema_content(
+        &self,
+        schema_content: &[u8],
+        id: &str,
+    ) -> IonSchemaResult<Vec<Element>> {
+        Ok(read_ion_content(id, schema_content, self.read_options())?.into_elements())
+    }
+
+    // Reserves a range of given number of TypeIds for the types of a schema
+    fn reserve_type_ids(&self, count: usize) -> IonSchemaResult<Range<TypeId>> {
+        self.next_type_id
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |next_type_id| {
+                next_type_id.checked_add(count)
+            })
+            .map(|first_type_id| first_type_id..first_type_id + count)
+            .or_else(|_| {
+                unresolvable_schema_error("Unable to reserve TypeIds for the types of the schema")
+            })
+    }
+
+    // This is a helper method that returns the fingerprint of the schema with given id from the first authority that provides it
+    fn fingerprint(&self, id: &str) -> Option<u64> {
+        self.authorities
+            .iter()
+            .find_map(|authority| authority.fingerprint(id))
     }
 
     // This is a helper method that returns the ISL version for given schema content
@@ -1116,43 +1711,368 @@ impl Resolver {
     }
 }
 
-/// Provides functions for instantiating instances of [`Schema`].
-///
-/// [`SchemaSystem`] is *[Send and Sync]* i.e. it is safe to send it to another thread and to be shared between threads.
-/// For cases when one does need thread-safe interior mutability, they can use the explicit locking via [`std::sync::Mutex`] and [`std::sync::RwLock`].
-///
-/// [Send and Sync]: https://doc.rust-lang.org/nomicon/send-and-sync.html
-pub struct SchemaSystem {
-    resolver: Resolver,
+/// Sets the given schema id as the location of an error that occurred while loading that schema.
+/// When the Ion text of the schema is available, it also sets the line and column of the type and constraint
+/// where the error occurred. Errors that occurred in an imported schema keep the location within the imported schema.
+fn with_schema_location(
+    error: IonSchemaError,
+    id: &str,
+    source_text: Option<&str>,
+) -> IonSchemaError {
+    let error = error.with_schema_id(id);
+    let line_column = match (error.location(), source_text) {
+        (Some(location), Some(text)) if location.schema_id() == Some(id) => {
+            find_line_column(text, location.type_name(), location.constraint())
+        }
+        _ => None,
+    };
+    match line_column {
+        Some(line_column) => error.with_line_column(line_column),
+        None => error,
+    }
 }
 
-// TODO: make methods public based on the requirements
-impl SchemaSystem {
-    pub fn new(authorities: Vec<Box<dyn DocumentAuthority>>) -> Self {
-        Self {
-            resolver: Resolver::new(authorities),
+/// Finds the 1-based line and column of the given type definition and constraint in the Ion text of a schema.
+///
+/// The Ion text reader doesn't provide the positions of the values that it reads, hence the text is tokenized to find
+/// the top level `type::` struct whose `name` field is the given type name, skipping comments, strings and the
+/// nested values. The position of the constraint is only used when the constraint is a field of that struct or of
+/// its nested structs exactly once, otherwise it can't be told apart from other fields with the same name and the
+/// position of the type definition is returned. Nothing is returned without a type name, as the position of the
+/// error is unknown.
+fn find_line_column(
+    text: &str,
+    type_name: Option<&str>,
+    constraint: Option<&str>,
+) -> Option<(usize, usize)> {
+    let type_name = type_name?;
+    let mut tokens = IonTokenizer::new(text).peekable();
+    let mut type_annotation_offset = None;
+    while let Some((offset, token)) = tokens.next() {
+        match token {
+            IonToken::Text(annotation)
+                if tokens.peek().map(|(_, token)| token) == Some(&IonToken::DoubleColon) =>
+            {
+                tokens.next();
+                if annotation == "type" {
+                    type_annotation_offset.get_or_insert(offset);
+                }
+                continue;
+            }
+            IonToken::Open('{') if type_annotation_offset.is_some() => {
+                let (name, constraint_offsets) = scan_type_definition(&mut tokens, constraint);
+                if name == Some(type_name) {
+                    let offset = match constraint_offsets.as_slice() {
+                        [constraint_offset] => *constraint_offset,
+                        _ => type_annotation_offset?,
+                    };
+                    return Some(line_column(text, offset));
+                }
+            }
+            IonToken::Open(_) => skip_container(&mut tokens),
+            _ => {}
         }
+        type_annotation_offset = None;
     }
+    None
+}
 
-    /// Requests each of the provided [`DocumentAuthority`]s, in order, to resolve the requested schema id
-    /// until one successfully resolves it.
-    /// If an authority throws an exception, resolution silently proceeds to the next authority.
-    /// This method returns an `Arc<Schema>` which allows to load this schema once re-use it across threads.
+// Scans the fields of a type definition struct whose opening brace was just read, up to its closing brace.
+// Returns the value of its `name` field and the offsets of the fields named like the given constraint, including the
+// fields of its nested structs.
+fn scan_type_definition<'a>(
+    tokens: &mut Peekable<IonTokenizer<'a>>,
+    constraint: Option<&str>,
+) -> (Option<&'a str>, Vec<usize>) {
+    let mut name = None;
+    let mut constraint_offsets = vec![];
+    // the nested containers, where `true` is a struct
+    let mut containers = vec![true];
+    while let Some((offset, token)) = tokens.next() {
+        match token {
+            IonToken::Text(field_name)
+                if containers.last() == Some(&true)
+                    && tokens.peek().map(|(_, token)| token) == Some(&IonToken::Colon) =>
+            {
+                tokens.next();
+                if Some(field_name) == constraint {
+                    constraint_offsets.push(offset);
+                }
+                if field_name == "name" && containers.len() == 1 {
+                    if let Some((_, IonToken::Text(value))) = tokens.peek() {
+                        name.get_or_insert(*value);
+                    }
+                }
+            }
+            IonToken::Open(delimiter) => containers.push(delimiter == '{'),
+            IonToken::Close => {
+                containers.pop();
+                if containers.is_empty() {
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    (name, constraint_offsets)
+}
+
+// Skips the values of a container whose opening delimiter was just read, up to its closing delimiter
+fn skip_container(tokens: &mut Peekable<IonTokenizer>) {
+    let mut depth = 1;
+    while depth > 0 {
+        match tokens.next() {
+            Some((_, IonToken::Open(_))) => depth += 1,
+            Some((_, IonToken::Close)) => depth -= 1,
+            Some(_) => {}
+            None => break,
+        }
+    }
+}
+
+// Returns the 1-based line and column of the given byte offset in the text
+fn line_column(text: &str, offset: usize) -> (usize, usize) {
+    let preceding_text = &text[..offset];
+    let line = preceding_text.matches('\n').count() + 1;
+    let column = preceding_text
+        .rsplit('\n')
+        .next()
+        .map_or(0, |line_text| line_text.chars().count())
+        + 1;
+    (line, column)
+}
+
+// The tokens of Ion text that are needed to find a type definition, see `find_line_column`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IonToken<'a> {
+    // an identifier, a quoted symbol or a short string, with its text as written (i.e. without unescaping it)
+    Text(&'a str),
+    // the opening delimiter of a struct, a list or an s-expression
+    Open(char),
+    Close,
+    Colon,
+    DoubleColon,
+    // any other value or punctuation, e.g. a number, a long string, a blob or an operator
+    Other,
+}
+
+// Splits Ion text into tokens along with their byte offsets, skipping whitespace and comments.
+// All the delimiters are ASCII characters, hence the offsets are always on a character boundary.
+struct IonTokenizer<'a> {
+    text: &'a str,
+    offset: usize,
+}
+
+impl<'a> IonTokenizer<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { text, offset: 0 }
+    }
+
+    fn starts_with(&self, prefix: &str) -> bool {
+        self.text.as_bytes()[self.offset..].starts_with(prefix.as_bytes())
+    }
+
+    // Moves the offset past the next occurrence of given terminator, or to the end of the text if there is none.
+    // When `escapes` is set, the characters that follow a backslash are skipped.
+    fn skip_past(&mut self, terminator: &str, escapes: bool) {
+        let bytes = self.text.as_bytes();
+        while self.offset < bytes.len() {
+            if escapes && bytes[self.offset] == b'\\' {
+                self.offset += 2;
+            } else if self.starts_with(terminator) {
+                self.offset += terminator.len();
+                return;
+            } else {
+                self.offset += 1;
+            }
+        }
+        self.offset = bytes.len();
+    }
+
+    // Moves the offset past the quoted text that starts at the offset, and returns its content
+    fn quoted_text(&mut self, quote: &str) -> &'a str {
+        self.offset += quote.len();
+        let start = self.offset;
+        self.skip_past(quote, true);
+        let end = if self.text[start..self.offset].ends_with(quote) {
+            self.offset - quote.len()
+        } else {
+            self.offset
+        };
+        &self.text[start..end]
+    }
+}
+
+impl<'a> Iterator for IonTokenizer<'a> {
+    type Item = (usize, IonToken<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.text.as_bytes();
+        loop {
+            let byte = *bytes.get(self.offset)?;
+            if byte.is_ascii_whitespace() {
+                self.offset += 1;
+            } else if self.starts_with("//") {
+                self.skip_past("\n", false);
+            } else if self.starts_with("/*") {
+                self.skip_past("*/", false);
+            } else {
+                break;
+            }
+        }
+        let offset = self.offset;
+        let token = match bytes[offset] {
+            _ if self.starts_with("'''") => {
+                self.quoted_text("'''");
+                IonToken::Other
+            }
+            b'\'' => IonToken::Text(self.quoted_text("'")),
+            b'"' => IonToken::Text(self.quoted_text("\"")),
+            _ if self.starts_with("{{") => {
+                // the strings of a clob may contain `}}`
+                self.offset += 2;
+                while self.offset < bytes.len() && !self.starts_with("}}") {
+                    if self.starts_with("'''") {
+                        self.quoted_text("'''");
+                    } else if bytes[self.offset] == b'"' {
+                        self.quoted_text("\"");
+                    } else {
+                        self.offset += 1;
+                    }
+                }
+                self.offset = (self.offset + 2).min(bytes.len());
+                IonToken::Other
+            }
+            delimiter @ (b'{' | b'[' | b'(') => {
+                self.offset += 1;
+                IonToken::Open(delimiter as char)
+            }
+            b'}' | b']' | b')' => {
+                self.offset += 1;
+                IonToken::Close
+            }
+            _ if self.starts_with("::") => {
+                self.offset += 2;
+                IonToken::DoubleColon
+            }
+            b':' => {
+                self.offset += 1;
+                IonToken::Colon
+            }
+            byte if byte.is_ascii_alphabetic() || byte == b'_' || byte == b'$' => {
+                self.offset += 1;
+                while bytes
+                    .get(self.offset)
+                    .is_some_and(|byte| byte.is_ascii_alphanumeric() || b"_$".contains(byte))
+                {
+                    self.offset += 1;
+                }
+                IonToken::Text(&self.text[offset..self.offset])
+            }
+            byte => {
+                // numbers and timestamps may contain `:`, unlike the operators of s-expressions
+                let is_number = byte.is_ascii_digit() || byte == b'-' || byte == b'+';
+                self.offset += 1;
+                while bytes.get(self.offset).is_some_and(|byte| {
+                    !(byte.is_ascii_whitespace()
+                        || b"{}[](),\"'".contains(byte)
+                        || (*byte == b':' && !is_number))
+                }) {
+                    self.offset += 1;
+                }
+                IonToken::Other
+            }
+        };
+        Some((offset, token))
+    }
+}
+
+/// Provides functions for instantiating instances of [`Schema`].
+///
+/// [`SchemaSystem`] is *[Send and Sync]* i.e. it is safe to send it to another thread and to be shared between threads.
+/// For cases when one does need thread-safe interior mutability, they can use the explicit locking via [`std::sync::Mutex`] and [`std::sync::RwLock`].
+///
+/// [Send and Sync]: https://doc.rust-lang.org/nomicon/send-and-sync.html
+pub struct SchemaSystem {
+    resolver: Resolver,
+}
+
+impl SchemaSystem {
+    pub fn new(authorities: Vec<Box<dyn DocumentAuthority>>) -> Self {
+        Self {
+            resolver: Resolver::new(authorities),
+        }
+    }
+
+    /// Requests each of the provided [`DocumentAuthority`]s, in order, to resolve the requested schema id
+    /// until one successfully resolves it.
+    /// If an authority throws an exception, resolution silently proceeds to the next authority.
+    /// This method returns an `Arc<Schema>` which allows to load this schema once re-use it across threads.
+    ///
+    /// Schemas that import each other, directly or transitively, can not be loaded. The returned
+    /// [`IonSchemaError::UnresolvableSchemaError`] lists the import cycle, e.g. `a.isl -> b.isl -> a.isl`.
     // TODO: Add support for Rc<Schema> by providing a trait implementation of schema and schema cache. This should
     //  allow users to choose what variant of schema they want.
     pub fn load_schema<A: AsRef<str>>(&mut self, id: A) -> IonSchemaResult<Arc<Schema>> {
         self.resolver
-            .load_schema(id, &mut TypeStore::default(), None)
+            .load_schema(id, &mut DiagnosticCollector::fail_fast())
+    }
+
+    /// Loads the schema with given id like [`SchemaSystem::load_schema`], but instead of returning the first error
+    /// it keeps going after recoverable errors in the header, imports, types and constraints of the schema and its
+    /// imports, and returns all the problems that were found. Warnings point out definitions that are valid but most
+    /// likely mistakes, e.g. a misspelled constraint in an ISL 1.0 type which is treated as open content.
+    ///
+    /// An empty result means that the schema can be loaded with [`SchemaSystem::load_schema`].
+    /// The schemas that are checked are not cached by this method.
+    ///
+    /// ```
+    /// use ion_schema::authority::MapDocumentAuthority;
+    /// use ion_schema::result::DiagnosticSeverity;
+    /// use ion_schema::system::SchemaSystem;
+    ///
+    /// let authority = MapDocumentAuthority::new([(
+    ///     "sample.isl",
+    ///     r#"
+    ///         type::{ name: short_string, type: string, codepoint_lenght: range::[0, 10] }
+    ///         type::{ name: small_int, type: int, valid_values: range::[min, "10"] }
+    ///         type::{ name: positive_int, type: int, valid_values: range::[1, max], occurs: optional }
+    ///     "#,
+    /// )]);
+    /// let mut schema_system = SchemaSystem::new(vec![Box::new(authority)]);
+    ///
+    /// let diagnostics = schema_system.check_schema("sample.isl");
+    /// let severities: Vec<_> = diagnostics.iter().map(|d| d.severity()).collect();
+    /// assert_eq!(
+    ///     severities,
+    ///     vec![DiagnosticSeverity::Warning, DiagnosticSeverity::Error, DiagnosticSeverity::Error]
+    /// );
+    /// ```
+    pub fn check_schema<A: AsRef<str>>(&mut self, id: A) -> Vec<SchemaDiagnostic> {
+        let mut collector = DiagnosticCollector::collecting();
+        if let Err(error) = self.resolver.load_schema(id, &mut collector) {
+            // the collector doesn't return errors while collecting diagnostics
+            let _ = collector.recover(error);
+        }
+        collector.into_diagnostics()
     }
 
     /// Constructs a new schema using provided ISL content.
     pub fn new_schema(&mut self, schema_content: &[u8], id: &str) -> IonSchemaResult<Arc<Schema>> {
-        let elements = Element::read_all(schema_content)?;
-        let isl = self
-            .resolver
-            .isl_schema_from_elements(elements.into_iter(), id)?;
+        let elements = self.resolver.read_schema_content(schema_content, id)?;
         self.resolver
-            .schema_from_isl_schema(isl.version(), isl, &mut TypeStore::default(), None)
+            .isl_schema_from_elements(elements.into_iter(), id)
+            .and_then(|isl| {
+                self.resolver.schema_from_isl_schema(
+                    isl.version(),
+                    isl,
+                    &mut TypeStore::default(),
+                    None,
+                )
+            })
+            .map_err(|error| {
+                with_schema_location(error, id, std::str::from_utf8(schema_content).ok())
+            })
     }
 
     /// Requests each of the provided [`DocumentAuthority`]s, in order, to get ISL model for the
@@ -1162,15 +2082,60 @@ impl SchemaSystem {
         self.resolver.load_isl_schema(id, None)
     }
 
+    /// Returns the transitive import graph of the schema with given id, i.e. the schemas that it imports directly
+    /// or transitively along with the types that are imported from each of them.
+    /// Only the ISL models of the schemas are loaded, hence the graph can contain import cycles.
+    /// See [`DependencyGraph`] for an example.
+    pub fn dependency_graph<A: AsRef<str>>(&mut self, id: A) -> IonSchemaResult<DependencyGraph> {
+        let mut schema_ids = vec![id.as_ref().to_owned()];
+        let mut dependencies: Vec<Dependency> = vec![];
+        let mut index = 0;
+        while index < schema_ids.len() {
+            let schema_id = schema_ids[index].to_owned();
+            let isl = self.resolver.load_isl_schema(&schema_id, None)?;
+            let inline_imports = isl.inline_imported_types().iter().map(|isl_import_type| {
+                match isl_import_type.alias() {
+                    Some(_) => IslImport::TypeAlias(isl_import_type.to_owned()),
+                    None => IslImport::Type(isl_import_type.to_owned()),
+                }
+            });
+            for isl_import in isl.imports().iter().cloned().chain(inline_imports) {
+                let imported_schema_id = isl_import.id();
+                if !schema_ids.contains(imported_schema_id) {
+                    schema_ids.push(imported_schema_id.to_owned());
+                }
+                let dependency_index = match dependencies.iter().position(|dependency| {
+                    dependency.schema_id() == schema_id
+                        && dependency.imported_schema_id() == imported_schema_id
+                }) {
+                    Some(dependency_index) => dependency_index,
+                    None => {
+                        dependencies.push(Dependency::new(
+                            schema_id.to_owned(),
+                            imported_schema_id.to_owned(),
+                        ));
+                        dependencies.len() - 1
+                    }
+                };
+                dependencies[dependency_index].add_import(isl_import);
+            }
+            index += 1;
+        }
+        Ok(DependencyGraph::new(schema_ids, dependencies))
+    }
+
     /// Constructs a new ISL model using provided ISL content.
     pub fn new_isl_schema(
         &mut self,
         schema_content: &[u8],
         id: &str,
     ) -> IonSchemaResult<IslSchema> {
-        let elements = Element::read_all(schema_content)?;
+        let elements = self.resolver.read_schema_content(schema_content, id)?;
         self.resolver
             .isl_schema_from_elements(elements.into_iter(), id)
+            .map_err(|error| {
+                with_schema_location(error, id, std::str::from_utf8(schema_content).ok())
+            })
     }
 
     /// Resolves given ISL 1.0 model into a [Schema].
@@ -1182,8 +2147,10 @@ impl SchemaSystem {
         &mut self,
         isl: IslSchema,
     ) -> IonSchemaResult<Arc<Schema>> {
+        let id = isl.id();
         self.resolver
             .schema_from_isl_schema(IslVersion::V1_0, isl, &mut TypeStore::default(), None)
+            .map_err(|error| with_schema_location(error, &id, None))
     }
 
     /// Resolves given ISL 2.0 model into a [Schema].
@@ -1195,30 +2162,225 @@ impl SchemaSystem {
         &mut self,
         isl: IslSchema,
     ) -> IonSchemaResult<Arc<Schema>> {
+        let id = isl.id();
         self.resolver
             .schema_from_isl_schema(IslVersion::V2_0, isl, &mut TypeStore::default(), None)
+            .map_err(|error| with_schema_location(error, &id, None))
+    }
+
+    /// Returns the type with given name from the schema with given id, loading the schema if needed.
+    /// The returned type definitions are cached until the schema is invalidated.
+    ///
+    /// The type is looked up like [`Schema::get_type`], but this tells apart a schema that can't be found
+    /// or loaded from a type that the schema doesn't define.
+    pub fn type_definition<A: AsRef<str>, B: AsRef<str>>(
+        &mut self,
+        schema_id: A,
+        type_name: B,
+    ) -> Result<TypeDefinition, ValidationError> {
+        let (schema_id, type_name) = (schema_id.as_ref(), type_name.as_ref());
+        if let Some(type_def) = self
+            .resolver
+            .type_definition_cache
+            .get(schema_id)
+            .and_then(|type_defs| type_defs.get(type_name))
+        {
+            return Ok(type_def.to_owned());
+        }
+
+        let schema = self.load_schema(schema_id).map_err(|error| match error {
+            // an import of the schema that can't be found fails to load the schema,
+            // hence only the schema itself not being found is reported as such
+            IonSchemaError::SchemaNotFoundError {
+                schema_id: missing_id,
+                ..
+            } if missing_id == schema_id => ValidationError::SchemaNotFound {
+                schema_id: schema_id.to_owned(),
+            },
+            error => ValidationError::SchemaError { source: error },
+        })?;
+        let type_def = schema
+            .get_type(type_name)
+            .ok_or_else(|| ValidationError::TypeNotFound {
+                schema_id: schema_id.to_owned(),
+                type_name: type_name.to_owned(),
+            })?;
+        self.resolver
+            .type_definition_cache
+            .entry(schema_id.to_owned())
+            .or_default()
+            .insert(type_name.to_owned(), type_def.to_owned());
+        Ok(type_def)
+    }
+
+    /// Validates given value against the type with given name from the schema with given id,
+    /// see [`SchemaSystem::type_definition`] for more details on how the type is looked up.
+    /// ```
+    /// use ion_rs::element::Element;
+    /// use ion_schema::authority::MapDocumentAuthority;
+    /// use ion_schema::result::ValidationError;
+    /// use ion_schema::system::SchemaSystem;
+    ///
+    /// let authority = MapDocumentAuthority::new([("sample.isl", "type::{ name: my_int, type: int }")]);
+    /// let mut schema_system = SchemaSystem::new(vec![Box::new(authority)]);
+    ///
+    /// assert!(schema_system.validate("sample.isl", "my_int", &Element::from(5)).is_ok());
+    /// assert!(schema_system.validate_qualified("sample.isl#my_int", &Element::from(5)).is_ok());
+    ///
+    /// let error = schema_system.validate("sample.isl", "my_int", &Element::from("five")).unwrap_err();
+    /// assert!(matches!(error, ValidationError::Violation(_)));
+    ///
+    /// let error = schema_system.validate("sample.isl", "my_itn", &Element::from(5)).unwrap_err();
+    /// assert!(matches!(error, ValidationError::TypeNotFound { .. }));
+    ///
+    /// let error = schema_system.validate("simple.isl", "my_int", &Element::from(5)).unwrap_err();
+    /// assert!(matches!(error, ValidationError::SchemaNotFound { .. }));
+    /// ```
+    pub fn validate<A: AsRef<str>, B: AsRef<str>, I: Into<IonSchemaElement>>(
+        &mut self,
+        schema_id: A,
+        type_name: B,
+        value: I,
+    ) -> Result<(), ValidationError> {
+        self.validate_with(schema_id, type_name, value, ValidationOptions::default())
+    }
+
+    /// Validates given value against the type with given name from the schema with given id like
+    /// [`SchemaSystem::validate`], using the given [`ValidationOptions`].
+    pub fn validate_with<A: AsRef<str>, B: AsRef<str>, I: Into<IonSchemaElement>>(
+        &mut self,
+        schema_id: A,
+        type_name: B,
+        value: I,
+        options: ValidationOptions,
+    ) -> Result<(), ValidationError> {
+        let type_def = self.type_definition(schema_id, type_name)?;
+        Ok(type_def.validate_with(value, options)?)
+    }
+
+    /// Validates given value against the type with given fully qualified name of the form `schema_id#type_name`,
+    /// like [`SchemaSystem::validate`]. The schema id ends at the first `#` of the name.
+    pub fn validate_qualified<A: AsRef<str>, I: Into<IonSchemaElement>>(
+        &mut self,
+        qualified_type_name: A,
+        value: I,
+    ) -> Result<(), ValidationError> {
+        self.validate_qualified_with(qualified_type_name, value, ValidationOptions::default())
+    }
+
+    /// Validates given value against the type with given fully qualified name like
+    /// [`SchemaSystem::validate_qualified`], using the given [`ValidationOptions`].
+    pub fn validate_qualified_with<A: AsRef<str>, I: Into<IonSchemaElement>>(
+        &mut self,
+        qualified_type_name: A,
+        value: I,
+        options: ValidationOptions,
+    ) -> Result<(), ValidationError> {
+        let qualified_type_name = qualified_type_name.as_ref();
+        match qualified_type_name.split_once('#') {
+            Some((schema_id, type_name)) if !schema_id.is_empty() && !type_name.is_empty() => {
+                self.validate_with(schema_id, type_name, value, options)
+            }
+            _ => Err(ValidationError::InvalidTypeName {
+                name: qualified_type_name.to_owned(),
+            }),
+        }
+    }
+
+    /// Removes the schema with given id from the cache of loaded schemas, along with all the schemas that imported it
+    /// directly or transitively, so that the next [`SchemaSystem::load_schema`] of any of them resolves it again
+    /// using the authorities. Returns the ids of the schemas that were removed from the cache, in sorted order.
+    pub fn invalidate_schema<A: AsRef<str>>(&mut self, id: A) -> Vec<String> {
+        self.resolver.invalidate_schema(id.as_ref())
     }
 
-    /// Returns authorities associated with this [`SchemaSystem`]
-    fn authorities(&mut self) -> &[Box<dyn DocumentAuthority>] {
+    /// Invalidates the schema with given id like [`SchemaSystem::invalidate_schema`] and loads it again.
+    pub fn reload_schema<A: AsRef<str>>(&mut self, id: A) -> IonSchemaResult<Arc<Schema>> {
+        self.invalidate_schema(id.as_ref());
+        self.load_schema(id)
+    }
+
+    /// Invalidates every schema that was modified since it was read from its authority, along with all the schemas
+    /// that imported it. The modified schemas are detected using [`DocumentAuthority::fingerprint`], hence only the
+    /// schemas of authorities that provide fingerprints (e.g. [`FileSystemDocumentAuthority`]) are invalidated,
+    /// and only if change detection is enabled (see [`SchemaSystem::with_change_detection`]).
+    /// Returns the ids of the schemas that were removed from the cache, in sorted order.
+    ///
+    /// [`FileSystemDocumentAuthority`]: crate::authority::FileSystemDocumentAuthority
+    pub fn invalidate_modified_schemas(&mut self) -> Vec<String> {
+        self.resolver.invalidate_modified_schemas()
+    }
+
+    /// Returns authorities associated with this [`SchemaSystem`], in the order they are requested to resolve schema ids
+    pub fn authorities(&self) -> &[Arc<dyn DocumentAuthority>] {
         &self.resolver.authorities
     }
 
-    /// Adds the provided authority to the list of [`DocumentAuthority`]s.
-    fn add_authority(&mut self, authority: Box<dyn DocumentAuthority>) {
-        self.resolver.authorities.push(authority);
+    /// Adds the provided authority to the end of the list of [`DocumentAuthority`]s.
+    ///
+    /// The loaded schemas stay cached, as the added authority is only requested for the schema ids
+    /// that the other authorities can't resolve.
+    pub fn add_authority(&mut self, authority: Box<dyn DocumentAuthority>) {
+        self.resolver.authorities.push(Arc::from(authority));
+    }
+
+    /// Removes the authority at given index from the list of [`DocumentAuthority`]s and returns it,
+    /// or returns `None` if there is no authority at given index.
+    ///
+    /// All the loaded schemas are removed from the cache, as they might have been resolved using the removed authority.
+    pub fn remove_authority(&mut self, index: usize) -> Option<Arc<dyn DocumentAuthority>> {
+        if index >= self.resolver.authorities.len() {
+            return None;
+        }
+        self.resolver.clear_cache();
+        Some(self.resolver.authorities.remove(index))
     }
 
     /// Replaces the list of [`DocumentAuthority`]s with a list containing only the specified authority.
-    fn with_authority(&mut self, authority: Box<dyn DocumentAuthority>) {
-        let authorities: Vec<Box<dyn DocumentAuthority>> = vec![authority];
-        self.resolver.authorities = authorities;
+    ///
+    /// All the loaded schemas are removed from the cache, so that they are resolved again using the specified authority.
+    pub fn with_authority(&mut self, authority: Box<dyn DocumentAuthority>) {
+        self.resolver.clear_cache();
+        self.resolver.authorities = vec![Arc::from(authority)];
+    }
+
+    /// Returns the [`LoadLimits`] that are enforced while loading schemas
+    pub fn load_limits(&self) -> LoadLimits {
+        self.resolver.load_limits
+    }
+
+    /// Replaces the [`LoadLimits`] that are enforced while loading schemas.
+    ///
+    /// All the loaded schemas are removed from the cache, so that the specified limits apply to all the returned schemas.
+    pub fn with_load_limits(&mut self, load_limits: LoadLimits) {
+        self.resolver.clear_cache();
+        self.resolver.load_limits = load_limits;
+    }
+
+    /// Returns `true` if the fingerprints of the schemas are recorded when they are loaded,
+    /// see [`SchemaSystem::with_change_detection`]
+    pub fn is_change_detection_enabled(&self) -> bool {
+        self.resolver.change_detection
+    }
+
+    /// Enables or disables the recording of the [`DocumentAuthority::fingerprint`] of each schema when it is loaded,
+    /// which is needed by [`SchemaSystem::invalidate_modified_schemas`] to detect the modified schemas.
+    /// Change detection is disabled by default, so that loading a schema doesn't request its fingerprint.
+    ///
+    /// All the loaded schemas are removed from the cache, so that the fingerprints of all the returned schemas are recorded.
+    pub fn with_change_detection(&mut self, enabled: bool) {
+        self.resolver.clear_cache();
+        self.resolver.change_detection = enabled;
     }
 
     // TODO: Use IntoIterator here instead of a Vec
     /// Replaces the list of [`DocumentAuthority`]s with the specified list of [`DocumentAuthority`]s.
-    fn with_authorities(&mut self, authorities: Vec<Box<dyn DocumentAuthority>>) {
-        self.resolver.authorities = authorities;
+    ///
+    /// All the loaded schemas are removed from the cache, so that they are resolved again using the specified
+    /// authorities.
+    pub fn with_authorities(&mut self, authorities: Vec<Box<dyn DocumentAuthority>>) {
+        self.resolver.clear_cache();
+        self.resolver.authorities = authorities.into_iter().map(Arc::from).collect();
     }
 
     /// Creates a schema from given [`IslType`]s using ISL 1.0
@@ -1244,14 +2406,324 @@ impl SchemaSystem {
     }
 }
 
+/// Provides a thread-safe alternative to [`SchemaSystem`] whose methods take `&self`, hence it can be shared between
+/// threads without any external locking, e.g. using an `Arc<SharedSchemaSystem>`.
+///
+/// Each loaded schema is cached by its id, so loading the same id again returns the same `Arc<Schema>`.
+/// The imported schemas are loaded and cached the same way, hence the schemas that import the same schema share its
+/// types and [`TypeId`]s.
+/// Concurrent loads of the same id are deduplicated: the first load resolves the schema while the others wait for it
+/// and then return the cached schema, while loads of different ids run in parallel.
+/// A schema that fails to load is not cached, the next load of its id tries to resolve it again.
+/// ```
+/// use ion_schema::authority::MapDocumentAuthority;
+/// use ion_schema::system::SharedSchemaSystem;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let authority = MapDocumentAuthority::new([("sample.isl", "type::{ name: my_int, type: int }")]);
+/// let schema_system = Arc::new(SharedSchemaSystem::new(vec![Box::new(authority)]));
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let schema_system = Arc::clone(&schema_system);
+///         thread::spawn(move || schema_system.load_schema("sample.isl").unwrap())
+///     })
+///     .collect();
+/// let schemas: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+/// assert!(schemas.iter().all(|schema| Arc::ptr_eq(schema, &schemas[0])));
+/// ```
+pub struct SharedSchemaSystem {
+    authorities: Vec<Arc<dyn DocumentAuthority>>,
+    schema_slots: Arc<SchemaSlots>,
+    // shared by the resolvers of all the loads, see [`Resolver::next_type_id`]
+    next_type_id: Arc<AtomicUsize>,
+    load_limits: LoadLimits,
+    change_detection: bool,
+}
+
+impl SharedSchemaSystem {
+    pub fn new(authorities: Vec<Box<dyn DocumentAuthority>>) -> Self {
+        Self::with_shared_authorities(authorities.into_iter().map(Arc::from).collect())
+    }
+
+    fn with_shared_authorities(authorities: Vec<Arc<dyn DocumentAuthority>>) -> Self {
+        Self {
+            authorities,
+            schema_slots: Arc::new(SchemaSlots::default()),
+            next_type_id: Arc::new(AtomicUsize::new(TypeStore::default().next_type_id())),
+            load_limits: LoadLimits::default(),
+            change_detection: false,
+        }
+    }
+
+    /// Replaces the [`LoadLimits`] that are enforced while loading schemas, see [`SchemaSystem::with_load_limits`].
+    pub fn with_load_limits(&mut self, load_limits: LoadLimits) {
+        self.schema_slots.lock().clear();
+        self.load_limits = load_limits;
+    }
+
+    /// Returns `true` if the fingerprints of the schemas are recorded when they are loaded,
+    /// see [`SchemaSystem::with_change_detection`]
+    pub fn is_change_detection_enabled(&self) -> bool {
+        self.change_detection
+    }
+
+    /// Enables or disables the recording of the fingerprint of each schema when it is loaded, which is needed by
+    /// [`SharedSchemaSystem::invalidate_modified_schemas`], see [`SchemaSystem::with_change_detection`].
+    pub fn with_change_detection(&mut self, enabled: bool) {
+        self.schema_slots.lock().clear();
+        self.change_detection = enabled;
+    }
+
+    /// Requests each of the provided [`DocumentAuthority`]s, in order, to resolve the requested schema id
+    /// until one successfully resolves it, unless the schema was already loaded by this [`SharedSchemaSystem`].
+    /// See [`SchemaSystem::load_schema`].
+    pub fn load_schema<A: AsRef<str>>(&self, id: A) -> IonSchemaResult<Arc<Schema>> {
+        self.resolver()
+            .load_schema(id, &mut DiagnosticCollector::fail_fast())
+    }
+
+    /// Requests each of the provided [`DocumentAuthority`]s, in order, to get ISL model for the
+    /// requested schema id until one successfully resolves it. ISL models are not cached.
+    pub fn load_isl_schema<A: AsRef<str>>(&self, id: A) -> IonSchemaResult<IslSchema> {
+        self.resolver().load_isl_schema(id, None)
+    }
+
+    /// Removes the schema with given id from the loaded schemas, along with all the schemas that imported it directly
+    /// or transitively, so that the next [`SharedSchemaSystem::load_schema`] of any of them resolves it again using
+    /// the authorities. Returns the ids of the removed schemas, in sorted order.
+    ///
+    /// The schemas that are being loaded by other threads are not removed, as they might have been resolved using
+    /// the previous version of the schema.
+    pub fn invalidate_schema<A: AsRef<str>>(&self, id: A) -> Vec<String> {
+        self.schema_slots.lock().invalidate(id.as_ref())
+    }
+
+    /// Invalidates the schema with given id like [`SharedSchemaSystem::invalidate_schema`] and loads it again.
+    pub fn reload_schema<A: AsRef<str>>(&self, id: A) -> IonSchemaResult<Arc<Schema>> {
+        self.invalidate_schema(id.as_ref());
+        self.load_schema(id)
+    }
+
+    /// Invalidates every schema that was modified since it was read from its authority, along with all the schemas
+    /// that imported it, see [`SchemaSystem::invalidate_modified_schemas`]. Only works if change detection is enabled
+    /// (see [`SharedSchemaSystem::with_change_detection`]).
+    /// Returns the ids of the removed schemas, in sorted order.
+    pub fn invalidate_modified_schemas(&self) -> Vec<String> {
+        let fingerprints = self.schema_slots.lock().fingerprints.clone();
+        // the authorities are requested for the fingerprints without holding the lock, so that loads are not blocked
+        let resolver = self.resolver();
+        let modified_ids: Vec<String> = fingerprints
+            .into_iter()
+            .filter(|(id, fingerprint)| resolver.fingerprint(id) != Some(*fingerprint))
+            .map(|(id, _)| id)
+            .collect();
+        let mut state = self.schema_slots.lock();
+        let mut invalidated_ids: Vec<String> = modified_ids
+            .iter()
+            .flat_map(|id| state.invalidate(id))
+            .collect();
+        invalidated_ids.sort();
+        invalidated_ids.dedup();
+        invalidated_ids
+    }
+
+    // Creates a resolver for a single load, which loads the schema and its imports through the shared schema slots
+    fn resolver(&self) -> Resolver {
+        let mut resolver = Resolver::with_shared_authorities(self.authorities.to_vec());
+        resolver.next_type_id = Arc::clone(&self.next_type_id);
+        resolver.schema_slots = Some(Arc::clone(&self.schema_slots));
+        resolver.load_limits = self.load_limits;
+        resolver.change_detection = self.change_detection;
+        resolver
+    }
+}
+
+// The schemas loaded by a [`SharedSchemaSystem`], where every schema id has its own slot. A slot is either loaded, or
+// being loaded by a thread while the other threads that load the same id wait for it.
+#[derive(Default)]
+struct SchemaSlots {
+    state: Mutex<SchemaSlotsState>,
+    // notified whenever a slot is loaded or removed
+    slot_changed: Condvar,
+}
+
+#[derive(Default)]
+struct SchemaSlotsState {
+    schemas: HashMap<String, SchemaSlot>,
+    // the schema id that each waiting thread waits for, used to detect import cycles between concurrent loads
+    waiting_ids: HashMap<ThreadId, String>,
+    // ids of the schemas imported by each loaded schema, used to find the schemas that imported an invalidated schema
+    imported_schema_ids: HashMap<String, HashSet<String>>,
+    // fingerprints of the loaded schemas, used to find the schemas that were modified since they were read
+    fingerprints: HashMap<String, u64>,
+}
+
+// A schema loaded into its slot, along with what is needed to invalidate it
+struct LoadedSchema {
+    schema: Arc<Schema>,
+    imported_ids: HashSet<String>,
+    fingerprint: Option<u64>,
+}
+
+enum SchemaSlot {
+    Loading(ThreadId),
+    Loaded(Arc<Schema>),
+}
+
+impl SchemaSlots {
+    fn lock(&self) -> MutexGuard<'_, SchemaSlotsState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    // Returns the schema with given id from its slot, or loads it with given function if there is no slot for it.
+    // The slot is removed if the load fails, so that the next load of the same id tries to load it again.
+    fn load<F: FnOnce() -> IonSchemaResult<LoadedSchema>>(
+        &self,
+        id: &str,
+        load: F,
+    ) -> IonSchemaResult<Arc<Schema>> {
+        let thread_id = thread::current().id();
+        let mut state = self.lock();
+        loop {
+            match state.schemas.get(id) {
+                Some(SchemaSlot::Loaded(schema)) => return Ok(Arc::clone(schema)),
+                Some(SchemaSlot::Loading(_)) => {
+                    // waiting for a schema that imports the schemas being loaded by this thread would never end
+                    if let Some(cycle) = state.wait_cycle(id, thread_id) {
+                        return unresolvable_schema_error(format!(
+                            "Unable to load schema {id} due to an import cycle: {}",
+                            cycle.join(" -> ")
+                        ));
+                    }
+                    state.waiting_ids.insert(thread_id, id.to_owned());
+                    state = self
+                        .slot_changed
+                        .wait(state)
+                        .unwrap_or_else(PoisonError::into_inner);
+                    state.waiting_ids.remove(&thread_id);
+                }
+                None => break,
+            }
+        }
+        state
+            .schemas
+            .insert(id.to_owned(), SchemaSlot::Loading(thread_id));
+        drop(state);
+
+        let mut loading_slot = LoadingSlot {
+            schema_slots: self,
+            id,
+            loaded_schema: None,
+        };
+        let loaded_schema = load()?;
+        let schema = Arc::clone(&loaded_schema.schema);
+        loading_slot.loaded_schema = Some(loaded_schema);
+        Ok(schema)
+    }
+}
+
+impl SchemaSlotsState {
+    // Removes all the loaded schemas, the loads can't be in progress as this requires the [`SharedSchemaSystem`] to be
+    // borrowed mutably
+    fn clear(&mut self) {
+        self.schemas.clear();
+        self.imported_schema_ids.clear();
+        self.fingerprints.clear();
+    }
+
+    // Removes the loaded schema with given id and all the loaded schemas that imported it, directly or transitively.
+    // Returns the ids of the removed schemas.
+    fn invalidate(&mut self, id: &str) -> Vec<String> {
+        let mut invalidated_ids = vec![];
+        let mut pending_ids = vec![id.to_owned()];
+        let mut visited_ids = HashSet::new();
+        while let Some(id) = pending_ids.pop() {
+            if !visited_ids.insert(id.to_owned()) {
+                continue;
+            }
+            pending_ids.extend(
+                self.imported_schema_ids
+                    .iter()
+                    .filter(|(_, imported_ids)| imported_ids.contains(&id))
+                    .map(|(importing_id, _)| importing_id.to_owned()),
+            );
+            if let Some(SchemaSlot::Loaded(_)) = self.schemas.get(&id) {
+                self.schemas.remove(&id);
+                self.imported_schema_ids.remove(&id);
+                self.fingerprints.remove(&id);
+                invalidated_ids.push(id);
+            }
+        }
+        invalidated_ids.sort();
+        invalidated_ids
+    }
+
+    // Returns the ids of the schemas that form a cycle, if the schema with given id is waiting, directly or through
+    // other threads, for a schema being loaded by given thread
+    fn wait_cycle<'a>(&'a self, id: &'a str, thread_id: ThreadId) -> Option<Vec<&'a str>> {
+        let mut cycle = vec![id];
+        let mut waiting_id = id;
+        while let Some(SchemaSlot::Loading(loading_thread_id)) = self.schemas.get(waiting_id) {
+            if *loading_thread_id == thread_id {
+                cycle.push(id);
+                return Some(cycle);
+            }
+            if cycle.len() > self.waiting_ids.len() {
+                break;
+            }
+            waiting_id = self.waiting_ids.get(loading_thread_id)?;
+            cycle.push(waiting_id);
+        }
+        None
+    }
+}
+
+// A slot that is being loaded, which is set to the loaded schema or removed when it is dropped, even when the load
+// panics, and wakes up the threads that wait for it
+struct LoadingSlot<'a> {
+    schema_slots: &'a SchemaSlots,
+    id: &'a str,
+    loaded_schema: Option<LoadedSchema>,
+}
+
+impl Drop for LoadingSlot<'_> {
+    fn drop(&mut self) {
+        let mut state = self.schema_slots.lock();
+        match self.loaded_schema.take() {
+            Some(loaded_schema) => {
+                state
+                    .schemas
+                    .insert(self.id.to_owned(), SchemaSlot::Loaded(loaded_schema.schema));
+                state
+                    .imported_schema_ids
+                    .insert(self.id.to_owned(), loaded_schema.imported_ids);
+                if let Some(fingerprint) = loaded_schema.fingerprint {
+                    state.fingerprints.insert(self.id.to_owned(), fingerprint);
+                }
+            }
+            None => {
+                state.schemas.remove(self.id);
+            }
+        }
+        self.schema_slots.slot_changed.notify_all();
+    }
+}
+
 #[cfg(test)]
 mod schema_system_tests {
     use super::*;
-    use crate::authority::{FileSystemDocumentAuthority, MapDocumentAuthority};
+    #[cfg(feature = "archive")]
+    use crate::authority::ArchiveLimits;
+    use crate::authority::{FileSystemDocumentAuthority, MapDocumentAuthority, SchemaElements};
     use crate::isl::isl_constraint;
     use crate::isl::isl_type;
     use crate::isl::isl_type_reference;
+    use crate::result::DiagnosticSeverity;
     use crate::system::IonSchemaError::InvalidSchemaError;
+    use crate::violation::ViolationCode;
+    use rstest::*;
     use std::path::Path;
 
     #[test]
@@ -1292,41 +2764,243 @@ mod schema_system_tests {
     }
 
     #[test]
-    fn schema_system_map_authority_with_type_alias_import_test() {
-        // map with (id, ion content)
-        let map_authority = [
+    fn schema_system_remove_authority_test() {
+        let mut schema_system = SchemaSystem::new(vec![
+            Box::new(FileSystemDocumentAuthority::new(Path::new("test"))),
+            Box::new(FileSystemDocumentAuthority::new(Path::new("ion"))),
+        ]);
+        assert!(schema_system.remove_authority(2).is_none());
+        assert!(schema_system.remove_authority(0).is_some());
+        assert_eq!(1, schema_system.authorities().len());
+    }
+
+    #[cfg(feature = "archive")]
+    #[rstest(
+        file_name,
+        case::zip("schemas.zip"),
+        case::tar("schemas.tar"),
+        case::tar_gz("schemas.tar.gz"),
+        case::tgz("schemas.TGZ")
+    )]
+    fn schema_system_archive_authority_test(file_name: &str) {
+        use crate::authority::ArchiveDocumentAuthority;
+        use std::fs;
+        use std::io::Write;
+
+        let entries = [
             (
-                "sample_number.isl",
+                "./isl/sample.isl",
                 r#"
-                    schema_header::{
-                      imports: [{ id: "sample_decimal.isl", type: my_decimal, as: other_decimal }],
-                    }
-                    
-                    type::{
-                      name: my_int,
-                      type: int,
-                    }
-                    
-                    type::{
-                      name: my_number,
-                      all_of: [
-                        my_int,
-                        other_decimal,
-                      ],
-                    }
-                    
-                    schema_footer::{
-                    }
+                    schema_header::{ imports: [{ id: "isl/numbers/positive.isl", type: positive_int }] }
+                    type::{ name: my_type, type: list, element: positive_int }
+                    schema_footer::{}
                 "#,
             ),
             (
-                "sample_decimal.isl",
-                r#"
-                    schema_header::{
-                      imports: [],
-                    }
-                    
-                    type::{
+                "./isl/numbers/positive.isl",
+                "type::{ name: positive_int, type: int, valid_values: range::[1, max] }",
+            ),
+        ];
+        let archive_path =
+            std::env::temp_dir().join(format!("ion-schema-{}-{file_name}", std::process::id()));
+        let file = fs::File::create(&archive_path).unwrap();
+        if file_name.ends_with(".zip") {
+            let mut writer = zip::ZipWriter::new(file);
+            writer
+                .add_directory("isl/", zip::write::FileOptions::default())
+                .unwrap();
+            for (path, ion_content) in entries {
+                writer
+                    .start_file(path, zip::write::FileOptions::default())
+                    .unwrap();
+                writer.write_all(ion_content.as_bytes()).unwrap();
+            }
+            writer.finish().unwrap();
+        } else {
+            let writer: Box<dyn Write> = if file_name.ends_with(".tar") {
+                Box::new(file)
+            } else {
+                Box::new(flate2::write::GzEncoder::new(
+                    file,
+                    flate2::Compression::default(),
+                ))
+            };
+            let mut builder = tar::Builder::new(writer);
+            for (path, ion_content) in entries {
+                let mut header = tar::Header::new_gnu();
+                header.set_size(ion_content.len() as u64);
+                header.set_mode(0o644);
+                builder
+                    .append_data(&mut header, path, ion_content.as_bytes())
+                    .unwrap();
+            }
+            builder.into_inner().unwrap().flush().unwrap();
+        }
+        let authority = ArchiveDocumentAuthority::new(&archive_path);
+        fs::remove_file(&archive_path).unwrap();
+        let authority = authority.unwrap();
+        let mut schema_ids: Vec<&str> = authority.schema_ids().collect();
+        schema_ids.sort();
+        assert_eq!(
+            schema_ids,
+            vec!["isl/numbers/positive.isl", "isl/sample.isl"]
+        );
+
+        let mut schema_system = SchemaSystem::new(vec![
+            Box::new(authority),
+            Box::new(MapDocumentAuthority::new([(
+                "other.isl",
+                "type::{ name: other_type, type: string }",
+            )])),
+        ]);
+        let schema = schema_system.load_schema("isl/sample.isl").unwrap();
+        let my_type = schema.get_type("my_type").unwrap();
+        assert!(my_type
+            .validate(&Element::read_one("[1, 2]").unwrap())
+            .is_ok());
+        assert!(my_type
+            .validate(&Element::read_one("[0]").unwrap())
+            .is_err());
+        // schemas that are not in the archive are resolved by the next authority
+        assert!(schema_system.load_schema("other.isl").is_ok());
+    }
+
+    #[cfg(feature = "archive")]
+    #[rstest(
+        limits, is_ok,
+        case::default_limits(ArchiveLimits::default(), true),
+        case::max_entry_size(ArchiveLimits::default().with_max_entry_size(99), false),
+        case::max_total_size(ArchiveLimits::default().with_max_total_size(199), false),
+        case::exact_limits(ArchiveLimits::default().with_max_entry_size(100).with_max_total_size(200), true)
+    )]
+    fn schema_system_archive_authority_limits_test(limits: ArchiveLimits, is_ok: bool) {
+        use crate::authority::ArchiveDocumentAuthority;
+        use std::io::{Cursor, Write};
+
+        // two entries of 100 bytes each
+        let ion_content = format!("\"{}\"", "a".repeat(98));
+        let mut zip_writer = zip::ZipWriter::new(Cursor::new(vec![]));
+        let mut tar_builder = tar::Builder::new(vec![]);
+        for path in ["a.isl", "b.isl"] {
+            zip_writer
+                .start_file(path, zip::write::FileOptions::default())
+                .unwrap();
+            zip_writer.write_all(ion_content.as_bytes()).unwrap();
+            let mut header = tar::Header::new_gnu();
+            header.set_size(ion_content.len() as u64);
+            header.set_mode(0o644);
+            tar_builder
+                .append_data(&mut header, path, ion_content.as_bytes())
+                .unwrap();
+        }
+        let zip_archive = zip_writer.finish().unwrap();
+        let tar_archive = tar_builder.into_inner().unwrap();
+
+        for result in [
+            ArchiveDocumentAuthority::from_zip_with_limits(zip_archive, limits),
+            ArchiveDocumentAuthority::from_tar_with_limits(tar_archive.as_slice(), limits),
+        ] {
+            match result {
+                Ok(authority) => {
+                    assert!(is_ok);
+                    assert_eq!(authority.schema_ids().count(), 2);
+                }
+                Err(error) => {
+                    assert!(!is_ok);
+                    assert!(matches!(error, IonSchemaError::LimitExceededError { .. }));
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "archive")]
+    #[test]
+    fn schema_system_archive_authority_unsupported_format_test() {
+        use crate::authority::ArchiveDocumentAuthority;
+
+        let error = ArchiveDocumentAuthority::new(Path::new("schemas.rar")).unwrap_err();
+        assert!(
+            matches!(error, IonSchemaError::IoError { source } if source.kind() == std::io::ErrorKind::InvalidInput)
+        );
+    }
+
+    #[rstest(
+        change_authorities, is_cached, is_int,
+        case::add_authority(|schema_system: &mut SchemaSystem| {
+            schema_system.add_authority(Box::new(MapDocumentAuthority::new([("sample.isl", "type::{ name: my_type, type: string }")])))
+        }, true, true),
+        case::remove_authority(|schema_system: &mut SchemaSystem| {
+            schema_system.remove_authority(1);
+        }, false, true),
+        case::with_authority(|schema_system: &mut SchemaSystem| {
+            schema_system.with_authority(Box::new(MapDocumentAuthority::new([("sample.isl", "type::{ name: my_type, type: string }")])))
+        }, false, false),
+        case::with_authorities(|schema_system: &mut SchemaSystem| {
+            schema_system.with_authorities(vec![Box::new(MapDocumentAuthority::new([("sample.isl", "type::{ name: my_type, type: string }")]))])
+        }, false, false),
+    )]
+    fn schema_system_change_authorities_test(
+        change_authorities: fn(&mut SchemaSystem),
+        is_cached: bool,
+        is_int: bool,
+    ) {
+        let mut schema_system = SchemaSystem::new(vec![
+            Box::new(MapDocumentAuthority::new([(
+                "sample.isl",
+                "type::{ name: my_type, type: int }",
+            )])),
+            Box::new(MapDocumentAuthority::new([])),
+        ]);
+        let schema = schema_system.load_schema("sample.isl").unwrap();
+        change_authorities(&mut schema_system);
+        let loaded_schema = schema_system.load_schema("sample.isl").unwrap();
+        assert_eq!(Arc::ptr_eq(&schema, &loaded_schema), is_cached);
+        assert_eq!(
+            loaded_schema
+                .get_type("my_type")
+                .unwrap()
+                .validate(&Element::from(5))
+                .is_ok(),
+            is_int
+        );
+    }
+
+    #[test]
+    fn schema_system_map_authority_with_type_alias_import_test() {
+        // map with (id, ion content)
+        let map_authority = [
+            (
+                "sample_number.isl",
+                r#"
+                    schema_header::{
+                      imports: [{ id: "sample_decimal.isl", type: my_decimal, as: other_decimal }],
+                    }
+                    
+                    type::{
+                      name: my_int,
+                      type: int,
+                    }
+                    
+                    type::{
+                      name: my_number,
+                      all_of: [
+                        my_int,
+                        other_decimal,
+                      ],
+                    }
+                    
+                    schema_footer::{
+                    }
+                "#,
+            ),
+            (
+                "sample_decimal.isl",
+                r#"
+                    schema_header::{
+                      imports: [],
+                    }
+                    
+                    type::{
                       name: my_decimal,
                       type: decimal,
                     }
@@ -1825,6 +3499,43 @@ mod schema_system_tests {
         assert!(schema.is_ok());
     }
 
+    #[rstest(
+        imports, is_valid,
+        case::multiple_type_imports_from_same_schema(
+            r#"[{ id: "numbers.isl", type: positive_int }, { id: "numbers.isl", type: negative_int, as: below_zero }]"#,
+            true
+        ),
+        case::type_import_conflicting_with_defined_type(
+            r#"[{ id: "numbers.isl", type: my_int }]"#,
+            false
+        ),
+        case::schema_import_conflicting_with_defined_type(r#"[{ id: "numbers.isl" }]"#, false),
+    )]
+    fn schema_system_map_authority_with_imported_type_names(imports: &str, is_valid: bool) {
+        let schema = format!(
+            r#"
+                schema_header::{{ imports: {imports} }}
+                type::{{ name: my_int, type: int }}
+                schema_footer::{{}}
+            "#
+        );
+        let map_authority = [
+            ("sample.isl", schema.as_str()),
+            (
+                "numbers.isl",
+                r#"
+                    type::{ name: positive_int, type: int, valid_values: range::[1, max] }
+                    type::{ name: negative_int, type: int, valid_values: range::[min, -1] }
+                    type::{ name: my_int, type: int }
+                "#,
+            ),
+        ];
+        let mut schema_system =
+            SchemaSystem::new(vec![Box::new(MapDocumentAuthority::new(map_authority))]);
+        let schema = schema_system.load_schema("sample.isl");
+        assert_eq!(schema.is_ok(), is_valid);
+    }
+
     #[test]
     fn schema_system_map_authority_with_multiple_codependent_type_definitions() {
         // map with (id, ion content)
@@ -2294,4 +4005,1914 @@ mod schema_system_tests {
         fn assert_sync<T: Sync>() {}
         assert_sync::<SchemaSystem>();
     }
+
+    #[test]
+    fn test_send_and_sync_shared_schema_system() {
+        fn assert_send_and_sync<T: Send + Sync>() {}
+        assert_send_and_sync::<SharedSchemaSystem>();
+    }
+
+    #[test]
+    fn load_schema_importing_cached_schema() {
+        let map_authority = [
+            (
+                "sample.isl",
+                r#"
+                    schema_header::{ imports: [{ id: "imported.isl" }] }
+                    type::{ name: my_type, type: imported_type }
+                    schema_footer::{}
+                "#,
+            ),
+            ("imported.isl", "type::{ name: imported_type, type: int }"),
+        ];
+        let mut schema_system =
+            SchemaSystem::new(vec![Box::new(MapDocumentAuthority::new(map_authority))]);
+        let imported_schema = schema_system.load_schema("imported.isl").unwrap();
+        let schema = schema_system.load_schema("sample.isl").unwrap();
+        assert!(schema.get_type("my_type").is_some());
+        // the cached root schema is returned for the subsequent loads
+        assert!(Arc::ptr_eq(
+            &imported_schema,
+            &schema_system.load_schema("imported.isl").unwrap()
+        ));
+
+        // a schema that was loaded as an import is cached along with the types that it shares with the importing schema
+        let mut schema_system =
+            SchemaSystem::new(vec![Box::new(MapDocumentAuthority::new(map_authority))]);
+        let schema = schema_system.load_schema("sample.isl").unwrap();
+        let imported_schema = schema_system.load_schema("imported.isl").unwrap();
+        assert!(imported_schema.get_type("imported_type").is_some());
+        assert_eq!(
+            schema
+                .import("imported.isl")
+                .unwrap()
+                .get_type("imported_type")
+                .unwrap()
+                .type_definition()
+                .id(),
+            imported_schema.get_type("imported_type").unwrap().id()
+        );
+    }
+
+    #[test]
+    fn imported_types_are_shared() {
+        let map_authority = [
+            (
+                "a.isl",
+                r#"
+                    schema_header::{ imports: [{ id: "common.isl" }] }
+                    type::{ name: a_type, type: list, element: common_type }
+                    schema_footer::{}
+                "#,
+            ),
+            (
+                "b.isl",
+                r#"
+                    schema_header::{ imports: [{ id: "common.isl", type: common_type, as: b_common_type }] }
+                    type::{ name: b_type, type: b_common_type }
+                    schema_footer::{}
+                "#,
+            ),
+            (
+                "c.isl",
+                r#"
+                    schema_header::{ imports: [{ id: "b.isl" }, { id: "a.isl" }] }
+                    type::{ name: c_type, one_of: [a_type, b_type] }
+                    schema_footer::{}
+                "#,
+            ),
+            (
+                "common.isl",
+                "type::{ name: common_type, type: int, valid_values: range::[1, max] }",
+            ),
+            (
+                "unknown_type.isl",
+                r#"
+                    schema_header::{ imports: [{ id: "common.isl", type: unknown_type }] }
+                    schema_footer::{}
+                "#,
+            ),
+        ];
+        let mut schema_system =
+            SchemaSystem::new(vec![Box::new(MapDocumentAuthority::new(map_authority))]);
+        let a = schema_system.load_schema("a.isl").unwrap();
+        let b = schema_system.load_schema("b.isl").unwrap();
+        let c = schema_system.load_schema("c.isl").unwrap();
+        let common = schema_system.load_schema("common.isl").unwrap();
+
+        // the built-in types are shared by all the type stores
+        assert!(Arc::ptr_eq(
+            &a.type_store().shared_types[0].types,
+            &TypeStore::default().shared_types[0].types
+        ));
+        // the types of an imported schema are shared with the importing schemas rather than copied
+        let common_types = &common.type_store().shared_types.last().unwrap().types;
+        let shares_common_types = |schema: &Schema| {
+            schema
+                .type_store()
+                .shared_types
+                .iter()
+                .any(|shared_types| Arc::ptr_eq(&shared_types.types, common_types))
+        };
+        assert!(shares_common_types(&a));
+        assert!(shares_common_types(&b));
+        assert!(shares_common_types(&c));
+        let imported_type = |schema: &Schema, name: &str| {
+            schema
+                .import("common.isl")
+                .unwrap()
+                .get_type(name)
+                .unwrap()
+                .type_definition()
+        };
+        let common_type_id = common.get_type("common_type").unwrap().id();
+        assert_eq!(imported_type(&a, "common_type").id(), common_type_id);
+        // an aliased import is renamed, hence it is the only imported type that is added to the importing schema
+        let b_common_type = imported_type(&b, "b_common_type");
+        assert_ne!(b_common_type.id(), common_type_id);
+        assert_eq!(b_common_type.name(), Some("b_common_type".to_owned()));
+
+        // the TypeIds of the types of different schemas never overlap
+        let c_type = c.get_type("c_type").unwrap();
+        assert!(c_type
+            .validate(&Element::read_one("[1, 2]").unwrap())
+            .is_ok());
+        assert!(c_type.validate(&Element::from(3)).is_ok());
+        assert!(c_type.validate(&Element::read_one("[0]").unwrap()).is_err());
+        assert!(c_type.validate(&Element::from(0)).is_err());
+
+        assert!(schema_system.load_schema("unknown_type.isl").is_err());
+    }
+
+    #[test]
+    fn add_conflicting_shared_types() {
+        let load_schema = || {
+            let authority = MapDocumentAuthority::new([(
+                "common.isl",
+                "type::{ name: common_type, type: int }",
+            )]);
+            SchemaSystem::new(vec![Box::new(authority)])
+                .load_schema("common.isl")
+                .unwrap()
+        };
+        // the types of schemas loaded by different schema systems start at the same TypeId
+        let (first, second) = (load_schema(), load_schema());
+        let mut type_store = TypeStore::default();
+        assert!(type_store.add_shared_types(first.type_store()).is_ok());
+        assert!(type_store.add_shared_types(first.type_store()).is_ok());
+        assert!(type_store.add_shared_types(second.type_store()).is_err());
+    }
+
+    // An authority that counts the number of times each schema is requested
+    #[derive(Debug)]
+    struct CountingDocumentAuthority {
+        authority: MapDocumentAuthority,
+        requests: Mutex<HashMap<String, usize>>,
+    }
+
+    impl DocumentAuthority for CountingDocumentAuthority {
+        fn elements(&self, id: &str) -> IonSchemaResult<Vec<Element>> {
+            *self
+                .requests
+                .lock()
+                .unwrap()
+                .entry(id.to_owned())
+                .or_default() += 1;
+            self.authority.elements(id)
+        }
+
+        fn read_elements(&self, id: &str, options: ReadOptions) -> IonSchemaResult<SchemaElements> {
+            *self
+                .requests
+                .lock()
+                .unwrap()
+                .entry(id.to_owned())
+                .or_default() += 1;
+            self.authority.read_elements(id, options)
+        }
+    }
+
+    #[test]
+    fn shared_schema_system_load_schema() {
+        let authority = Arc::new(CountingDocumentAuthority {
+            authority: MapDocumentAuthority::new([
+                (
+                    "sample.isl",
+                    r#"
+                        schema_header::{ imports: [{ id: "imported.isl" }] }
+                        type::{ name: my_type, type: imported_type }
+                        schema_footer::{}
+                    "#,
+                ),
+                ("imported.isl", "type::{ name: imported_type, type: int }"),
+            ]),
+            requests: Mutex::new(HashMap::new()),
+        });
+        let schema_system = Arc::new(SharedSchemaSystem::with_shared_authorities(vec![
+            authority.clone()
+        ]));
+
+        let schemas: Vec<Arc<Schema>> = (0..8)
+            .map(|_| {
+                let schema_system = Arc::clone(&schema_system);
+                std::thread::spawn(move || schema_system.load_schema("sample.isl").unwrap())
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect();
+
+        // all the concurrent loads return the schema that was loaded once
+        assert!(schemas
+            .iter()
+            .all(|schema| Arc::ptr_eq(schema, &schemas[0])));
+        assert!(schemas[0].get_type("my_type").is_some());
+        assert_eq!(
+            authority.requests.lock().unwrap().get("sample.isl"),
+            Some(&1)
+        );
+
+        // the imported schema is cached when it is loaded by the importing schema
+        let imported_schema = schema_system.load_schema("imported.isl").unwrap();
+        assert!(Arc::ptr_eq(
+            &imported_schema,
+            &schema_system.load_schema("imported.isl").unwrap()
+        ));
+        assert_eq!(
+            authority.requests.lock().unwrap().get("imported.isl"),
+            Some(&1)
+        );
+
+        // a schema that fails to load is requested again by the next load, and its slot is removed
+        assert!(schema_system.load_schema("missing.isl").is_err());
+        assert!(schema_system.load_schema("missing.isl").is_err());
+        assert_eq!(
+            authority.requests.lock().unwrap().get("missing.isl"),
+            Some(&2)
+        );
+        assert!(!schema_system
+            .schema_slots
+            .lock()
+            .schemas
+            .contains_key("missing.isl"));
+    }
+
+    #[test]
+    fn shared_schema_system_shares_imported_schemas() {
+        let authority = Arc::new(CountingDocumentAuthority {
+            authority: MapDocumentAuthority::new([
+                (
+                    "first.isl",
+                    r#"
+                        schema_header::{ imports: [{ id: "common.isl", type: common_type }] }
+                        type::{ name: first_type, type: list, element: common_type }
+                        schema_footer::{}
+                    "#,
+                ),
+                (
+                    "second.isl",
+                    r#"
+                        schema_header::{ imports: [{ id: "common.isl" }] }
+                        type::{ name: second_type, type: sexp, element: common_type }
+                        schema_footer::{}
+                    "#,
+                ),
+                (
+                    "both.isl",
+                    r#"
+                        schema_header::{ imports: [{ id: "first.isl" }, { id: "second.isl" }] }
+                        type::{ name: both_type, one_of: [first_type, second_type] }
+                        schema_footer::{}
+                    "#,
+                ),
+                ("common.isl", "type::{ name: common_type, type: int }"),
+            ]),
+            requests: Mutex::new(HashMap::new()),
+        });
+        let schema_system = Arc::new(SharedSchemaSystem::with_shared_authorities(vec![
+            authority.clone()
+        ]));
+
+        let schemas: Vec<Arc<Schema>> = ["first.isl", "second.isl"]
+            .into_iter()
+            .map(|id| {
+                let schema_system = Arc::clone(&schema_system);
+                std::thread::spawn(move || schema_system.load_schema(id).unwrap())
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect();
+
+        // both schemas import the same type from the common schema, which is loaded once
+        let common_schema = schema_system.load_schema("common.isl").unwrap();
+        let common_type_id = common_schema.get_type("common_type").unwrap().id();
+        for schema in &schemas {
+            let import = schema.import("common.isl").unwrap();
+            let imported_type = import.get_type("common_type").unwrap();
+            assert_eq!(imported_type.type_definition().id(), common_type_id);
+        }
+        assert_eq!(
+            authority.requests.lock().unwrap().get("common.isl"),
+            Some(&1)
+        );
+
+        // the types of both schemas can be imported together as they don't overlap
+        let schema = schema_system.load_schema("both.isl").unwrap();
+        for schema in &schemas {
+            assert!(Arc::ptr_eq(
+                schema,
+                &schema_system.load_schema(schema.id()).unwrap()
+            ));
+        }
+        let both_type = schema.get_type("both_type").unwrap();
+        assert!(both_type
+            .validate(&Element::read_one("[1, 2]").unwrap())
+            .is_ok());
+        assert!(both_type
+            .validate(&Element::read_one("(1 2)").unwrap())
+            .is_ok());
+        assert!(both_type
+            .validate(&Element::read_one("[a]").unwrap())
+            .is_err());
+    }
+
+    // An authority whose first reads wait for each other, so that the schemas they read are loaded concurrently
+    #[derive(Debug)]
+    struct ConcurrentDocumentAuthority {
+        authority: MapDocumentAuthority,
+        barrier: std::sync::Barrier,
+        reads: Mutex<usize>,
+    }
+
+    impl DocumentAuthority for ConcurrentDocumentAuthority {
+        fn elements(&self, id: &str) -> IonSchemaResult<Vec<Element>> {
+            let read = {
+                let mut reads = self.reads.lock().unwrap();
+                *reads += 1;
+                *reads
+            };
+            if read <= 2 {
+                self.barrier.wait();
+            }
+            self.authority.elements(id)
+        }
+    }
+
+    fn cyclic_schemas() -> MapDocumentAuthority {
+        MapDocumentAuthority::new([
+            (
+                "first.isl",
+                r#"
+                    schema_header::{ imports: [{ id: "second.isl" }] }
+                    type::{ name: first_type, type: int }
+                    schema_footer::{}
+                "#,
+            ),
+            (
+                "second.isl",
+                r#"
+                    schema_header::{ imports: [{ id: "first.isl" }] }
+                    type::{ name: second_type, type: int }
+                    schema_footer::{}
+                "#,
+            ),
+        ])
+    }
+
+    #[test]
+    fn shared_schema_system_import_cycle() {
+        let schema_system = SharedSchemaSystem::new(vec![Box::new(cyclic_schemas())]);
+
+        let error = schema_system.load_schema("first.isl").unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("import cycle: first.isl -> second.isl -> first.isl"),
+            "{error}"
+        );
+        // the slots of the schemas that failed to load are removed
+        assert!(schema_system.schema_slots.lock().schemas.is_empty());
+    }
+
+    #[test]
+    fn shared_schema_system_concurrent_import_cycle() {
+        let schema_system = Arc::new(SharedSchemaSystem::new(vec![Box::new(
+            ConcurrentDocumentAuthority {
+                authority: cyclic_schemas(),
+                barrier: std::sync::Barrier::new(2),
+                reads: Mutex::new(0),
+            },
+        )]));
+
+        // each load imports the schema that is being loaded by the other one, which fails instead of waiting forever
+        let errors: Vec<IonSchemaError> = ["first.isl", "second.isl"]
+            .into_iter()
+            .map(|id| {
+                let schema_system = Arc::clone(&schema_system);
+                std::thread::spawn(move || schema_system.load_schema(id).unwrap_err())
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect();
+        for error in errors {
+            assert!(error.to_string().contains("import cycle"), "{error}");
+        }
+        assert!(schema_system.schema_slots.lock().schemas.is_empty());
+    }
+
+    // An authority whose schemas can be modified after they are loaded
+    #[derive(Debug)]
+    struct MutableDocumentAuthority {
+        ion_content_by_id: Mutex<HashMap<String, String>>,
+    }
+
+    impl MutableDocumentAuthority {
+        fn set(&self, id: &str, ion_content: &str) {
+            self.ion_content_by_id
+                .lock()
+                .unwrap()
+                .insert(id.to_owned(), ion_content.to_owned());
+        }
+    }
+
+    impl DocumentAuthority for MutableDocumentAuthority {
+        fn elements(&self, id: &str) -> IonSchemaResult<Vec<Element>> {
+            let ion_content_by_id = self.ion_content_by_id.lock().unwrap();
+            let ion_content = ion_content_by_id
+                .get(id)
+                .ok_or_else(|| unresolvable_schema_error_raw(id))?;
+            Ok(Element::read_all(ion_content.as_bytes())?)
+        }
+
+        fn fingerprint(&self, id: &str) -> Option<u64> {
+            // the length of the content is enough to tell apart the schemas used in the tests
+            self.ion_content_by_id
+                .lock()
+                .unwrap()
+                .get(id)
+                .map(|ion_content| ion_content.len() as u64)
+        }
+    }
+
+    #[test]
+    fn invalidate_schema() {
+        let authority = Arc::new(MutableDocumentAuthority {
+            ion_content_by_id: Mutex::new(HashMap::new()),
+        });
+        authority.set(
+            "sample.isl",
+            r#"
+                schema_header::{ imports: [{ id: "imported.isl", type: imported_type }] }
+                type::{ name: my_type, type: imported_type }
+                schema_footer::{}
+            "#,
+        );
+        authority.set("imported.isl", "type::{ name: imported_type, type: int }");
+        authority.set("other.isl", "type::{ name: other_type, type: int }");
+        let mut schema_system = SchemaSystem {
+            resolver: Resolver::with_shared_authorities(vec![authority.clone()]),
+        };
+        schema_system.with_change_detection(true);
+
+        let schema = schema_system.load_schema("sample.isl").unwrap();
+        let imported_schema = schema_system.load_schema("imported.isl").unwrap();
+        let other_schema = schema_system.load_schema("other.isl").unwrap();
+        let is_valid = |schema: &Arc<Schema>, value: Element| {
+            schema.get_type("my_type").unwrap().validate(&value).is_ok()
+        };
+        assert!(is_valid(&schema, Element::from(5)));
+
+        // nothing is invalidated until a schema is modified
+        assert!(schema_system.invalidate_modified_schemas().is_empty());
+        assert!(Arc::ptr_eq(
+            &schema,
+            &schema_system.load_schema("sample.isl").unwrap()
+        ));
+
+        // modifying an imported schema invalidates the schemas that imported it
+        authority.set(
+            "imported.isl",
+            "type::{ name: imported_type, type: string }",
+        );
+        assert_eq!(
+            schema_system.invalidate_modified_schemas(),
+            vec!["imported.isl".to_owned(), "sample.isl".to_owned()]
+        );
+        let reloaded_schema = schema_system.load_schema("sample.isl").unwrap();
+        assert!(!Arc::ptr_eq(&schema, &reloaded_schema));
+        assert!(!is_valid(&reloaded_schema, Element::from(5)));
+        assert!(is_valid(&reloaded_schema, Element::from("five")));
+        assert!(!Arc::ptr_eq(
+            &imported_schema,
+            &schema_system.load_schema("imported.isl").unwrap()
+        ));
+        assert!(Arc::ptr_eq(
+            &other_schema,
+            &schema_system.load_schema("other.isl").unwrap()
+        ));
+
+        // an explicitly invalidated schema is reloaded even if it was not modified
+        assert_eq!(
+            schema_system.invalidate_schema("imported.isl"),
+            vec!["imported.isl".to_owned(), "sample.isl".to_owned()]
+        );
+        assert_eq!(
+            schema_system.invalidate_schema("imported.isl"),
+            Vec::<String>::new()
+        );
+        assert!(!Arc::ptr_eq(
+            &reloaded_schema,
+            &schema_system.reload_schema("sample.isl").unwrap()
+        ));
+    }
+
+    #[test]
+    fn invalidate_modified_schemas_without_change_detection() {
+        let authority = Arc::new(MutableDocumentAuthority {
+            ion_content_by_id: Mutex::new(HashMap::new()),
+        });
+        authority.set("sample.isl", "type::{ name: my_type, type: int }");
+        let mut schema_system = SchemaSystem {
+            resolver: Resolver::with_shared_authorities(vec![authority.clone()]),
+        };
+        assert!(!schema_system.is_change_detection_enabled());
+
+        let schema = schema_system.load_schema("sample.isl").unwrap();
+        authority.set("sample.isl", "type::{ name: my_type, type: string }");
+        // the fingerprints are not recorded, hence the modified schema stays cached
+        assert!(schema_system.invalidate_modified_schemas().is_empty());
+        assert!(Arc::ptr_eq(
+            &schema,
+            &schema_system.load_schema("sample.isl").unwrap()
+        ));
+    }
+
+    #[test]
+    fn shared_schema_system_invalidate_schema() {
+        let authority = Arc::new(MutableDocumentAuthority {
+            ion_content_by_id: Mutex::new(HashMap::new()),
+        });
+        authority.set(
+            "sample.isl",
+            r#"
+                schema_header::{ imports: [{ id: "imported.isl", type: imported_type }] }
+                type::{ name: my_type, type: imported_type }
+                schema_footer::{}
+            "#,
+        );
+        authority.set("imported.isl", "type::{ name: imported_type, type: int }");
+        authority.set("other.isl", "type::{ name: other_type, type: int }");
+        let mut schema_system =
+            SharedSchemaSystem::with_shared_authorities(vec![authority.clone()]);
+        schema_system.with_change_detection(true);
+        assert!(schema_system.is_change_detection_enabled());
+
+        let schema = schema_system.load_schema("sample.isl").unwrap();
+        let other_schema = schema_system.load_schema("other.isl").unwrap();
+        let is_valid = |schema: &Arc<Schema>, value: Element| {
+            schema.get_type("my_type").unwrap().validate(&value).is_ok()
+        };
+        assert!(is_valid(&schema, Element::from(5)));
+        assert!(schema_system.invalidate_modified_schemas().is_empty());
+
+        // modifying an imported schema invalidates the schemas that imported it
+        authority.set(
+            "imported.isl",
+            "type::{ name: imported_type, type: string }",
+        );
+        assert_eq!(
+            schema_system.invalidate_modified_schemas(),
+            vec!["imported.isl".to_owned(), "sample.isl".to_owned()]
+        );
+        let reloaded_schema = schema_system.load_schema("sample.isl").unwrap();
+        assert!(!Arc::ptr_eq(&schema, &reloaded_schema));
+        assert!(is_valid(&reloaded_schema, Element::from("five")));
+        assert!(Arc::ptr_eq(
+            &other_schema,
+            &schema_system.load_schema("other.isl").unwrap()
+        ));
+
+        // an explicitly invalidated schema is reloaded even if it was not modified
+        assert_eq!(
+            schema_system.invalidate_schema("imported.isl"),
+            vec!["imported.isl".to_owned(), "sample.isl".to_owned()]
+        );
+        assert_eq!(
+            schema_system.invalidate_schema("imported.isl"),
+            Vec::<String>::new()
+        );
+        assert!(!Arc::ptr_eq(
+            &reloaded_schema,
+            &schema_system.reload_schema("sample.isl").unwrap()
+        ));
+    }
+
+    #[test]
+    fn types_exceeding_the_reserved_type_ids() {
+        // the nested anonymous types of nested.isl don't fit in the range of TypeIds reserved for its single type
+        let nesting = TYPE_IDS_RESERVED_PER_DEFINITION * 2;
+        let nested_type = format!(
+            "type::{{ name: nested_type, {}type: int{} }}",
+            "type: { ".repeat(nesting),
+            " }".repeat(nesting)
+        );
+        let authority = MapDocumentAuthority::new([
+            (
+                "both.isl",
+                r#"
+                    schema_header::{ imports: [{ id: "nested.isl" }, { id: "other.isl" }] }
+                    type::{ name: both_type, any_of: [nested_type, other_type] }
+                    schema_footer::{}
+                "#,
+            ),
+            ("nested.isl", nested_type.as_str()),
+            ("other.isl", "type::{ name: other_type, type: string }"),
+        ]);
+        let schema_system = SharedSchemaSystem::new(vec![Box::new(authority)]);
+
+        // the types of the schemas loaded after nested.isl don't overlap with its types
+        let nested_schema = schema_system.load_schema("nested.isl").unwrap();
+        let other_schema = schema_system.load_schema("other.isl").unwrap();
+        let both_schema = schema_system.load_schema("both.isl").unwrap();
+        assert!(nested_schema.get_type("nested_type").is_some());
+        assert!(other_schema.get_type("other_type").is_some());
+        let both_type = both_schema.get_type("both_type").unwrap();
+        assert!(both_type.validate(&Element::from(1)).is_ok());
+        assert!(both_type.validate(&Element::from("one")).is_ok());
+        assert!(both_type.validate(&Element::from(1.5)).is_err());
+
+        // the diagnostics of the types that were resolved again are only reported once
+        let invalid_schema =
+            format!("{nested_type} type::{{ name: invalid_type, type: missing_type }}");
+        let mut schema_system = SchemaSystem::new(vec![Box::new(MapDocumentAuthority::new([(
+            "invalid.isl",
+            invalid_schema.as_str(),
+        )]))]);
+        assert_eq!(schema_system.check_schema("invalid.isl").len(), 1);
+    }
+
+    #[test]
+    fn file_system_document_authority_read_elements() {
+        use std::fs;
+
+        let base_dir =
+            std::env::temp_dir().join(format!("ion-schema-max-size-{}", std::process::id()));
+        fs::create_dir_all(&base_dir).unwrap();
+        let ion_content = "type::{ name: my_type, type: int }";
+        fs::write(base_dir.join("sample.isl"), ion_content).unwrap();
+        let authority = FileSystemDocumentAuthority::new(&base_dir);
+        let options = ReadOptions::default().with_max_size(ion_content.len());
+        let max_size_result =
+            authority.read_elements("sample.isl", options.with_max_size(ion_content.len() - 1));
+        let result = authority.read_elements("sample.isl", options);
+        let fingerprint_result =
+            authority.read_elements("sample.isl", options.with_fingerprint(true));
+        // the limit of the authority on the file size is reported as an access denied error
+        let max_file_size_result = authority
+            .clone()
+            .with_max_file_size(ion_content.len() as u64 - 1)
+            .read_elements("sample.isl", options);
+        fs::remove_dir_all(&base_dir).unwrap();
+
+        assert!(matches!(
+            max_size_result,
+            Err(IonSchemaError::LimitExceededError { .. })
+        ));
+        let schema_elements = result.unwrap();
+        assert_eq!(schema_elements.elements().len(), 1);
+        assert_eq!(schema_elements.size(), Some(ion_content.len()));
+        // the fingerprint is only taken when it is requested
+        assert_eq!(schema_elements.fingerprint(), None);
+        assert!(fingerprint_result.unwrap().fingerprint().is_some());
+        assert!(matches!(
+            max_file_size_result,
+            Err(IonSchemaError::AccessDeniedError { .. })
+        ));
+    }
+
+    #[test]
+    fn file_system_document_authority_fingerprint() {
+        use std::fs;
+
+        let base_dir =
+            std::env::temp_dir().join(format!("ion-schema-fingerprint-{}", std::process::id()));
+        fs::create_dir_all(&base_dir).unwrap();
+        let authority = FileSystemDocumentAuthority::new(&base_dir);
+        fs::write(
+            base_dir.join("sample.isl"),
+            "type::{ name: my_type, type: int }",
+        )
+        .unwrap();
+        let fingerprint = authority.fingerprint("sample.isl");
+        let same_fingerprint = authority.fingerprint("sample.isl");
+        fs::write(
+            base_dir.join("sample.isl"),
+            "type::{ name: my_type, type: string }",
+        )
+        .unwrap();
+        let modified_fingerprint = authority.fingerprint("sample.isl");
+        fs::remove_dir_all(&base_dir).unwrap();
+
+        assert!(fingerprint.is_some());
+        assert_eq!(fingerprint, same_fingerprint);
+        assert_ne!(fingerprint, modified_fingerprint);
+        assert_eq!(authority.fingerprint("sample.isl"), None);
+    }
+
+    #[test]
+    fn caching_document_authority() {
+        use crate::authority::CachingDocumentAuthority;
+
+        let counting_authority = Arc::new(CountingDocumentAuthority {
+            authority: MapDocumentAuthority::new([
+                (
+                    "sample.isl",
+                    r#"
+                        schema_header::{ imports: [{ id: "imported.isl" }] }
+                        type::{ name: my_type, type: imported_type }
+                        schema_footer::{}
+                    "#,
+                ),
+                ("imported.isl", "type::{ name: imported_type, type: int }"),
+            ]),
+            requests: Mutex::new(HashMap::new()),
+        });
+        let authority = CachingDocumentAuthority::new(counting_authority.clone());
+
+        // the elements are read once and shared by the schema systems
+        for _ in 0..3 {
+            let mut schema_system = SchemaSystem::new(vec![Box::new(authority.clone())]);
+            let schema = schema_system.load_schema("sample.isl").unwrap();
+            assert!(schema.get_type("my_type").is_some());
+        }
+        assert_eq!(authority.len(), 2);
+        assert_eq!(
+            counting_authority
+                .requests
+                .lock()
+                .unwrap()
+                .get("sample.isl"),
+            Some(&1)
+        );
+        assert_eq!(
+            counting_authority
+                .requests
+                .lock()
+                .unwrap()
+                .get("imported.isl"),
+            Some(&1)
+        );
+
+        // errors are not cached
+        assert!(authority.elements("missing.isl").is_err());
+        assert!(authority.elements("missing.isl").is_err());
+        assert_eq!(
+            counting_authority
+                .requests
+                .lock()
+                .unwrap()
+                .get("missing.isl"),
+            Some(&2)
+        );
+
+        // an invalidated schema is read again
+        assert!(authority.invalidate("sample.isl"));
+        assert!(!authority.invalidate("sample.isl"));
+        assert!(authority.elements("sample.isl").is_ok());
+        assert_eq!(
+            counting_authority
+                .requests
+                .lock()
+                .unwrap()
+                .get("sample.isl"),
+            Some(&2)
+        );
+        authority.clear();
+        assert!(authority.is_empty());
+    }
+
+    #[test]
+    fn caching_document_authority_with_capacity() {
+        use crate::authority::CachingDocumentAuthority;
+
+        let authority = CachingDocumentAuthority::with_capacity(
+            Arc::new(MapDocumentAuthority::new([
+                ("a.isl", "type::{ name: a, type: int }"),
+                ("b.isl", "type::{ name: b, type: int }"),
+                ("c.isl", "type::{ name: c, type: int }"),
+            ])),
+            2,
+        );
+        assert!(authority.elements("a.isl").is_ok());
+        assert!(authority.elements("b.isl").is_ok());
+        assert!(authority.elements("a.isl").is_ok());
+        // the least recently used schema is evicted
+        assert!(authority.elements("c.isl").is_ok());
+        assert_eq!(authority.len(), 2);
+        assert!(!authority.invalidate("b.isl"));
+        assert!(authority.invalidate("a.isl"));
+        assert!(authority.invalidate("c.isl"));
+    }
+
+    #[test]
+    fn caching_document_authority_evicts_least_recently_used() {
+        use crate::authority::CachingDocumentAuthority;
+
+        let counting_authority = Arc::new(CountingDocumentAuthority {
+            authority: MapDocumentAuthority::new(
+                ["a.isl", "b.isl", "c.isl", "d.isl", "e.isl"]
+                    .map(|id| (id, "type::{ name: my_type, type: int }")),
+            ),
+            requests: Mutex::new(HashMap::new()),
+        });
+        let authority = CachingDocumentAuthority::with_capacity(counting_authority.clone(), 3);
+        for id in ["a.isl", "b.isl", "c.isl", "a.isl", "d.isl"] {
+            assert!(authority.elements(id).is_ok());
+        }
+        // `b.isl` was evicted, then an entry is removed from the middle of the recency order
+        assert!(!authority.invalidate("b.isl"));
+        assert!(authority.invalidate("c.isl"));
+        for id in ["e.isl", "b.isl", "d.isl", "e.isl", "b.isl"] {
+            assert!(authority.elements(id).is_ok());
+        }
+        // `a.isl` was evicted to make room for `b.isl`, the other schemas are still cached
+        assert_eq!(authority.len(), 3);
+        assert!(!authority.invalidate("a.isl"));
+        assert_eq!(
+            *counting_authority.requests.lock().unwrap(),
+            HashMap::from([
+                ("a.isl".to_owned(), 1),
+                ("b.isl".to_owned(), 2),
+                ("c.isl".to_owned(), 1),
+                ("d.isl".to_owned(), 1),
+                ("e.isl".to_owned(), 1),
+            ])
+        );
+    }
+
+    // An authority that takes the fingerprint of a schema while reading it, and counts the separate fingerprint requests
+    #[derive(Debug)]
+    struct FingerprintOnReadAuthority {
+        authority: MapDocumentAuthority,
+        fingerprint_requests: Mutex<usize>,
+    }
+
+    impl DocumentAuthority for FingerprintOnReadAuthority {
+        fn elements(&self, id: &str) -> IonSchemaResult<Vec<Element>> {
+            self.authority.elements(id)
+        }
+
+        fn fingerprint(&self, _id: &str) -> Option<u64> {
+            *self.fingerprint_requests.lock().unwrap() += 1;
+            Some(1)
+        }
+
+        fn read_elements(&self, id: &str, options: ReadOptions) -> IonSchemaResult<SchemaElements> {
+            let schema_elements = self.authority.read_elements(id, options)?;
+            Ok(schema_elements.with_fingerprint(options.fingerprint().then_some(1)))
+        }
+    }
+
+    #[test]
+    fn caching_document_authority_reads_fingerprint_with_elements() {
+        use crate::authority::CachingDocumentAuthority;
+
+        let fingerprint_authority = Arc::new(FingerprintOnReadAuthority {
+            authority: MapDocumentAuthority::new([(
+                "sample.isl",
+                "type::{ name: my_type, type: int }",
+            )]),
+            fingerprint_requests: Mutex::new(0),
+        });
+        let authority = CachingDocumentAuthority::new(fingerprint_authority.clone());
+        let mut schema_system = SchemaSystem::new(vec![Box::new(authority.clone())]);
+        schema_system.with_change_detection(true);
+
+        assert!(schema_system.load_schema("sample.isl").is_ok());
+        assert_eq!(authority.len(), 1);
+        assert_eq!(
+            *fingerprint_authority.fingerprint_requests.lock().unwrap(),
+            0
+        );
+        // the fingerprint is only requested separately to detect the modified schemas
+        assert!(schema_system.invalidate_modified_schemas().is_empty());
+        assert_eq!(
+            *fingerprint_authority.fingerprint_requests.lock().unwrap(),
+            1
+        );
+        // and to check that the cached elements are still up to date
+        let schema_elements = authority
+            .read_elements("sample.isl", ReadOptions::default().with_fingerprint(true))
+            .unwrap();
+        assert_eq!(schema_elements.fingerprint(), Some(1));
+        assert_eq!(
+            *fingerprint_authority.fingerprint_requests.lock().unwrap(),
+            2
+        );
+        // but not when the schema is loaded without change detection, even with a maximum size
+        let mut schema_system = SchemaSystem::new(vec![Box::new(CachingDocumentAuthority::new(
+            fingerprint_authority.clone(),
+        ))]);
+        schema_system.with_load_limits(LoadLimits::default().with_max_schema_size(1000));
+        assert!(schema_system.load_schema("sample.isl").is_ok());
+        assert!(schema_system.reload_schema("sample.isl").is_ok());
+        assert_eq!(
+            *fingerprint_authority.fingerprint_requests.lock().unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn caching_document_authority_reloads_modified_file() {
+        use crate::authority::CachingDocumentAuthority;
+        use std::fs;
+
+        let base_dir =
+            std::env::temp_dir().join(format!("ion-schema-caching-{}", std::process::id()));
+        fs::create_dir_all(&base_dir).unwrap();
+        fs::write(
+            base_dir.join("sample.isl"),
+            "type::{ name: my_type, type: int }",
+        )
+        .unwrap();
+        let authority =
+            CachingDocumentAuthority::new(Arc::new(FileSystemDocumentAuthority::new(&base_dir)));
+        let mut schema_system = SchemaSystem::new(vec![Box::new(authority.clone())]);
+        schema_system.with_change_detection(true);
+        let schema = schema_system.load_schema("sample.isl").unwrap();
+        let int_result = schema
+            .get_type("my_type")
+            .unwrap()
+            .validate(&Element::from(5));
+
+        // the cached elements of the edited file are not returned by the reload
+        fs::write(
+            base_dir.join("sample.isl"),
+            "type::{ name: my_type, type: string }",
+        )
+        .unwrap();
+        let schema = schema_system.reload_schema("sample.isl");
+        fs::remove_dir_all(&base_dir).unwrap();
+
+        assert!(int_result.is_ok());
+        assert!(schema
+            .unwrap()
+            .get_type("my_type")
+            .unwrap()
+            .validate(&Element::from("five"))
+            .is_ok());
+        assert_eq!(authority.len(), 1);
+    }
+
+    #[test]
+    fn caching_document_authority_checks_cached_size() {
+        use crate::authority::CachingDocumentAuthority;
+
+        let ion_content = "type::{ name: my_type, type: int }";
+        let counting_authority = Arc::new(CountingDocumentAuthority {
+            authority: MapDocumentAuthority::new([("sample.isl", ion_content)]),
+            requests: Mutex::new(HashMap::new()),
+        });
+        let authority = CachingDocumentAuthority::new(counting_authority.clone());
+        let mut schema_system = SchemaSystem::new(vec![Box::new(authority.clone())]);
+        assert!(schema_system.load_schema("sample.isl").is_ok());
+
+        // the schema cached by a schema system without limits is still too large for the other schema systems
+        for (max_size, is_ok) in [(ion_content.len() - 1, false), (ion_content.len(), true)] {
+            let mut schema_system = SchemaSystem::new(vec![Box::new(authority.clone())]);
+            schema_system.with_load_limits(LoadLimits::default().with_max_schema_size(max_size));
+            let result = schema_system.load_schema("sample.isl");
+            assert_eq!(result.is_ok(), is_ok, "{result:?}");
+            if let Err(error) = result {
+                assert!(matches!(error, IonSchemaError::LimitExceededError { .. }));
+            }
+        }
+        assert_eq!(
+            counting_authority
+                .requests
+                .lock()
+                .unwrap()
+                .get("sample.isl"),
+            Some(&1)
+        );
+    }
+
+    #[test]
+    fn caching_document_authority_drops_modified_schemas() {
+        use crate::authority::CachingDocumentAuthority;
+
+        let mutable_authority = Arc::new(MutableDocumentAuthority {
+            ion_content_by_id: Mutex::new(HashMap::new()),
+        });
+        mutable_authority.set("sample.isl", "type::{ name: my_type, type: int }");
+        let mut schema_system = SchemaSystem::new(vec![Box::new(CachingDocumentAuthority::new(
+            mutable_authority.clone(),
+        ))]);
+        schema_system.with_change_detection(true);
+        let schema = schema_system.load_schema("sample.isl").unwrap();
+        assert!(schema
+            .get_type("my_type")
+            .unwrap()
+            .validate(&Element::from(5))
+            .is_ok());
+
+        mutable_authority.set("sample.isl", "type::{ name: my_type, type: string }");
+        assert_eq!(
+            schema_system.invalidate_modified_schemas(),
+            vec!["sample.isl".to_owned()]
+        );
+        let schema = schema_system.load_schema("sample.isl").unwrap();
+        assert!(schema
+            .get_type("my_type")
+            .unwrap()
+            .validate(&Element::from("five"))
+            .is_ok());
+    }
+
+    #[test]
+    fn routing_document_authority() {
+        use crate::authority::RoutingDocumentAuthority;
+
+        let authority = RoutingDocumentAuthority::new()
+            .with_route(
+                "com.acme/",
+                Arc::new(MapDocumentAuthority::new([(
+                    "sample.isl",
+                    r#"
+                        schema_header::{ imports: [{ id: "vendor/numbers/positive.isl", type: positive_int }] }
+                        type::{ name: my_type, type: list, element: positive_int }
+                        schema_footer::{}
+                    "#,
+                )])),
+            )
+            .with_rewrite(
+                "vendor/",
+                "v1/",
+                Arc::new(MapDocumentAuthority::new([(
+                    "v1/numbers/positive.isl",
+                    "type::{ name: positive_int, type: int, valid_values: range::[1, max] }",
+                )])),
+            )
+            // the longest matching prefix wins
+            .with_route(
+                "vendor/legacy/",
+                Arc::new(MapDocumentAuthority::new([(
+                    "negative.isl",
+                    "type::{ name: negative_int, type: int, valid_values: range::[min, -1] }",
+                )])),
+            );
+        assert_eq!(
+            authority.prefixes().collect::<Vec<_>>(),
+            vec!["com.acme/", "vendor/", "vendor/legacy/"]
+        );
+        let mut schema_system = SchemaSystem::new(vec![Box::new(authority)]);
+
+        let schema = schema_system.load_schema("com.acme/sample.isl").unwrap();
+        let my_type = schema.get_type("my_type").unwrap();
+        assert!(my_type
+            .validate(&Element::read_one("[1, 2]").unwrap())
+            .is_ok());
+        assert!(my_type
+            .validate(&Element::read_one("[0]").unwrap())
+            .is_err());
+        assert!(schema_system
+            .load_schema("vendor/legacy/negative.isl")
+            .is_ok());
+
+        // the routed authority doesn't resolve the ids of other routes
+        assert!(schema_system.load_schema("vendor/positive.isl").is_err());
+        assert_eq!(
+            schema_system
+                .load_schema("sample.isl")
+                .unwrap_err()
+                .to_string(),
+            r#"RoutingDocumentAuthority has no route for schema id: sample.isl, expected an id starting with one of ["com.acme/", "vendor/", "vendor/legacy/"]"#
+        );
+    }
+
+    #[test]
+    fn sandboxed_file_system_document_authority() {
+        use std::fs;
+
+        let temp_dir =
+            std::env::temp_dir().join(format!("ion-schema-sandbox-{}", std::process::id()));
+        let base_dir = temp_dir.join("schemas");
+        fs::create_dir_all(base_dir.join("nested")).unwrap();
+        fs::create_dir_all(temp_dir.join("outside")).unwrap();
+        fs::write(
+            base_dir.join("sample.isl"),
+            "type::{ name: my_type, type: int }",
+        )
+        .unwrap();
+        fs::write(
+            base_dir.join("importing.isl"),
+            r#"schema_header::{ imports: [{ id: "../outside/secret.isl" }] } schema_footer::{}"#,
+        )
+        .unwrap();
+        fs::write(
+            base_dir.join("notes.txt"),
+            "type::{ name: my_type, type: int }",
+        )
+        .unwrap();
+        fs::write(
+            base_dir.join("big.isl"),
+            format!(
+                "type::{{ name: my_type, type: int, doc: \"{}\" }}",
+                "a".repeat(100)
+            ),
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.join("outside/secret.isl"),
+            "type::{ name: secret, type: int }",
+        )
+        .unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(
+            temp_dir.join("outside/secret.isl"),
+            base_dir.join("link.isl"),
+        )
+        .unwrap();
+
+        let authority = FileSystemDocumentAuthority::sandboxed(&base_dir)
+            .unwrap()
+            .with_allowed_extensions(["isl"])
+            .with_max_file_size(100);
+        assert!(authority.is_sandboxed());
+        let absolute_id = temp_dir.join("outside/secret.isl");
+        let mut cases = vec![
+            ("sample.isl", Ok(())),
+            ("./nested/../sample.isl", Ok(())),
+            ("missing.isl", Err("NotFound")),
+            (
+                "../outside/secret.isl",
+                Err("outside of the base directory"),
+            ),
+            (
+                "nested/../../outside/secret.isl",
+                Err("outside of the base directory"),
+            ),
+            (
+                absolute_id.to_str().unwrap(),
+                Err("outside of the base directory"),
+            ),
+            ("notes.txt", Err("allowed extensions")),
+            ("big.isl", Err("larger than the maximum size")),
+        ];
+        if cfg!(unix) {
+            cases.push((
+                "link.isl",
+                Err("link to a file outside of the base directory"),
+            ));
+        }
+        for (id, expected) in cases {
+            match (authority.elements(id), expected) {
+                (Ok(_), Ok(())) => {}
+                (Err(IonSchemaError::IoError { source }), Err(expected)) => {
+                    assert_eq!(format!("{:?}", source.kind()), expected, "{id}")
+                }
+                (Err(error @ IonSchemaError::AccessDeniedError { .. }), Err(expected)) => {
+                    assert!(error.to_string().contains(expected), "{id}: {error}")
+                }
+                (result, expected) => panic!("{id}: expected {expected:?}, found {result:?}"),
+            }
+        }
+
+        // the error is reported in the schema that imports the rejected schema
+        let mut schema_system = SchemaSystem::new(vec![Box::new(authority)]);
+        let error = schema_system.load_schema("importing.isl").unwrap_err();
+        fs::remove_dir_all(&temp_dir).unwrap();
+        assert!(matches!(error, IonSchemaError::AccessDeniedError { .. }));
+        assert_eq!(
+            error.location().and_then(|location| location.schema_id()),
+            Some("importing.isl")
+        );
+        // without sandbox, the base path doesn't have to exist and ids are simply joined to it
+        assert!(!FileSystemDocumentAuthority::new(&base_dir).is_sandboxed());
+        assert!(FileSystemDocumentAuthority::sandboxed(&base_dir).is_err());
+    }
+
+    #[rstest(
+        load_limits, schema, expected_error,
+        case::within_limits(
+            LoadLimits::default().with_max_types(3).with_max_nesting_depth(4).with_max_regex_size(3).with_max_imports(1).with_max_total_elements(11).with_max_schema_size(1000),
+            r#"
+                schema_header::{ imports: [{ id: "imported.isl" }] }
+                type::{ name: my_type, type: list, element: { type: string, regex: "a.c" } }
+                schema_footer::{}
+            "#,
+            None
+        ),
+        case::max_types(
+            LoadLimits::default().with_max_types(2),
+            r#"
+                type::{ name: a, type: int }
+                type::{ name: b, type: list, element: { type: int } }
+            "#,
+            Some("Schema exceeds the maximum number of types of 2 (schema: sample.isl, type: b, line: 3, column: 17)")
+        ),
+        case::max_types_excludes_aliased_imports(
+            LoadLimits::default().with_max_types(1),
+            r#"
+                schema_header::{ imports: [{ id: "imported.isl", type: imported_type, as: aliased_type }] }
+                type::{ name: my_type, type: aliased_type }
+                schema_footer::{}
+            "#,
+            None
+        ),
+        case::max_nesting_depth(
+            LoadLimits::default().with_max_nesting_depth(3),
+            "type::{ name: my_type, element: { element: { type: int } } }",
+            Some("Schema exceeds the maximum nesting depth of 3 (schema: sample.isl, type: my_type, line: 1, column: 1)")
+        ),
+        case::max_regex_size(
+            LoadLimits::default().with_max_regex_size(3),
+            r#"type::{ name: my_type, type: string, regex: "abcd" }"#,
+            Some("Schema exceeds the maximum regex size of 3 (schema: sample.isl, type: my_type, line: 1, column: 1)")
+        ),
+        case::max_imports(
+            LoadLimits::default().with_max_imports(1),
+            r#"
+                schema_header::{ imports: [{ id: "imported.isl" }] }
+                type::{ name: my_type, type: { id: "imported.isl", type: imported_type } }
+                schema_footer::{}
+            "#,
+            Some("Schema exceeds the maximum number of imports of 1 (schema: sample.isl)")
+        ),
+        case::max_total_elements(
+            LoadLimits::default().with_max_total_elements(10),
+            "type::{ name: my_type, type: int, valid_values: [1, 2, 3, 4, 5, 6, 7, 8, 9] }",
+            Some("Schema exceeds the maximum number of values of 10 (schema: sample.isl, type: my_type, line: 1, column: 1)")
+        ),
+        case::max_schema_size(
+            LoadLimits::default().with_max_schema_size(30),
+            "type::{ name: my_type, type: int }",
+            Some("Schema sample.isl exceeds the maximum size of 30 bytes")
+        ),
+    )]
+    fn schema_system_load_limits(
+        load_limits: LoadLimits,
+        schema: &str,
+        expected_error: Option<&str>,
+    ) {
+        let mut schema_system = SchemaSystem::new(vec![Box::new(MapDocumentAuthority::new([
+            ("sample.isl", schema),
+            ("imported.isl", "type::{ name: imported_type, type: int }"),
+        ]))]);
+        schema_system.with_load_limits(load_limits);
+        assert_eq!(schema_system.load_limits(), load_limits);
+        match (schema_system.load_schema("sample.isl"), expected_error) {
+            (Ok(_), None) => {}
+            (Err(error @ IonSchemaError::LimitExceededError { .. }), Some(expected_error)) => {
+                assert_eq!(error.to_string(), expected_error)
+            }
+            (result, expected_error) => {
+                panic!("expected {expected_error:?} but found {result:?}")
+            }
+        }
+    }
+
+    #[test]
+    fn schema_system_load_limits_while_reading() {
+        // a schema nested too deeply to be parsed recursively without exhausting the stack
+        let depth = 100_000;
+        let nested_schema = format!(
+            "type::{{ name: my_type, valid_values: [{}{}] }}",
+            "[".repeat(depth),
+            "]".repeat(depth)
+        );
+        let load_limits = LoadLimits::default()
+            .with_max_nesting_depth(8)
+            .with_max_schema_size(depth * 3);
+        let mut schema_system = SchemaSystem::new(vec![Box::new(MapDocumentAuthority::new([(
+            "sample.isl",
+            nested_schema.as_str(),
+        )]))]);
+        schema_system.with_load_limits(load_limits);
+
+        let expected_error = "Schema sample.isl exceeds the maximum nesting depth of 8";
+        for result in [
+            schema_system.load_schema("sample.isl").map(|_| ()),
+            schema_system.load_isl_schema("sample.isl").map(|_| ()),
+            schema_system
+                .new_schema(nested_schema.as_bytes(), "sample.isl")
+                .map(|_| ()),
+            schema_system
+                .new_isl_schema(nested_schema.as_bytes(), "sample.isl")
+                .map(|_| ()),
+        ] {
+            assert_eq!(result.unwrap_err().to_string(), expected_error);
+        }
+
+        // the schema content given to the schema system is limited like the content of the authorities
+        schema_system.with_load_limits(LoadLimits::default().with_max_schema_size(30));
+        let schema = "type::{ name: my_type, type: int }".as_bytes();
+        let expected_error = "Schema sample.isl exceeds the maximum size of 30 bytes";
+        assert_eq!(
+            schema_system
+                .new_schema(schema, "sample.isl")
+                .unwrap_err()
+                .to_string(),
+            expected_error
+        );
+        assert_eq!(
+            schema_system
+                .new_isl_schema(schema, "sample.isl")
+                .unwrap_err()
+                .to_string(),
+            expected_error
+        );
+    }
+
+    #[rstest(
+        schema, expected_schema_id, expected_type_name, expected_constraint, expected_line_column,
+        case::invalid_constraint(
+            r#"
+                type::{
+                    name: my_type,
+                    codepoint_length: a,
+                }
+            "#,
+            "sample.isl",
+            Some("my_type"),
+            Some("codepoint_length"),
+            Some((4, 21))
+        ),
+        case::unresolvable_type_reference(
+            r#"
+                type::{ name: my_int, type: int }
+                type::{
+                    element: my_int,
+                    name: my_list,
+                    type: unknown_type,
+                }
+            "#,
+            "sample.isl",
+            Some("my_list"),
+            None,
+            Some((3, 17))
+        ),
+        case::constraint_of_anonymous_type(
+            r#"
+                type::{
+                    name: my_struct,
+                    fields: {
+                        a: int,
+                        b: { type: blob, byte_length: ten },
+                    },
+                }
+            "#,
+            "sample.isl",
+            Some("my_struct"),
+            Some("byte_length"),
+            Some((6, 42))
+        ),
+        case::imported_schema(
+            r#"
+                schema_header::{ imports: [{ id: "imported.isl" }] }
+                type::{ name: my_int, type: int }
+                schema_footer::{}
+            "#,
+            "imported.isl",
+            Some("imported_type"),
+            Some("regex"),
+            Some((4, 17))
+        ),
+        case::field_named_like_a_later_type(
+            r#"
+                type::{
+                    name: my_struct,
+                    fields: { name: my_list },
+                }
+                type::{
+                    name: my_list,
+                    type: unknown_type,
+                }
+            "#,
+            "sample.isl",
+            Some("my_list"),
+            None,
+            Some((6, 17))
+        ),
+        case::type_name_in_comments_and_strings(
+            r#"
+                // type::{ name: my_type, codepoint_length: a }
+                /* type::{ name: my_type } */
+                type::{ name: my_string, type: string, regex: "name: my_type" }
+                type::{
+                    'name': my_type,
+                    codepoint_length: a,
+                }
+            "#,
+            "sample.isl",
+            Some("my_type"),
+            Some("codepoint_length"),
+            Some((7, 21))
+        ),
+        case::ambiguous_constraint(
+            r#"
+                type::{
+                    name: my_struct,
+                    fields: {
+                        codepoint_length: { codepoint_length: a },
+                    },
+                }
+            "#,
+            "sample.isl",
+            Some("my_struct"),
+            Some("codepoint_length"),
+            Some((2, 17))
+        ),
+        case::missing_import(
+            r#"
+                schema_header::{ imports: [{ id: "missing.isl" }] }
+                schema_footer::{}
+            "#,
+            "sample.isl",
+            None,
+            None,
+            None
+        ),
+    )]
+    fn schema_error_location(
+        schema: &str,
+        expected_schema_id: &str,
+        expected_type_name: Option<&str>,
+        expected_constraint: Option<&str>,
+        expected_line_column: Option<(usize, usize)>,
+    ) {
+        let imported_schema = r#"
+            type::{
+                name: imported_type,
+                regex: 1,
+            }
+        "#;
+        let map_authority = [("sample.isl", schema), ("imported.isl", imported_schema)];
+        let mut schema_system =
+            SchemaSystem::new(vec![Box::new(MapDocumentAuthority::new(map_authority))]);
+        let error = schema_system.load_schema("sample.isl").unwrap_err();
+        let location = error.location().unwrap();
+        assert_eq!(location.schema_id(), Some(expected_schema_id));
+        assert_eq!(location.type_name(), expected_type_name);
+        assert_eq!(location.constraint(), expected_constraint);
+        assert_eq!(location.line().zip(location.column()), expected_line_column);
+    }
+
+    #[rstest(
+        schema, expected_diagnostics,
+        case::valid_schema(
+            r#"
+                schema_header::{ imports: [{ id: "imported.isl", type: imported_int }] }
+                type::{ name: my_int, type: imported_int }
+                schema_footer::{}
+            "#,
+            vec![]
+        ),
+        case::multiple_errors(
+            r#"
+                type::{ name: my_string, type: string, codepoint_length: a }
+                type::{ name: my_int, type: int, valid_values: b, occurs: optional }
+                type::{ type: int }
+                type::{ name: my_list, type: list, element: unknown_type }
+            "#,
+            vec![
+                (DiagnosticSeverity::Error, "sample.isl", Some("my_string"), Some("codepoint_length")),
+                (DiagnosticSeverity::Error, "sample.isl", Some("my_int"), Some("valid_values")),
+                (DiagnosticSeverity::Error, "sample.isl", Some("my_int"), Some("occurs")),
+                (DiagnosticSeverity::Error, "sample.isl", None, None),
+                (DiagnosticSeverity::Error, "sample.isl", Some("my_list"), None),
+            ]
+        ),
+        case::warnings(
+            r#"
+                type::{ name: my_string, type: string, codepoint_lenght: range::[1, 10] }
+                type::{ name: my_string, type: string }
+            "#,
+            vec![
+                (DiagnosticSeverity::Warning, "sample.isl", Some("my_string"), Some("codepoint_lenght")),
+                (DiagnosticSeverity::Warning, "sample.isl", Some("my_string"), None),
+            ]
+        ),
+        case::header_and_imports(
+            r#"
+                schema_header::{ imports: [{ id: "invalid.isl" }, { id: "missing.isl" }, { type: my_int }] }
+                type::{ name: my_int, type: int }
+            "#,
+            vec![
+                (DiagnosticSeverity::Error, "sample.isl", None, None),
+                (DiagnosticSeverity::Error, "sample.isl", None, None),
+                (DiagnosticSeverity::Error, "invalid.isl", Some("invalid_type"), Some("regex")),
+                (DiagnosticSeverity::Error, "invalid.isl", Some("invalid_int"), None),
+                (DiagnosticSeverity::Error, "sample.isl", None, None),
+            ]
+        ),
+    )]
+    fn check_schema(
+        schema: &str,
+        expected_diagnostics: Vec<(DiagnosticSeverity, &str, Option<&str>, Option<&str>)>,
+    ) {
+        let imported_schema = r#"
+            type::{ name: imported_int, type: int }
+        "#;
+        let invalid_schema = r#"
+            type::{ name: invalid_type, regex: 1 }
+            type::{ name: invalid_int, type: unknown_int }
+        "#;
+        let map_authority = [
+            ("sample.isl", schema),
+            ("imported.isl", imported_schema),
+            ("invalid.isl", invalid_schema),
+        ];
+        let mut schema_system =
+            SchemaSystem::new(vec![Box::new(MapDocumentAuthority::new(map_authority))]);
+        let diagnostics: Vec<_> = schema_system
+            .check_schema("sample.isl")
+            .iter()
+            .map(|diagnostic| {
+                let location = diagnostic.location().unwrap();
+                (
+                    diagnostic.severity(),
+                    location.schema_id().unwrap().to_owned(),
+                    location.type_name().map(|name| name.to_owned()),
+                    location.constraint().map(|name| name.to_owned()),
+                )
+            })
+            .collect();
+        let expected_diagnostics: Vec<_> = expected_diagnostics
+            .into_iter()
+            .map(|(severity, schema_id, type_name, constraint)| {
+                (
+                    severity,
+                    schema_id.to_owned(),
+                    type_name.map(|name| name.to_owned()),
+                    constraint.map(|name| name.to_owned()),
+                )
+            })
+            .collect();
+        assert_eq!(diagnostics, expected_diagnostics);
+        // a schema with errors can not be loaded and a schema without errors can be loaded
+        let has_errors = expected_diagnostics
+            .iter()
+            .any(|(severity, _, _, _)| *severity == DiagnosticSeverity::Error);
+        assert_eq!(schema_system.load_schema("sample.isl").is_err(), has_errors);
+    }
+
+    #[test]
+    fn dependency_graph() {
+        let map_authority = [
+            (
+                "a.isl",
+                r#"
+                    schema_header::{ imports: [{ id: "b.isl" }, { id: "c.isl", type: c_int }] }
+                    type::{ name: a_type, one_of: [b_type, c_int, { id: "c.isl", type: c_string }] }
+                    schema_footer::{}
+                "#,
+            ),
+            (
+                "b.isl",
+                r#"
+                    schema_header::{ imports: [{ id: "c.isl", type: c_int, as: "b \"int\"" }] }
+                    type::{ name: b_type, type: list }
+                    schema_footer::{}
+                "#,
+            ),
+            (
+                "c.isl",
+                r#"
+                    type::{ name: c_int, type: int }
+                    type::{ name: c_string, type: string }
+                    type::{ name: c_list, type: list, element: { id: "a.isl", type: a_type } }
+                "#,
+            ),
+            (
+                "missing_import.isl",
+                r#"
+                    schema_header::{ imports: [{ id: "a.isl" }, { id: "missing.isl" }] }
+                    schema_footer::{}
+                "#,
+            ),
+        ];
+        let mut schema_system =
+            SchemaSystem::new(vec![Box::new(MapDocumentAuthority::new(map_authority))]);
+
+        let dependency_graph = schema_system.dependency_graph("a.isl").unwrap();
+        assert_eq!(dependency_graph.root_schema_id(), "a.isl");
+        assert_eq!(dependency_graph.schema_ids(), ["a.isl", "b.isl", "c.isl"]);
+        let imported_schema_ids: Vec<&str> = dependency_graph
+            .dependencies_of("a.isl")
+            .map(|dependency| dependency.imported_schema_id())
+            .collect();
+        assert_eq!(imported_schema_ids, vec!["b.isl", "c.isl"]);
+        assert_eq!(
+            dependency_graph.to_dot(),
+            r#"digraph "a.isl" {
+    "a.isl";
+    "b.isl";
+    "c.isl";
+    "a.isl" -> "b.isl" [label="*"];
+    "a.isl" -> "c.isl" [label="c_int\nc_string"];
+    "b.isl" -> "c.isl" [label="c_int as b \"int\""];
+    "c.isl" -> "a.isl" [label="a_type"];
+}
+"#
+        );
+
+        assert!(schema_system
+            .dependency_graph("missing_import.isl")
+            .is_err());
+    }
+
+    #[rstest(
+        id,
+        expected_cycle,
+        expected_schema_id,
+        case::self_import("self.isl", "self.isl -> self.isl", "self.isl"),
+        case::two_schemas("a.isl", "a.isl -> b.isl -> a.isl", "b.isl"),
+        case::three_schemas("c.isl", "c.isl -> a.isl -> b.isl -> a.isl", "b.isl"),
+        case::inline_import("inline.isl", "inline.isl -> d.isl -> inline.isl", "d.isl")
+    )]
+    fn import_cycle(id: &str, expected_cycle: &str, expected_schema_id: &str) {
+        let map_authority = [
+            (
+                "self.isl",
+                r#"
+                    schema_header::{ imports: [{ id: "self.isl" }] }
+                    type::{ name: self_type, type: int }
+                    schema_footer::{}
+                "#,
+            ),
+            (
+                "a.isl",
+                r#"
+                    schema_header::{ imports: [{ id: "b.isl" }] }
+                    type::{ name: a_type, type: int }
+                    schema_footer::{}
+                "#,
+            ),
+            (
+                "b.isl",
+                r#"
+                    schema_header::{ imports: [{ id: "a.isl", type: a_type }] }
+                    type::{ name: b_type, type: a_type }
+                    schema_footer::{}
+                "#,
+            ),
+            (
+                "c.isl",
+                r#"
+                    schema_header::{ imports: [{ id: "a.isl" }] }
+                    type::{ name: c_type, type: int }
+                    schema_footer::{}
+                "#,
+            ),
+            (
+                "inline.isl",
+                r#"type::{ name: inline_type, type: { id: "d.isl", type: d_type } }"#,
+            ),
+            (
+                "d.isl",
+                r#"type::{ name: d_type, type: { id: "inline.isl", type: inline_type } }"#,
+            ),
+        ];
+        let mut schema_system =
+            SchemaSystem::new(vec![Box::new(MapDocumentAuthority::new(map_authority))]);
+        let error = schema_system.load_schema(id).unwrap_err();
+        assert!(matches!(
+            error,
+            IonSchemaError::UnresolvableSchemaError { .. }
+        ));
+        assert!(
+            error.to_string().contains(expected_cycle),
+            "{error} doesn't contain {expected_cycle}"
+        );
+        assert_eq!(
+            error.location().unwrap().schema_id(),
+            Some(expected_schema_id)
+        );
+
+        // the schemas of the cycle are not left in a loading state
+        assert_eq!(
+            schema_system.load_schema(id).unwrap_err().to_string(),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn validate_by_type_name() {
+        let map_authority = [
+            (
+                "sample.isl",
+                r#"
+                    schema_header::{ imports: [{ id: "numbers.isl", type: positive_int }] }
+                    type::{ name: my_int, type: positive_int }
+                    schema_footer::{}
+                "#,
+            ),
+            (
+                "numbers.isl",
+                "type::{ name: positive_int, type: int, valid_values: range::[1, max] }",
+            ),
+            ("invalid.isl", "type::{ name: my_int, type: unknown_type }"),
+            (
+                "missing_import.isl",
+                r#"type::{ name: my_int, type: { id: "missing.isl", type: int } }"#,
+            ),
+        ];
+        let mut schema_system =
+            SchemaSystem::new(vec![Box::new(MapDocumentAuthority::new(map_authority))]);
+
+        assert_eq!(
+            schema_system.validate("sample.isl", "my_int", &Element::from(5)),
+            Ok(())
+        );
+        // built-in types can be looked up too
+        assert_eq!(
+            schema_system.validate("sample.isl", "int", &Element::from(-5)),
+            Ok(())
+        );
+        let error = schema_system
+            .validate("sample.isl", "my_int", &Element::from(-5))
+            .unwrap_err();
+        assert_eq!(
+            error.violation().map(|violation| violation.code()),
+            Some(&ViolationCode::TypeConstraintsUnsatisfied)
+        );
+        assert_eq!(
+            schema_system.validate("sample.isl", "my_itn", &Element::from(5)),
+            Err(ValidationError::TypeNotFound {
+                schema_id: "sample.isl".to_owned(),
+                type_name: "my_itn".to_owned()
+            })
+        );
+        assert_eq!(
+            schema_system.validate("simple.isl", "my_int", &Element::from(5)),
+            Err(ValidationError::SchemaNotFound {
+                schema_id: "simple.isl".to_owned()
+            })
+        );
+        // a schema that can't be loaded is told apart from a schema that can't be found,
+        // even when it fails because one of its imports can't be found
+        for schema_id in ["invalid.isl", "missing_import.isl"] {
+            let error = schema_system
+                .validate(schema_id, "my_int", &Element::from(5))
+                .unwrap_err();
+            assert!(
+                matches!(error, ValidationError::SchemaError { .. }),
+                "{schema_id}: {error:?}"
+            );
+        }
+        // the import that can't be found is reported with the location of the import
+        match schema_system.validate("missing_import.isl", "my_int", &Element::from(5)) {
+            Err(ValidationError::SchemaError {
+                source: error @ IonSchemaError::SchemaNotFoundError { .. },
+            }) => {
+                assert_eq!(
+                    error.to_string(),
+                    "Unable to load schema: missing.isl (schema: missing_import.isl)"
+                );
+            }
+            result => panic!("expected a missing import, found: {result:?}"),
+        }
+
+        assert_eq!(
+            schema_system.validate_qualified("sample.isl#my_int", &Element::from(5)),
+            Ok(())
+        );
+        assert!(schema_system
+            .validate_qualified("sample.isl#my_int", &Element::from(-5))
+            .unwrap_err()
+            .violation()
+            .is_some());
+        for name in ["sample.isl", "sample.isl#", "#my_int"] {
+            assert_eq!(
+                schema_system.validate_qualified(name, &Element::from(5)),
+                Err(ValidationError::InvalidTypeName {
+                    name: name.to_owned()
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn validate_by_type_name_of_unresolvable_schema() {
+        use crate::authority::RoutingDocumentAuthority;
+
+        let routing_authority = RoutingDocumentAuthority::new().with_route(
+            "vendor/",
+            Arc::new(MapDocumentAuthority::new([(
+                "sample.isl",
+                "type::{ name: my_int, type: int }",
+            )])),
+        );
+        let mut schema_system = SchemaSystem::new(vec![
+            Box::new(MapDocumentAuthority::new([(
+                "first.isl",
+                "type::{ name: my_int, type: int }",
+            )])),
+            Box::new(MapDocumentAuthority::new([(
+                "second.isl",
+                "type::{ name: my_int, type: int }",
+            )])),
+            Box::new(routing_authority),
+        ]);
+
+        // a schema that the first authority doesn't contain is resolved by the next ones
+        for schema_id in ["first.isl", "second.isl", "vendor/sample.isl"] {
+            assert_eq!(
+                schema_system.validate(schema_id, "my_int", &Element::from(5)),
+                Ok(())
+            );
+        }
+        assert_eq!(
+            schema_system.validate("vendor/missing.isl", "my_int", &Element::from(5)),
+            Err(ValidationError::SchemaNotFound {
+                schema_id: "vendor/missing.isl".to_owned()
+            })
+        );
+        // an id that the routing authority refuses is an error of that authority, not a missing schema
+        let error = schema_system
+            .validate("unrouted.isl", "my_int", &Element::from(5))
+            .unwrap_err();
+        assert!(
+            matches!(
+                error,
+                ValidationError::SchemaError {
+                    source: IonSchemaError::UnresolvableSchemaError { .. }
+                }
+            ),
+            "{error:?}"
+        );
+    }
+
+    #[test]
+    fn validate_by_type_name_with_options() {
+        let mut schema_system = SchemaSystem::new(vec![Box::new(MapDocumentAuthority::new([(
+            "sample.isl",
+            "type::{ name: nested_list, type: list, element: nested_list }",
+        )]))]);
+        let value = Element::read_one(format!("{}{}", "[".repeat(10), "]".repeat(10))).unwrap();
+
+        for options in [
+            ValidationOptions::default(),
+            ValidationOptions::default().with_max_depth(10),
+        ] {
+            assert_eq!(
+                schema_system.validate_with("sample.isl", "nested_list", &value, options),
+                Ok(())
+            );
+        }
+        let options = ValidationOptions::default().with_max_depth(5);
+        let error = schema_system
+            .validate_with("sample.isl", "nested_list", &value, options)
+            .unwrap_err();
+        assert_eq!(
+            error.violation().map(|violation| violation.code()),
+            Some(&ViolationCode::ValidationLimitExceeded)
+        );
+        let error = schema_system
+            .validate_qualified_with("sample.isl#nested_list", &value, options)
+            .unwrap_err();
+        assert_eq!(
+            error.violation().map(|violation| violation.code()),
+            Some(&ViolationCode::ValidationLimitExceeded)
+        );
+    }
+
+    #[test]
+    fn validate_by_type_name_after_invalidation() {
+        let authority = Arc::new(MutableDocumentAuthority {
+            ion_content_by_id: Mutex::new(HashMap::new()),
+        });
+        authority.set("sample.isl", "type::{ name: my_type, type: int }");
+        let mut schema_system = SchemaSystem {
+            resolver: Resolver::with_shared_authorities(vec![authority.clone()]),
+        };
+        schema_system.with_change_detection(true);
+
+        assert_eq!(
+            schema_system.validate("sample.isl", "my_type", &Element::from(5)),
+            Ok(())
+        );
+        assert_eq!(
+            schema_system.resolver.type_definition_cache["sample.isl"].len(),
+            1
+        );
+
+        // the cached type definitions are dropped along with their schema
+        authority.set("sample.isl", "type::{ name: my_type, type: string }");
+        assert_eq!(
+            schema_system.invalidate_modified_schemas(),
+            vec!["sample.isl".to_owned()]
+        );
+        assert!(schema_system.resolver.type_definition_cache.is_empty());
+        assert!(schema_system
+            .validate("sample.isl", "my_type", &Element::from(5))
+            .is_err());
+        assert_eq!(
+            schema_system.validate("sample.isl", "my_type", &Element::from("five")),
+            Ok(())
+        );
+    }
 }
@@ -1,10 +1,12 @@
+//! Provides the [`TypeReference`]s used by the resolved constraints to refer to other type definitions.
+
 use crate::ion_path::IonPath;
 use crate::isl::isl_range::Range;
 use crate::isl::isl_type_reference::NullabilityModifier;
 use crate::result::ValidationResult;
 use crate::system::{TypeId, TypeStore};
-use crate::types::TypeValidator;
-use crate::IonSchemaElement;
+use crate::types::{TypeValidator, ValidationContext};
+use crate::{IonSchemaElement, IonSchemaElementRef};
 use ion_rs::IonType;
 
 /// Provides reference to a type definition.
@@ -26,17 +28,48 @@ impl TypeReference {
     pub fn type_id(&self) -> TypeId {
         self.type_id
     }
+
+    /// Provides the nullability modifier of this type reference, i.e. `$null_or::` or `nullable::` annotations
+    pub fn nullability_modifier(&self) -> NullabilityModifier {
+        self.type_modifier
+    }
 }
 
 impl TypeValidator for TypeReference {
     fn is_valid(
         &self,
-        value: &IonSchemaElement,
+        value: IonSchemaElementRef,
         type_store: &TypeStore,
-        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> bool {
-        let violation = self.validate(value, type_store, ion_path);
-        violation.is_ok()
+        use crate::isl::isl_type_reference::NullabilityModifier::*;
+        let type_def = type_store.get_type_by_id(self.type_id()).unwrap();
+        match self.type_modifier {
+            Nullable => {
+                if let IonSchemaElementRef::SingleElement(element) = value {
+                    if element.is_null()
+                        && (element.ion_type() == IonType::Null
+                            || type_def.is_valid_for_base_nullable_type(value, type_store, context))
+                    {
+                        return true;
+                    }
+                }
+            }
+            NullOr => {
+                if let IonSchemaElementRef::SingleElement(element) = value {
+                    if element.ion_type() == IonType::Null {
+                        return true;
+                    }
+                }
+            }
+            Nothing => {}
+        }
+        if context.enter(&mut IonPath::default()).is_err() {
+            return false;
+        }
+        let is_valid = type_def.is_valid(value, type_store, context);
+        context.exit();
+        is_valid
     }
 
     fn validate(
@@ -44,6 +77,7 @@ impl TypeValidator for TypeReference {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         use crate::isl::isl_type_reference::NullabilityModifier::*;
         let type_def = type_store.get_type_by_id(self.type_id()).unwrap();
@@ -52,8 +86,11 @@ impl TypeValidator for TypeReference {
                 IonSchemaElement::SingleElement(element) => {
                     if element.is_null()
                         && (element.ion_type() == IonType::Null
-                            || type_def
-                                .is_valid_for_base_nullable_type(value, type_store, ion_path))
+                            || type_def.is_valid_for_base_nullable_type(
+                                value.into(),
+                                type_store,
+                                context,
+                            ))
                     {
                         return Ok(());
                     }
@@ -69,7 +106,10 @@ impl TypeValidator for TypeReference {
             }
             Nothing => {}
         }
-        type_def.validate(value, type_store, ion_path)
+        context.enter(ion_path)?;
+        let result = type_def.validate(value, type_store, ion_path, context);
+        context.exit();
+        type_def.set_violation_type(self.type_id, result)
     }
 }
 
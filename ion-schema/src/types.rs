@@ -1,27 +1,34 @@
+use crate::batch::BatchValidation;
 use crate::constraint::Constraint;
 use crate::ion_path::IonPath;
 use crate::isl::isl_constraint::IslConstraintImpl;
 use crate::isl::isl_type::IslTypeImpl;
 use crate::isl::IslVersion;
 use crate::result::{IonSchemaResult, ValidationResult};
+use crate::streaming;
+use crate::streaming::StreamValidationIterator;
 use crate::system::{PendingTypes, TypeId, TypeStore};
-use crate::violation::{Violation, ViolationCode};
-use crate::IonSchemaElement;
+use crate::type_reference::TypeReference;
+use crate::violation::{Violation, ViolationCode, ViolationType};
+use crate::{IonSchemaElement, IonSchemaElementRef};
 use ion_rs::element::Element;
-use ion_rs::IonType;
 use ion_rs::Symbol;
+use ion_rs::{IonReader, IonType, StreamItem};
+#[cfg(feature = "parallel")]
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
 use std::fmt::{Display, Formatter};
 use std::sync::Arc;
 
 /// Provides validation for type definition
 pub(crate) trait TypeValidator {
     /// If the specified value violates one or more of this type's constraints,
-    /// returns `false`, otherwise `true`
+    /// returns `false`, otherwise `true`.
+    /// Unlike `validate`, this doesn't build any [Violation]s and stops at the first unsatisfied constraint.
     fn is_valid(
         &self,
-        value: &IonSchemaElement,
+        value: IonSchemaElementRef,
         type_store: &TypeStore,
-        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> bool;
 
     /// Returns `Err(violation)` with details as to which constraints were violated,
@@ -31,9 +38,249 @@ pub(crate) trait TypeValidator {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult;
 }
 
+/// Provides options that control how violations are collected while validating a value.
+///
+/// By default, validation gathers every violation for every element and field of the value,
+/// and follows at most [`DEFAULT_MAX_DEPTH`](Self::DEFAULT_MAX_DEPTH) nested type references.
+/// ```
+/// use ion_schema::types::ValidationOptions;
+///
+/// // stop at the first violation
+/// let options = ValidationOptions::default().with_fail_fast(true);
+///
+/// // report at most 100 violations
+/// let options = ValidationOptions::default().with_max_violations(100);
+///
+/// // bound the resources used to validate untrusted values against recursive types
+/// let options = ValidationOptions::default()
+///     .with_max_depth(64)
+///     .with_max_steps(100_000);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationOptions {
+    fail_fast: bool,
+    max_violations: usize,
+    max_depth: usize,
+    max_steps: usize,
+}
+
+impl ValidationOptions {
+    /// The default maximum depth, which keeps the stack used to validate deeply nested values
+    /// within the default 2 MiB stack of a spawned thread.
+    pub const DEFAULT_MAX_DEPTH: usize = 256;
+
+    /// When `fail_fast` is true, validation stops at the first violation found,
+    /// which results in a single chain of violations leading to that root cause.
+    pub fn with_fail_fast(self, fail_fast: bool) -> Self {
+        Self { fail_fast, ..self }
+    }
+
+    /// Sets the maximum number of root cause violations that are reported for a value, across its whole violation tree.
+    /// This bounds the memory used by the violation tree of a value with a large number of invalid elements or fields.
+    /// Validation stops collecting further violations once this limit is reached, and the root causes after the first
+    /// `max_violations` ones are removed from the reported violation tree. At least one root cause is always reported
+    /// for an invalid value.
+    pub fn with_max_violations(self, max_violations: usize) -> Self {
+        Self {
+            max_violations,
+            ..self
+        }
+    }
+
+    /// Sets the maximum number of nested type references that are followed while validating a value,
+    /// e.g. the `element` of a recursive linked list type is followed once for each level of nesting of the list.
+    /// This bounds the stack used to validate deeply nested values, and defaults to [`DEFAULT_MAX_DEPTH`](Self::DEFAULT_MAX_DEPTH).
+    /// Validation stops with a [`ViolationCode::ValidationLimitExceeded`] violation once this limit is exceeded.
+    /// When validating values read from a stream, the child values nested more than `max_depth` times
+    /// exceed this limit as well, as they are not materialized.
+    pub fn with_max_depth(self, max_depth: usize) -> Self {
+        Self { max_depth, ..self }
+    }
+
+    /// Sets the maximum number of type references that are followed while validating a value,
+    /// which bounds the time spent validating it.
+    /// Validation stops with a [`ViolationCode::ValidationLimitExceeded`] violation once this limit is exceeded.
+    pub fn with_max_steps(self, max_steps: usize) -> Self {
+        Self { max_steps, ..self }
+    }
+
+    pub fn fail_fast(&self) -> bool {
+        self.fail_fast
+    }
+
+    pub fn max_violations(&self) -> usize {
+        self.max_violations
+    }
+
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    pub fn max_steps(&self) -> usize {
+        self.max_steps
+    }
+}
+
+impl Default for ValidationOptions {
+    fn default() -> Self {
+        Self {
+            fail_fast: false,
+            max_violations: usize::MAX,
+            max_depth: Self::DEFAULT_MAX_DEPTH,
+            max_steps: usize::MAX,
+        }
+    }
+}
+
+/// Keeps track of the state of a validation based on the [`ValidationOptions`] it was started with.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ValidationContext {
+    options: ValidationOptions,
+    // represents the number of root cause violations collected so far
+    violation_count: usize,
+    // represents the number of nested type references currently being followed
+    depth: usize,
+    // represents the number of type references followed so far
+    steps: usize,
+    // represents the violation for the first limit of the options that was exceeded, if any
+    limit_violation: Option<Violation>,
+    // an empty buffer for the annotations of a value, reused by the ISL 2.0 `annotations` constraints
+    annotations_buffer: Vec<Element>,
+}
+
+impl ValidationContext {
+    pub(crate) fn new(options: ValidationOptions) -> Self {
+        Self {
+            options,
+            violation_count: 0,
+            depth: 0,
+            steps: 0,
+            limit_violation: None,
+            annotations_buffer: vec![],
+        }
+    }
+
+    /// Takes the buffer for the annotations of a value, which is empty.
+    /// The buffer must be given back with [`restore_annotations_buffer`](Self::restore_annotations_buffer)
+    /// so that its allocation is reused by the next values validated with this context.
+    pub(crate) fn take_annotations_buffer(&mut self) -> Vec<Element> {
+        std::mem::take(&mut self.annotations_buffer)
+    }
+
+    /// Gives back the buffer taken with [`take_annotations_buffer`](Self::take_annotations_buffer)
+    pub(crate) fn restore_annotations_buffer(&mut self, mut annotations_buffer: Vec<Element>) {
+        annotations_buffer.clear();
+        self.annotations_buffer = annotations_buffer;
+    }
+
+    /// Records that a type reference is followed for the value at given `ion_path`,
+    /// returns a violation if this exceeds the maximum depth or number of steps of the options.
+    /// Once a limit is exceeded, this keeps returning a violation so that the validation unwinds quickly.
+    /// Each successful call must be paired with a call to [`exit`](Self::exit).
+    pub(crate) fn enter(&mut self, ion_path: &mut IonPath) -> Result<(), Violation> {
+        if let Some(violation) = &self.limit_violation {
+            return Err(violation.to_owned());
+        }
+        self.steps += 1;
+        let message = if self.depth >= self.options.max_depth {
+            format!(
+                "validation exceeded the maximum depth of {}",
+                self.options.max_depth
+            )
+        } else if self.steps > self.options.max_steps {
+            format!(
+                "validation exceeded the maximum number of steps of {}",
+                self.options.max_steps
+            )
+        } else {
+            self.depth += 1;
+            return Ok(());
+        };
+        Err(self.exceed_limit(message, ion_path))
+    }
+
+    /// Returns the maximum depth of the options, which also bounds the nesting of the values read from a stream
+    pub(crate) fn max_depth(&self) -> usize {
+        self.options.max_depth
+    }
+
+    /// Records that the value at given `ion_path` is nested deeper than the maximum depth of the options,
+    /// unless another limit was already exceeded
+    pub(crate) fn exceed_max_depth(&mut self, ion_path: &mut IonPath) {
+        if self.limit_violation.is_none() {
+            let message = format!(
+                "value exceeded the maximum depth of {}",
+                self.options.max_depth
+            );
+            self.exceed_limit(message, ion_path);
+        }
+    }
+
+    fn exceed_limit(&mut self, message: String, ion_path: &mut IonPath) -> Violation {
+        let violation = Violation::new(
+            "validation_limit",
+            ViolationCode::ValidationLimitExceeded,
+            message,
+            ion_path,
+        );
+        self.limit_violation = Some(violation.to_owned());
+        violation
+    }
+
+    /// Records that the type reference followed by the last call to [`enter`](Self::enter) was validated
+    pub(crate) fn exit(&mut self) {
+        self.depth -= 1;
+    }
+
+    /// Returns the final result of a validation, which is the violation for the exceeded limit if any.
+    /// A limit violation can otherwise be hidden by constraints like `not` or `any_of`, which don't report
+    /// the violations of their type references.
+    /// The reported violation tree is also pruned to the maximum number of root cause violations of the options,
+    /// as the nested violations of a single element or field can go past that number before validation stops.
+    pub(crate) fn finish(&mut self, result: ValidationResult) -> ValidationResult {
+        let result = match self.limit_violation.take() {
+            Some(violation) => Err(violation),
+            None => result,
+        };
+        result.map_err(|mut violation| {
+            violation.truncate_root_causes(self.options.max_violations);
+            violation
+        })
+    }
+
+    /// Adds the given violation to `violations`, keeping track of the number of root cause violations
+    pub(crate) fn push_violation(&mut self, violations: &mut Vec<Violation>, violation: Violation) {
+        if violation.violations().is_empty() {
+            self.violation_count += 1;
+        }
+        violations.push(violation);
+    }
+
+    /// Verifies if a validation that already found the given `violations` should stop looking for more violations.
+    /// This never stops a validation that hasn't found any violation yet, so it doesn't affect whether a value is valid or not.
+    pub(crate) fn should_stop(&self, violations: &[Violation]) -> bool {
+        !violations.is_empty()
+            && (self.options.fail_fast
+                || self.violation_count >= self.options.max_violations
+                || self.limit_violation.is_some())
+    }
+
+    /// Returns a checkpoint that can later be used to discard the violations collected after it
+    pub(crate) fn checkpoint(&self) -> usize {
+        self.violation_count
+    }
+
+    /// Discards the violations collected after the given checkpoint.
+    /// This is used when nested violations don't end up in the final result, e.g. for an `any_of` constraint that was satisfied.
+    pub(crate) fn restore(&mut self, checkpoint: usize) {
+        self.violation_count = checkpoint;
+    }
+}
+
 // Provides a public facing schema type which has a reference to TypeStore
 // to get the underlying TypeDefinitionKind from TypeStore
 /// Represents a top level ISL type definition
@@ -52,6 +299,89 @@ impl TypeDefinition {
         self.id
     }
 
+    pub(crate) fn type_store(&self) -> &TypeStore {
+        &self.type_store
+    }
+
+    /// Returns the name of this type definition, or `None` if it is an anonymous type definition.
+    /// Built-in types are named after the Ion type they represent, e.g. `int` or `$int` for nullable types.
+    pub fn name(&self) -> Option<String> {
+        match self.type_definition_kind() {
+            TypeDefinitionKind::Named(type_def) => type_def.name().to_owned(),
+            TypeDefinitionKind::Anonymous(_) => None,
+            TypeDefinitionKind::BuiltIn(built_in_type) => Some(built_in_type.name()),
+        }
+    }
+
+    /// Returns `true` if this is a built-in type definition, e.g. `int`, `$int` or `number`
+    pub fn is_built_in(&self) -> bool {
+        matches!(self.type_definition_kind(), TypeDefinitionKind::BuiltIn(_))
+    }
+
+    /// Returns the id of the schema that defines this type definition, or `None` for built-in types
+    /// and types that were not loaded from a schema
+    pub fn schema_id(&self) -> Option<&str> {
+        match self.type_definition_kind() {
+            TypeDefinitionKind::Named(type_def) | TypeDefinitionKind::Anonymous(type_def) => {
+                type_def.schema_id()
+            }
+            TypeDefinitionKind::BuiltIn(_) => None,
+        }
+    }
+
+    /// Returns the resolved constraints of this type definition.
+    /// Built-in types that represent an Ion type, e.g. `int`, don't have any constraints.
+    ///
+    /// The type references of the constraints can be resolved with [`referenced_type`](Self::referenced_type).
+    /// ```
+    /// use ion_schema::authority::MapDocumentAuthority;
+    /// use ion_schema::constraint::Constraint;
+    /// use ion_schema::system::SchemaSystem;
+    ///
+    /// let authority = MapDocumentAuthority::new([(
+    ///     "sample.isl",
+    ///     r#"
+    ///         type::{ name: short_string, type: string, codepoint_length: range::[1, 10] }
+    ///         type::{ name: names, type: list, element: short_string }
+    ///     "#,
+    /// )]);
+    /// let mut schema_system = SchemaSystem::new(vec![Box::new(authority)]);
+    /// let schema = schema_system.load_schema("sample.isl").unwrap();
+    /// let names = schema.get_type("names").unwrap();
+    ///
+    /// for constraint in names.constraints() {
+    ///     if let Constraint::Element(element) = constraint {
+    ///         let element_type = names.referenced_type(&element.type_reference());
+    ///         assert_eq!(element_type.name(), Some("short_string".to_owned()));
+    ///         assert!(element_type
+    ///             .constraints()
+    ///             .iter()
+    ///             .any(|c| matches!(c, Constraint::CodepointLength(_))));
+    ///     }
+    /// }
+    /// ```
+    pub fn constraints(&self) -> &[Constraint] {
+        match self.type_definition_kind() {
+            TypeDefinitionKind::Named(type_def) | TypeDefinitionKind::Anonymous(type_def) => {
+                type_def.constraints()
+            }
+            TypeDefinitionKind::BuiltIn(BuiltInTypeDefinition::Derived(type_def)) => {
+                type_def.constraints()
+            }
+            TypeDefinitionKind::BuiltIn(BuiltInTypeDefinition::Atomic(_, _)) => &[],
+        }
+    }
+
+    /// Returns the [`TypeDefinition`] referred to by the given type reference of one of the constraints of this
+    /// type definition. The type reference must come from a type definition of the same schema.
+    pub fn referenced_type(&self, type_reference: &TypeReference) -> TypeDefinition {
+        TypeDefinition::new(type_reference.type_id(), Arc::clone(&self.type_store))
+    }
+
+    fn type_definition_kind(&self) -> &TypeDefinitionKind {
+        self.type_store.get_type_by_id(self.id).unwrap()
+    }
+
     /// Provides the validation for the given value based on this schema type
     /// ```
     /// use ion_rs::element::Element;
@@ -98,12 +428,238 @@ impl TypeDefinition {
     /// }
     /// ```
     pub fn validate<I: Into<IonSchemaElement>>(&self, value: I) -> ValidationResult {
+        self.validate_with(value, ValidationOptions::default())
+    }
+
+    /// Provides the validation for the given value based on this schema type, using the given [`ValidationOptions`]
+    /// to control how violations are collected.
+    /// ```
+    /// use ion_rs::element::Element;
+    /// use ion_schema::authority::{DocumentAuthority, MapDocumentAuthority};
+    /// use ion_schema::system::SchemaSystem;
+    /// use ion_schema::types::ValidationOptions;
+    /// use ion_schema::result::IonSchemaResult;
+    ///
+    /// fn main() -> IonSchemaResult<()> {
+    ///     let map_authority = [("sample.isl", "type::{ name: my_list, element: int }")];
+    ///     let authorities: Vec<Box<dyn DocumentAuthority>> =
+    ///         vec![Box::new(MapDocumentAuthority::new(map_authority))];
+    ///     let mut schema_system = SchemaSystem::new(authorities);
+    ///     let schema = schema_system.load_schema("sample.isl")?;
+    ///     let my_list = schema.get_type("my_list").unwrap();
+    ///
+    ///     let value = Element::read_one("[a, b, c, 1]")?;
+    ///
+    ///     // by default all the violations are collected
+    ///     let violation = my_list.validate(&value).unwrap_err();
+    ///     assert_eq!(violation.flattened_violations().len(), 3);
+    ///
+    ///     // with fail fast, validation stops at the first violation
+    ///     let options = ValidationOptions::default().with_fail_fast(true);
+    ///     let violation = my_list.validate_with(&value, options).unwrap_err();
+    ///     assert_eq!(violation.flattened_violations().len(), 1);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn validate_with<I: Into<IonSchemaElement>>(
+        &self,
+        value: I,
+        options: ValidationOptions,
+    ) -> ValidationResult {
         let type_def = self.type_store.get_type_by_id(self.id).unwrap();
 
         // convert given IonSchemaElement to an Element
         let schema_element: IonSchemaElement = value.into();
 
-        type_def.validate(&schema_element, &self.type_store, &mut IonPath::default())
+        let mut context = ValidationContext::new(options);
+        let result = type_def.validate(
+            &schema_element,
+            &self.type_store,
+            &mut IonPath::default(),
+            &mut context,
+        );
+        type_def.set_violation_type(self.id, context.finish(result))
+    }
+
+    /// Checks whether the given value is valid for this schema type.
+    ///
+    /// This is faster than [`validate`](Self::validate) when the details of the violations aren't needed,
+    /// as it borrows the given value instead of cloning it, doesn't build any [`Violation`]s
+    /// and stops as soon as a constraint is not satisfied.
+    /// It still allocates while evaluating some constraints, e.g. the states of an `ordered_elements` constraint,
+    /// and the buffer in which an ISL 2.0 `annotations` constraint copies the annotations of the values that have any,
+    /// which is allocated once per call and reused for all the values checked by that call.
+    ///
+    /// Like [`validate`](Self::validate), an element annotated with `document` is checked as a document,
+    /// which requires converting it into an [`IonSchemaElement`] first.
+    /// ```
+    /// use ion_rs::element::Element;
+    /// use ion_schema::authority::{DocumentAuthority, MapDocumentAuthority};
+    /// use ion_schema::system::SchemaSystem;
+    /// use ion_schema::result::IonSchemaResult;
+    ///
+    /// fn main() -> IonSchemaResult<()> {
+    ///     let map_authority = [("sample.isl", "type::{ name: my_int, type: int }")];
+    ///     let authorities: Vec<Box<dyn DocumentAuthority>> =
+    ///         vec![Box::new(MapDocumentAuthority::new(map_authority))];
+    ///     let mut schema_system = SchemaSystem::new(authorities);
+    ///     let schema = schema_system.load_schema("sample.isl")?;
+    ///     let my_int = schema.get_type("my_int").unwrap();
+    ///
+    ///     let owned_element: Element = 4.into();
+    ///     let document: Vec<Element> = vec![4.into()];
+    ///
+    ///     assert!(my_int.is_valid(&owned_element)); // 4 is valid for `my_int`
+    ///     assert!(!my_int.is_valid(&document)); // document type is invalid for `my_int` type
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn is_valid<'a, I: Into<IonSchemaElementRef<'a>>>(&self, value: I) -> bool {
+        self.is_valid_with(value, ValidationOptions::default())
+    }
+
+    /// Checks whether the given value is valid for this schema type like [`is_valid`](Self::is_valid),
+    /// using the limits of the given [`ValidationOptions`]. A value that exceeds one of the limits is not valid.
+    pub fn is_valid_with<'a, I: Into<IonSchemaElementRef<'a>>>(
+        &self,
+        value: I,
+        options: ValidationOptions,
+    ) -> bool {
+        let value = value.into();
+        if let Some(element) = value.as_element() {
+            if element.annotations().contains("document") {
+                return self.is_valid_with(&IonSchemaElement::from(element), options);
+            }
+        }
+        let type_def = self.type_store.get_type_by_id(self.id).unwrap();
+        type_def.is_valid(
+            value,
+            &self.type_store,
+            &mut ValidationContext::new(options),
+        )
+    }
+
+    /// Validates each of the given values against this schema type, one after the other.
+    /// The returned [`BatchValidation`] has the result for each value along with the statistics of all the results,
+    /// see [`batch`] for an example.
+    ///
+    /// [`batch`]: crate::batch
+    pub fn validate_all<I, V>(&self, values: I) -> BatchValidation
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<IonSchemaElement>,
+    {
+        self.validate_all_with(values, ValidationOptions::default())
+    }
+
+    /// Validates each of the given values against this schema type like [`validate_all`](Self::validate_all),
+    /// using the given [`ValidationOptions`] for each value.
+    pub fn validate_all_with<I, V>(&self, values: I, options: ValidationOptions) -> BatchValidation
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<IonSchemaElement>,
+    {
+        BatchValidation::new(
+            values
+                .into_iter()
+                .map(|value| self.validate_with(value, options))
+                .collect(),
+        )
+    }
+
+    /// Validates each of the given values against this schema type like [`validate_all`](Self::validate_all),
+    /// but the values are validated in parallel on the [rayon] thread pool.
+    /// The results are still returned in the same order as the values.
+    ///
+    /// [rayon]: https://docs.rs/rayon
+    #[cfg(feature = "parallel")]
+    pub fn par_validate_all<I, V>(&self, values: I) -> BatchValidation
+    where
+        I: IntoParallelIterator<Item = V>,
+        I::Iter: IndexedParallelIterator,
+        V: Into<IonSchemaElement>,
+    {
+        self.par_validate_all_with(values, ValidationOptions::default())
+    }
+
+    /// Validates each of the given values against this schema type in parallel like
+    /// [`par_validate_all`](Self::par_validate_all), using the given [`ValidationOptions`] for each value.
+    /// The values are validated on the threads of the rayon thread pool, whose stack size must be large enough
+    /// for the maximum depth of the options.
+    #[cfg(feature = "parallel")]
+    pub fn par_validate_all_with<I, V>(
+        &self,
+        values: I,
+        options: ValidationOptions,
+    ) -> BatchValidation
+    where
+        I: IntoParallelIterator<Item = V>,
+        I::Iter: IndexedParallelIterator,
+        V: Into<IonSchemaElement>,
+    {
+        BatchValidation::new(
+            values
+                .into_par_iter()
+                .map(|value| self.validate_with(value, options))
+                .collect(),
+        )
+    }
+
+    /// Validates each of the top level values read from the given reader against this schema type.
+    ///
+    /// Values are validated as they are read, and the child values of a top level container are
+    /// read and validated one at a time, hence a top level container never needs to be fully materialized
+    /// in memory. Only this one level is streamed: each child value is materialized as a whole, including
+    /// the containers nested in it. See [`streaming`] for more details.
+    ///
+    /// The returned iterator yields the [`ValidationResult`] for each top level value, or an
+    /// [`IonSchemaError`] if the reader fails to read a value.
+    ///
+    /// [`streaming`]: crate::streaming
+    /// [`IonSchemaError`]: crate::result::IonSchemaError
+    pub fn validate_stream<'a, R: IonReader<Item = StreamItem, Symbol = Symbol>>(
+        &'a self,
+        reader: &'a mut R,
+    ) -> StreamValidationIterator<'a, R> {
+        self.validate_stream_with(reader, ValidationOptions::default())
+    }
+
+    /// Validates each of the top level values read from the given reader like [`validate_stream`](Self::validate_stream),
+    /// using the given [`ValidationOptions`] for each value.
+    pub fn validate_stream_with<'a, R: IonReader<Item = StreamItem, Symbol = Symbol>>(
+        &'a self,
+        reader: &'a mut R,
+        options: ValidationOptions,
+    ) -> StreamValidationIterator<'a, R> {
+        StreamValidationIterator::new(self, reader, options)
+    }
+
+    /// Validates all the top level values read from the given reader as a single document against this schema type.
+    ///
+    /// The top level values are read and validated one at a time, hence the document never needs
+    /// to be fully materialized in memory, but each top level value is materialized as a whole.
+    /// See [`streaming`] for more details.
+    ///
+    /// Returns an [`IonSchemaError`] if the reader fails to read a value,
+    /// otherwise returns the [`ValidationResult`] for the document.
+    ///
+    /// [`streaming`]: crate::streaming
+    /// [`IonSchemaError`]: crate::result::IonSchemaError
+    pub fn validate_document_stream<R: IonReader<Item = StreamItem, Symbol = Symbol>>(
+        &self,
+        reader: &mut R,
+    ) -> IonSchemaResult<ValidationResult> {
+        self.validate_document_stream_with(reader, ValidationOptions::default())
+    }
+
+    /// Validates all the top level values read from the given reader as a single document like
+    /// [`validate_document_stream`](Self::validate_document_stream), using the given [`ValidationOptions`].
+    pub fn validate_document_stream_with<R: IonReader<Item = StreamItem, Symbol = Symbol>>(
+        &self,
+        reader: &mut R,
+        options: ValidationOptions,
+    ) -> IonSchemaResult<ValidationResult> {
+        streaming::validate_document(reader, self.id, &self.type_store, options)
     }
 }
 
@@ -153,17 +709,41 @@ impl BuiltInTypeDefinition {
         ));
         Ok(builtin_type_def)
     }
+
+    /// Returns the name of this built-in type, e.g. `int` or `$int` for atomic types and `number` for derived types
+    pub(crate) fn name(&self) -> String {
+        match self {
+            BuiltInTypeDefinition::Atomic(ion_type, is_nullable) => match is_nullable {
+                Nullability::Nullable => format!("${ion_type}"),
+                Nullability::NotNullable => format!("{ion_type}"),
+            },
+            BuiltInTypeDefinition::Derived(other_type) => other_type.name().to_owned().unwrap(),
+        }
+    }
 }
 
 impl TypeValidator for BuiltInTypeDefinition {
     fn is_valid(
         &self,
-        value: &IonSchemaElement,
+        value: IonSchemaElementRef,
         type_store: &TypeStore,
-        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> bool {
-        let violation = self.validate(value, type_store, ion_path);
-        violation.is_ok()
+        match &self {
+            BuiltInTypeDefinition::Atomic(ion_type, is_nullable) => match value {
+                IonSchemaElementRef::SingleElement(element) => {
+                    (*is_nullable == Nullability::Nullable || !element.is_null())
+                        && element.ion_type() == *ion_type
+                }
+                IonSchemaElementRef::Document(_) => false,
+            },
+            BuiltInTypeDefinition::Derived(other_type) => {
+                if other_type.name().as_deref() == Some("document") {
+                    return value.as_document().is_some();
+                }
+                other_type.is_valid(value, type_store, context)
+            }
+        }
     }
 
     fn validate(
@@ -171,6 +751,7 @@ impl TypeValidator for BuiltInTypeDefinition {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         match &self {
             BuiltInTypeDefinition::Atomic(ion_type, is_nullable) => {
@@ -226,7 +807,7 @@ impl TypeValidator for BuiltInTypeDefinition {
                     return Ok(());
                 }
                 // if it is not a document type do validation using the type definition
-                other_type.validate(value, type_store, ion_path)
+                other_type.validate(value, type_store, ion_path, context)
             }
         }
     }
@@ -281,9 +862,9 @@ impl TypeDefinitionKind {
     // It returns the result of validation for that nullable base type.
     pub fn is_valid_for_base_nullable_type(
         &self,
-        value: &IonSchemaElement,
+        value: IonSchemaElementRef,
         type_store: &TypeStore,
-        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> bool {
         // get a nullable built in base type name which can be used to perform validation to check for correct `null.*` type
         let built_in_type_name = match self {
@@ -316,7 +897,7 @@ impl TypeDefinitionKind {
             )
             .unwrap();
 
-        type_def.is_valid(value, type_store, ion_path)
+        type_def.is_valid(value, type_store, context)
     }
 }
 
@@ -336,15 +917,54 @@ impl Display for TypeDefinitionKind {
     }
 }
 
+impl TypeDefinitionKind {
+    /// Sets this type definition with given [`TypeId`] as the type of the violation in given result,
+    /// along with the nested violations that don't have a type yet.
+    /// Violations of an anonymous type are left to be set by the closest named type that encloses it.
+    pub(crate) fn set_violation_type(
+        &self,
+        type_id: TypeId,
+        result: ValidationResult,
+    ) -> ValidationResult {
+        result.map_err(|mut violation| {
+            if violation.type_id().is_some() {
+                return violation;
+            }
+            let violation_type = match self {
+                TypeDefinitionKind::Named(named_type) => ViolationType::new(
+                    named_type.name().to_owned().unwrap_or_default(),
+                    type_id,
+                    named_type.schema_id().map(|id| id.to_owned()),
+                ),
+                TypeDefinitionKind::Anonymous(_) => return violation,
+                TypeDefinitionKind::BuiltIn(built_in_type) => {
+                    ViolationType::new(built_in_type.name(), type_id, None)
+                }
+            };
+            violation.set_type_if_missing(&Arc::new(violation_type));
+            violation
+        })
+    }
+}
+
 impl TypeValidator for TypeDefinitionKind {
     fn is_valid(
         &self,
-        value: &IonSchemaElement,
+        value: IonSchemaElementRef,
         type_store: &TypeStore,
-        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> bool {
-        let violation = self.validate(value, type_store, ion_path);
-        violation.is_ok()
+        match self {
+            TypeDefinitionKind::Named(named_type) => {
+                named_type.is_valid(value, type_store, context)
+            }
+            TypeDefinitionKind::Anonymous(anonymous_type) => {
+                anonymous_type.is_valid(value, type_store, context)
+            }
+            TypeDefinitionKind::BuiltIn(built_in_type) => {
+                built_in_type.is_valid(value, type_store, context)
+            }
+        }
     }
 
     fn validate(
@@ -352,16 +972,17 @@ impl TypeValidator for TypeDefinitionKind {
         value: &IonSchemaElement,
         type_store: &TypeStore,
         ion_path: &mut IonPath,
+        context: &mut ValidationContext,
     ) -> ValidationResult {
         match self {
             TypeDefinitionKind::Named(named_type) => {
-                named_type.validate(value, type_store, ion_path)
+                named_type.validate(value, type_store, ion_path, context)
             }
             TypeDefinitionKind::Anonymous(anonymous_type) => {
-                anonymous_type.validate(value, type_store, ion_path)
+                anonymous_type.validate(value, type_store, ion_path, context)
             }
             TypeDefinitionKind::BuiltIn(built_in_type) => {
-                built_in_type.validate(value, type_store, ion_path)
+                built_in_type.validate(value, type_store, ion_path, context)
             }
         }
     }
@@ -388,6 +1009,8 @@ pub(crate) struct TypeDefinitionImpl {
     is_deferred_type_def: bool,
     // Represents the ISL type struct in string format, this will be used for violation messages
     isl_type_struct: Option<Element>,
+    // Represents the id of the schema that defines this type, this is `None` for built-in types
+    schema_id: Option<String>,
 }
 
 impl TypeDefinitionImpl {
@@ -401,6 +1024,7 @@ impl TypeDefinitionImpl {
             constraints,
             is_deferred_type_def: false,
             isl_type_struct,
+            schema_id: None,
         }
     }
 
@@ -410,6 +1034,7 @@ impl TypeDefinitionImpl {
             constraints: vec![],
             is_deferred_type_def: true,
             isl_type_struct: None,
+            schema_id: None,
         }
     }
 
@@ -423,9 +1048,18 @@ impl TypeDefinitionImpl {
             constraints: self.constraints,
             is_deferred_type_def: self.is_deferred_type_def,
             isl_type_struct: None,
+            schema_id: self.schema_id,
         }
     }
 
+    pub fn schema_id(&self) -> Option<&str> {
+        self.schema_id.as_deref()
+    }
+
+    pub(crate) fn set_schema_id(&mut self, schema_id: &str) {
+        self.schema_id = Some(schema_id.to_owned());
+    }
+
     pub fn is_deferred_type_def(&self) -> bool {
         self.is_deferred_type_def
     }
@@ -470,7 +1104,14 @@ impl TypeDefinitionImpl {
                 type_store,
                 pending_types,
                 isl_type.is_open_content_allowed(),
-            )?;
+            )
+            .map_err(|error| {
+                let error = error.with_constraint(isl_constraint.field_name());
+                match type_name {
+                    Some(name) => error.with_type_name(name),
+                    None => error,
+                }
+            })?;
             constraints.push(constraint);
         }
 
@@ -535,30 +1176,31 @@ impl TypeDefinitionImpl {
     }
 }
 
-impl PartialEq for TypeDefinitionImpl {
-    fn eq(&self, other: &Self) -> bool {
-        self.name() == other.name() && self.constraints == other.constraints()
-    }
-}
-
-impl TypeValidator for TypeDefinitionImpl {
-    fn is_valid(
+impl TypeDefinitionImpl {
+    /// Combines the validation results for each of the constraints into a result for this type definition
+    pub(crate) fn combine_results<I: IntoIterator<Item = ValidationResult>>(
         &self,
-        value: &IonSchemaElement,
-        type_store: &TypeStore,
+        results: I,
         ion_path: &mut IonPath,
-    ) -> bool {
-        let violation = self.validate(value, type_store, ion_path);
-        violation.is_ok()
+        context: &mut ValidationContext,
+    ) -> ValidationResult {
+        let mut violations: Vec<Violation> = vec![];
+        for result in results {
+            if let Err(violation) = result {
+                context.push_violation(&mut violations, violation);
+            }
+        }
+        self.violations_to_result(violations, ion_path)
     }
 
-    fn validate(
+    fn violations_to_result(
         &self,
-        value: &IonSchemaElement,
-        type_store: &TypeStore,
+        violations: Vec<Violation>,
         ion_path: &mut IonPath,
     ) -> ValidationResult {
-        let mut violations: Vec<Violation> = vec![];
+        if violations.is_empty() {
+            return Ok(());
+        }
         let type_name = match self.name() {
             None => match self.isl_type_struct.as_ref() {
                 None => "".to_owned(),
@@ -568,14 +1210,6 @@ impl TypeValidator for TypeDefinitionImpl {
             },
             Some(name) => name.to_owned(),
         };
-        for constraint in self.constraints() {
-            if let Err(violation) = constraint.validate(value, type_store, ion_path) {
-                violations.push(violation);
-            }
-        }
-        if violations.is_empty() {
-            return Ok(());
-        }
         Err(Violation::with_violations(
             type_name,
             ViolationCode::TypeConstraintsUnsatisfied,
@@ -586,6 +1220,44 @@ impl TypeValidator for TypeDefinitionImpl {
     }
 }
 
+impl PartialEq for TypeDefinitionImpl {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name() && self.constraints == other.constraints()
+    }
+}
+
+impl TypeValidator for TypeDefinitionImpl {
+    fn is_valid(
+        &self,
+        value: IonSchemaElementRef,
+        type_store: &TypeStore,
+        context: &mut ValidationContext,
+    ) -> bool {
+        self.constraints()
+            .iter()
+            .all(|constraint| constraint.is_valid(value, type_store, context))
+    }
+
+    fn validate(
+        &self,
+        value: &IonSchemaElement,
+        type_store: &TypeStore,
+        ion_path: &mut IonPath,
+        context: &mut ValidationContext,
+    ) -> ValidationResult {
+        let mut violations: Vec<Violation> = vec![];
+        for constraint in self.constraints() {
+            if context.should_stop(&violations) {
+                break;
+            }
+            if let Err(violation) = constraint.validate(value, type_store, ion_path, context) {
+                context.push_violation(&mut violations, violation);
+            }
+        }
+        self.violations_to_result(violations, ion_path)
+    }
+}
+
 impl Display for TypeDefinitionImpl {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let type_def_name = match &self.name {
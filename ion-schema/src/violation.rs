@@ -1,6 +1,11 @@
-use crate::ion_path::IonPath;
+use crate::ion_path::{IonPath, IonPathElement};
+use crate::isl::WriteToIsl;
+use crate::result::{IonSchemaResult, ValidationResult};
+use crate::system::TypeId;
+use ion_rs::{IonType, IonWriter};
 use std::fmt;
-use std::fmt::Formatter;
+use std::fmt::{Formatter, Write};
+use std::sync::Arc;
 use thiserror::Error;
 
 /// Represents [Violation] found during validation with detailed error message, error code and the constraint for which the validation failed
@@ -11,6 +16,26 @@ pub struct Violation {
     message: String,     // represents the detailed error message for this violation
     ion_path: IonPath,   // represents the path to Ion value for which violation occurred
     violations: Vec<Violation>,
+    // represents the closest named type that produced this violation, this is shared with the nested violations of the same type
+    violation_type: Option<Arc<ViolationType>>,
+}
+
+/// Represents the type that produced a [Violation] along with the schema that defines it
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ViolationType {
+    type_name: String,
+    type_id: TypeId,
+    schema_id: Option<String>,
+}
+
+impl ViolationType {
+    pub(crate) fn new(type_name: String, type_id: TypeId, schema_id: Option<String>) -> Self {
+        Self {
+            type_name,
+            type_id,
+            schema_id,
+        }
+    }
 }
 
 impl Violation {
@@ -26,6 +51,7 @@ impl Violation {
             message: message.as_ref().to_owned(),
             ion_path: ion_path.to_owned(),
             violations: vec![],
+            violation_type: None,
         }
     }
 
@@ -42,6 +68,44 @@ impl Violation {
             message: message.as_ref().to_owned(),
             ion_path: ion_path.to_owned(),
             violations,
+            violation_type: None,
+        }
+    }
+
+    /// Returns the name of the constraint that created this violation.
+    /// For a violation that groups the violations of a type definition, this is the name of the type
+    /// or the ISL struct of an anonymous type.
+    pub fn constraint(&self) -> &str {
+        &self.constraint
+    }
+
+    /// Returns the name of the type whose constraints created this violation.
+    /// Violations created by an anonymous type belong to the closest named type that encloses it,
+    /// and violations created by a built-in type like `int` belong to the built-in type.
+    pub fn type_name(&self) -> Option<&str> {
+        self.violation_type.as_ref().map(|t| t.type_name.as_str())
+    }
+
+    /// Returns the [`TypeId`] of the type returned by [`type_name`](Self::type_name)
+    pub fn type_id(&self) -> Option<TypeId> {
+        self.violation_type.as_ref().map(|t| t.type_id)
+    }
+
+    /// Returns the id of the schema that defines the type returned by [`type_name`](Self::type_name),
+    /// this is `None` for built-in types.
+    pub fn schema_id(&self) -> Option<&str> {
+        self.violation_type.as_ref()?.schema_id.as_deref()
+    }
+
+    /// Sets the type that created this violation and the nested violations which don't have a type yet.
+    /// Nested violations that already have a type were created by another type that is referenced from this one.
+    pub(crate) fn set_type_if_missing(&mut self, violation_type: &Arc<ViolationType>) {
+        if self.violation_type.is_some() {
+            return;
+        }
+        self.violation_type = Some(Arc::clone(violation_type));
+        for violation in &mut self.violations {
+            violation.set_type_if_missing(violation_type);
         }
     }
 
@@ -64,6 +128,28 @@ impl Violation {
         flattened_violations
     }
 
+    /// Removes the root cause violations after the first `max_root_causes` ones, in the order of
+    /// [`flattened_violations`](Self::flattened_violations), along with the violations that are left without
+    /// any nested violations. This violation itself and its first root cause are always kept.
+    pub(crate) fn truncate_root_causes(&mut self, max_root_causes: usize) {
+        let mut remaining = max_root_causes.max(1);
+        self.retain_root_causes(&mut remaining);
+    }
+
+    // returns `false` if this violation doesn't have any remaining root cause
+    fn retain_root_causes(&mut self, remaining: &mut usize) -> bool {
+        if self.violations.is_empty() {
+            if *remaining == 0 {
+                return false;
+            }
+            *remaining -= 1;
+            return true;
+        }
+        self.violations
+            .retain_mut(|violation| violation.retain_root_causes(remaining));
+        !self.violations.is_empty()
+    }
+
     fn flatten_violations<'a>(&'a self, flattened: &mut Vec<&'a Violation>) {
         if self.violations.is_empty() {
             flattened.push(self);
@@ -80,17 +166,410 @@ impl Violation {
     pub fn violations(&self) -> &[Violation] {
         &self.violations
     }
+
+    /// Provides a [ViolationDisplay] which renders this violation along with its nested violations
+    /// as an indented tree. It can be configured to use ANSI colors or to limit the depth of the rendered tree.
+    pub fn display(&self) -> ViolationDisplay<'_> {
+        ViolationDisplay {
+            violation: self,
+            color: false,
+            max_depth: usize::MAX,
+        }
+    }
+
+    /// Renders this violation along with its nested violations as a JSON object
+    /// with the same structure as the one written by [WriteToIsl::write_to].
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        self.write_json(&mut json);
+        json
+    }
+
+    fn write_json(&self, json: &mut String) {
+        json.push_str("{\"constraint\":");
+        write_json_string(json, &self.constraint);
+        json.push_str(",\"code\":");
+        write_json_string(json, &self.code.to_string());
+        json.push_str(",\"message\":");
+        write_json_string(json, &self.message);
+        json.push_str(",\"path\":[");
+        for (i, element) in self.ion_path.elements().iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            match element {
+                IonPathElement::Index(index) => {
+                    let _ = write!(json, "{index}");
+                }
+                IonPathElement::Field(name) => write_json_string(json, name),
+            }
+        }
+        json.push(']');
+        if let Some(type_name) = self.type_name() {
+            json.push_str(",\"type\":");
+            write_json_string(json, type_name);
+        }
+        if let Some(schema_id) = self.schema_id() {
+            json.push_str(",\"schema\":");
+            write_json_string(json, schema_id);
+        }
+        json.push_str(",\"violations\":[");
+        for (i, violation) in self.violations.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            violation.write_json(json);
+        }
+        json.push_str("]}");
+    }
+}
+
+/// Writes the violation as an Ion struct with the following structure,
+/// where the `path` is the [IonPath] s-expression of the value for which the violation occurred
+/// and the optional `type` and `schema` fields are written when the violation has a type and a schema id:
+/// ```ion
+/// {
+///     constraint: "element",
+///     code: element_mismatched,
+///     message: "one or more elements don't satisfy element constraint",
+///     path: (),
+///     type: my_list,
+///     schema: "sample.isl",
+///     violations: [ /* nested violations */ ],
+/// }
+/// ```
+impl WriteToIsl for Violation {
+    fn write_to<W: IonWriter>(&self, writer: &mut W) -> IonSchemaResult<()> {
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_name("constraint");
+        writer.write_string(&self.constraint)?;
+        writer.set_field_name("code");
+        writer.write_symbol(self.code.to_string().as_str())?;
+        writer.set_field_name("message");
+        writer.write_string(&self.message)?;
+
+        writer.set_field_name("path");
+        writer.step_in(IonType::SExp)?;
+        for element in self.ion_path.elements() {
+            match element {
+                IonPathElement::Index(index) => writer.write_i64(*index as i64)?,
+                IonPathElement::Field(name) => writer.write_symbol(name.as_str())?,
+            }
+        }
+        writer.step_out()?;
+
+        if let Some(type_name) = self.type_name() {
+            writer.set_field_name("type");
+            writer.write_symbol(type_name)?;
+        }
+        if let Some(schema_id) = self.schema_id() {
+            writer.set_field_name("schema");
+            writer.write_string(schema_id)?;
+        }
+
+        writer.set_field_name("violations");
+        writer.step_in(IonType::List)?;
+        for violation in &self.violations {
+            violation.write_to(writer)?;
+        }
+        writer.step_out()?;
+        writer.step_out()?;
+        Ok(())
+    }
+}
+
+/// Represents the outcome of validating a sequence of Ion values against a type.
+/// The report can be written as Ion using [WriteToIsl::write_to] or rendered as JSON using [ValidationReport::to_json],
+/// where each result is identified by the index of the validated value and invalid results contain their [Violation].
+///
+/// ## Example:
+/// ```
+/// use ion_rs::element::Element;
+/// use ion_rs::{IonWriter, TextWriterBuilder};
+/// use ion_schema::authority::{DocumentAuthority, MapDocumentAuthority};
+/// use ion_schema::isl::WriteToIsl;
+/// use ion_schema::system::SchemaSystem;
+/// use ion_schema::result::IonSchemaResult;
+/// use ion_schema::violation::ValidationReport;
+///
+/// fn main() -> IonSchemaResult<()> {
+///     let map_authority = [("sample.isl", "type::{ name: my_int, type: int }")];
+///     let authorities: Vec<Box<dyn DocumentAuthority>> =
+///         vec![Box::new(MapDocumentAuthority::new(map_authority))];
+///     let mut schema_system = SchemaSystem::new(authorities);
+///     let schema = schema_system.load_schema("sample.isl")?;
+///     let my_int = schema.get_type("my_int").unwrap();
+///
+///     let values = Element::read_all("1 a")?;
+///     let report = ValidationReport::new(
+///         "my_int",
+///         values.iter().map(|value| my_int.validate(value)).collect(),
+///     );
+///     assert!(!report.is_valid());
+///
+///     let mut writer = TextWriterBuilder::default().build(vec![])?;
+///     report.write_to(&mut writer)?;
+///     writer.flush()?;
+///     assert_eq!(
+///         Element::read_one(writer.output())?,
+///         Element::read_one(r#"{
+///             type: my_int,
+///             valid: false,
+///             results: [
+///                 { index: 0, valid: true },
+///                 {
+///                     index: 1,
+///                     valid: false,
+///                     violation: {
+///                         constraint: "my_int",
+///                         code: type_constraints_unsatisfied,
+///                         message: "value didn't satisfy type constraint(s)",
+///                         path: (),
+///                         type: my_int,
+///                         schema: "sample.isl",
+///                         violations: [
+///                             {
+///                                 constraint: "type_constraint",
+///                                 code: type_mismatched,
+///                                 message: "expected type Int, found Symbol",
+///                                 path: (),
+///                                 type: int,
+///                                 violations: [],
+///                             },
+///                         ],
+///                     },
+///                 },
+///             ],
+///         }"#)?
+///     );
+///
+///     assert!(report.to_json().starts_with(
+///         r#"{"type":"my_int","valid":false,"results":[{"index":0,"valid":true},{"index":1,"valid":false,"violation":{"#
+///     ));
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationReport {
+    type_name: String,
+    results: Vec<ValidationResult>,
+}
+
+impl ValidationReport {
+    pub fn new<A: AsRef<str>>(type_name: A, results: Vec<ValidationResult>) -> Self {
+        Self {
+            type_name: type_name.as_ref().to_owned(),
+            results,
+        }
+    }
+
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    pub fn results(&self) -> &[ValidationResult] {
+        &self.results
+    }
+
+    /// Returns true if all of the values in this report are valid for the type
+    pub fn is_valid(&self) -> bool {
+        self.results.iter().all(|result| result.is_ok())
+    }
+
+    /// Renders this report as a JSON object with the same structure as the one written by [WriteToIsl::write_to].
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        json.push_str("{\"type\":");
+        write_json_string(&mut json, &self.type_name);
+        let _ = write!(json, ",\"valid\":{},\"results\":[", self.is_valid());
+        for (index, result) in self.results.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            let _ = write!(json, "{{\"index\":{index},\"valid\":{}", result.is_ok());
+            if let Err(violation) = result {
+                json.push_str(",\"violation\":");
+                violation.write_json(&mut json);
+            }
+            json.push('}');
+        }
+        json.push_str("]}");
+        json
+    }
 }
 
-// TODO: Implement Violation with proper indentation for the nested tree of violations
+impl WriteToIsl for ValidationReport {
+    fn write_to<W: IonWriter>(&self, writer: &mut W) -> IonSchemaResult<()> {
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_name("type");
+        writer.write_symbol(self.type_name.as_str())?;
+        writer.set_field_name("valid");
+        writer.write_bool(self.is_valid())?;
+
+        writer.set_field_name("results");
+        writer.step_in(IonType::List)?;
+        for (index, result) in self.results.iter().enumerate() {
+            writer.step_in(IonType::Struct)?;
+            writer.set_field_name("index");
+            writer.write_i64(index as i64)?;
+            writer.set_field_name("valid");
+            writer.write_bool(result.is_ok())?;
+            if let Err(violation) = result {
+                writer.set_field_name("violation");
+                violation.write_to(writer)?;
+            }
+            writer.step_out()?;
+        }
+        writer.step_out()?;
+        writer.step_out()?;
+        Ok(())
+    }
+}
+
+// writes the given value as a quoted JSON string, escaping the characters that JSON doesn't allow in a string
+fn write_json_string(json: &mut String, value: &str) {
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+/// Displays the violation as an indented tree, see [ViolationDisplay] for more details
 impl fmt::Display for Violation {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "A validation error occurred: {}", self.message)
+        write!(f, "{}", self.display())
+    }
+}
+
+const ANSI_BOLD: &str = "\x1b[1m";
+const ANSI_RED: &str = "\x1b[31m";
+const ANSI_CYAN: &str = "\x1b[36m";
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// Renders a [Violation] and its nested violations as an indented tree.
+/// Each line shows the constraint name, the [ViolationCode], the [IonPath] and the message of a violation,
+/// and nested violations are indented under their parent violation.
+///
+/// ## Example:
+/// ```
+/// use ion_rs::element::Element;
+/// use ion_schema::authority::{DocumentAuthority, MapDocumentAuthority};
+/// use ion_schema::system::SchemaSystem;
+/// use ion_schema::result::IonSchemaResult;
+///
+/// fn main() -> IonSchemaResult<()> {
+///     let map_authority = [("sample.isl", "type::{ name: my_list, element: int }")];
+///     let authorities: Vec<Box<dyn DocumentAuthority>> =
+///         vec![Box::new(MapDocumentAuthority::new(map_authority))];
+///     let mut schema_system = SchemaSystem::new(authorities);
+///     let schema = schema_system.load_schema("sample.isl")?;
+///     let my_list = schema.get_type("my_list").unwrap();
+///
+///     let violation = my_list.validate(&Element::read_one("[1, a]")?).unwrap_err();
+///     assert_eq!(
+///         format!("{violation}"),
+///         "my_list (type_constraints_unsatisfied) at (): value didn't satisfy type constraint(s)
+///   element (element_mismatched) at (): one or more elements don't satisfy element constraint
+///     type_constraint (type_mismatched) at (1): expected type Int, found Symbol"
+///     );
+///
+///     // only render the top level violation and its direct nested violations
+///     assert_eq!(
+///         format!("{}", violation.display().with_max_depth(1)),
+///         "my_list (type_constraints_unsatisfied) at (): value didn't satisfy type constraint(s)
+///   element (element_mismatched) at (): one or more elements don't satisfy element constraint
+///     ... 1 nested violation(s) omitted"
+///     );
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ViolationDisplay<'a> {
+    violation: &'a Violation,
+    color: bool,
+    max_depth: usize,
+}
+
+impl<'a> ViolationDisplay<'a> {
+    /// When `color` is true, the rendered tree uses ANSI escape codes to highlight
+    /// the constraint name, the violation code and the Ion path of each violation.
+    pub fn with_color(self, color: bool) -> Self {
+        Self { color, ..self }
+    }
+
+    /// Sets the maximum depth of nested violations that are rendered, where the top level violation is at depth 0.
+    /// The nested violations beyond this depth are summarized with the number of omitted violations.
+    pub fn with_max_depth(self, max_depth: usize) -> Self {
+        Self { max_depth, ..self }
+    }
+
+    fn fmt_violation(
+        &self,
+        f: &mut Formatter<'_>,
+        violation: &Violation,
+        depth: usize,
+    ) -> fmt::Result {
+        let indent = depth * 2;
+        write!(f, "{:indent$}", "")?;
+        self.fmt_styled(f, ANSI_BOLD, &violation.constraint)?;
+        write!(f, " (")?;
+        self.fmt_styled(f, ANSI_RED, &violation.code)?;
+        write!(f, ") at ")?;
+        self.fmt_styled(f, ANSI_CYAN, &violation.ion_path)?;
+        write!(f, ": {}", violation.message)?;
+
+        if violation.violations.is_empty() {
+            return Ok(());
+        }
+        if depth >= self.max_depth {
+            return write!(
+                f,
+                "\n{:indent$}... {} nested violation(s) omitted",
+                "",
+                violation.violations.len(),
+                indent = indent + 2
+            );
+        }
+        for nested_violation in &violation.violations {
+            writeln!(f)?;
+            self.fmt_violation(f, nested_violation, depth + 1)?;
+        }
+        Ok(())
+    }
+
+    fn fmt_styled<D: fmt::Display>(
+        &self,
+        f: &mut Formatter<'_>,
+        style: &str,
+        value: D,
+    ) -> fmt::Result {
+        if self.color {
+            write!(f, "{style}{value}{ANSI_RESET}")
+        } else {
+            write!(f, "{value}")
+        }
+    }
+}
+
+impl<'a> fmt::Display for ViolationDisplay<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_violation(f, self.violation, 0)
     }
 }
 
 /// Represents violation code that indicates the type of the violation
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ViolationCode {
     AllTypesNotMatched,
     AnnotationMismatched,
@@ -113,6 +592,7 @@ pub enum ViolationCode {
     TypeMatched,
     TypeMismatched,
     UnexpectedAnnotation, // if unexpected annotation is found for annotations constraint
+    ValidationLimitExceeded, // if the validation exceeds the maximum depth or number of steps of its options
 }
 
 impl fmt::Display for ViolationCode {
@@ -142,7 +622,173 @@ impl fmt::Display for ViolationCode {
                 ViolationCode::TypeMatched => "type_matched",
                 ViolationCode::TypeMismatched => "type_mismatched",
                 ViolationCode::UnexpectedAnnotation => "unexpected_annotation",
+                ViolationCode::ValidationLimitExceeded => "validation_limit_exceeded",
             }
         )
     }
 }
+
+#[cfg(test)]
+mod violation_tests {
+    use super::*;
+    use crate::ion_path::IonPathElement;
+    use ion_rs::element::Element;
+    use ion_rs::TextWriterBuilder;
+    use rstest::*;
+
+    fn violation_tree() -> Violation {
+        let mut ion_path = IonPath::default();
+        ion_path.push(IonPathElement::Field("name".to_owned()));
+        let codepoint_length = Violation::new(
+            "codepoint_length",
+            ViolationCode::InvalidLength,
+            "expected codepoint length range::[1, max] found 0",
+            &mut ion_path,
+        );
+        let regex = Violation::new(
+            "regex",
+            ViolationCode::RegexMismatched,
+            "doesn't match regex [a-z]+",
+            &mut ion_path,
+        );
+        let fields = Violation::with_violations(
+            "fields",
+            ViolationCode::FieldsNotMatched,
+            "value didn't satisfy fields constraint",
+            &mut IonPath::default(),
+            vec![codepoint_length, regex],
+        );
+        Violation::with_violations(
+            "person",
+            ViolationCode::TypeConstraintsUnsatisfied,
+            "value didn't satisfy type constraint(s)",
+            &mut IonPath::default(),
+            vec![fields],
+        )
+    }
+
+    #[rstest(
+        color, max_depth, expected,
+        case::all_nested_violations(
+            false,
+            usize::MAX,
+            "person (type_constraints_unsatisfied) at (): value didn't satisfy type constraint(s)\n  \
+            fields (fields_not_matched) at (): value didn't satisfy fields constraint\n    \
+            codepoint_length (invalid_length) at (name): expected codepoint length range::[1, max] found 0\n    \
+            regex (regex_mismatched) at (name): doesn't match regex [a-z]+"
+        ),
+        case::max_depth(
+            false,
+            0,
+            "person (type_constraints_unsatisfied) at (): value didn't satisfy type constraint(s)\n  \
+            ... 1 nested violation(s) omitted"
+        ),
+        case::color(
+            true,
+            0,
+            "\x1b[1mperson\x1b[0m (\x1b[31mtype_constraints_unsatisfied\x1b[0m) at \x1b[36m()\x1b[0m: \
+            value didn't satisfy type constraint(s)\n  \
+            ... 1 nested violation(s) omitted"
+        ),
+    )]
+    fn violation_display(color: bool, max_depth: usize, expected: &str) {
+        let violation = violation_tree();
+        let display = violation
+            .display()
+            .with_color(color)
+            .with_max_depth(max_depth);
+        assert_eq!(format!("{display}"), expected);
+    }
+
+    #[rstest(
+        max_root_causes, expected_constraints,
+        case::all_root_causes(usize::MAX, vec!["codepoint_length", "regex"]),
+        case::first_root_cause(1, vec!["codepoint_length"]),
+        case::keeps_at_least_one_root_cause(0, vec!["codepoint_length"])
+    )]
+    fn violation_truncate_root_causes(max_root_causes: usize, expected_constraints: Vec<&str>) {
+        let mut violation = violation_tree();
+        violation.truncate_root_causes(max_root_causes);
+        let constraints: Vec<&str> = violation
+            .flattened_violations()
+            .iter()
+            .map(|violation| violation.constraint())
+            .collect();
+        assert_eq!(constraints, expected_constraints);
+        assert_eq!(violation.violations()[0].constraint(), "fields");
+    }
+
+    #[test]
+    fn violation_write_to() {
+        let mut writer = TextWriterBuilder::default().build(vec![]).unwrap();
+        violation_tree().write_to(&mut writer).unwrap();
+        writer.flush().unwrap();
+        let expected = Element::read_one(
+            r#"{
+                constraint: "person",
+                code: type_constraints_unsatisfied,
+                message: "value didn't satisfy type constraint(s)",
+                path: (),
+                violations: [
+                    {
+                        constraint: "fields",
+                        code: fields_not_matched,
+                        message: "value didn't satisfy fields constraint",
+                        path: (),
+                        violations: [
+                            {
+                                constraint: "codepoint_length",
+                                code: invalid_length,
+                                message: "expected codepoint length range::[1, max] found 0",
+                                path: (name),
+                                violations: [],
+                            },
+                            {
+                                constraint: "regex",
+                                code: regex_mismatched,
+                                message: "doesn't match regex [a-z]+",
+                                path: (name),
+                                violations: [],
+                            },
+                        ],
+                    },
+                ],
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(Element::read_one(writer.output()).unwrap(), expected);
+    }
+
+    #[rstest(
+        violation, expected,
+        case::nested_violations(
+            violation_tree(),
+            "{\"constraint\":\"person\",\"code\":\"type_constraints_unsatisfied\",\
+            \"message\":\"value didn't satisfy type constraint(s)\",\"path\":[],\"violations\":[\
+            {\"constraint\":\"fields\",\"code\":\"fields_not_matched\",\
+            \"message\":\"value didn't satisfy fields constraint\",\"path\":[],\"violations\":[\
+            {\"constraint\":\"codepoint_length\",\"code\":\"invalid_length\",\
+            \"message\":\"expected codepoint length range::[1, max] found 0\",\"path\":[\"name\"],\"violations\":[]},\
+            {\"constraint\":\"regex\",\"code\":\"regex_mismatched\",\
+            \"message\":\"doesn't match regex [a-z]+\",\"path\":[\"name\"],\"violations\":[]}]}]}"
+        ),
+        case::escaped_strings(
+            Violation::new(
+                "regex",
+                ViolationCode::RegexMismatched,
+                "doesn't match regex \"\\d+\"\n",
+                &mut {
+                    let mut ion_path = IonPath::default();
+                    ion_path.push(IonPathElement::Field("a\tb".to_owned()));
+                    ion_path.push(IonPathElement::Index(2));
+                    ion_path
+                },
+            ),
+            "{\"constraint\":\"regex\",\"code\":\"regex_mismatched\",\
+            \"message\":\"doesn't match regex \\\"\\\\d+\\\"\\n\",\"path\":[\"a\\tb\",2],\"violations\":[]}"
+        ),
+    )]
+    fn violation_to_json(violation: Violation, expected: &str) {
+        assert_eq!(violation.to_json(), expected);
+    }
+}
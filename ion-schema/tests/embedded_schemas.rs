@@ -0,0 +1,35 @@
+#![cfg(feature = "embed")]
+
+#[cfg(test)]
+mod embedded_schemas_tests {
+    use ion_schema::authority::{EmbeddedDocumentAuthority, MapDocumentAuthority};
+    use ion_schema::external::ion_rs::element::Element;
+    use ion_schema::include_schemas;
+    use ion_schema::result::IonSchemaResult;
+    use ion_schema::system::SchemaSystem;
+
+    #[test]
+    fn include_schemas_embeds_schema_directory() -> IonSchemaResult<()> {
+        let authority: EmbeddedDocumentAuthority = include_schemas!("tests/schemas");
+        let mut schema_ids: Vec<&str> = authority.schema_ids().collect();
+        schema_ids.sort();
+        assert_eq!(schema_ids, vec!["numbers/positive.isl", "sample.isl"]);
+
+        let mut schema_system = SchemaSystem::new(vec![
+            Box::new(authority),
+            Box::new(MapDocumentAuthority::new([(
+                "other.isl",
+                "type::{ name: other_type, type: string }",
+            )])),
+        ]);
+        // relative ids are resolved like for the file system
+        let schema = schema_system.load_schema("./numbers/../sample.isl")?;
+        let my_type = schema.get_type("my_type").unwrap();
+        assert!(my_type.validate(&Element::read_one("[1, 2]")?).is_ok());
+        assert!(my_type.validate(&Element::read_one("[0]")?).is_err());
+        assert!(schema_system.load_schema("../sample.isl").is_err());
+        // schemas that are not embedded are resolved by the next authority
+        assert!(schema_system.load_schema("other.isl").is_ok());
+        Ok(())
+    }
+}
@@ -38,6 +38,8 @@ pub struct ViolationResult {
 pub struct SchemaValidationResult {
     result: bool,
     violations: Array,
+    // indented tree of the violations for an invalid value
+    violation: String,
     value: String,
     has_error: bool,
     error: String,
@@ -56,6 +58,7 @@ impl SchemaValidationResult {
         SchemaValidationResult {
             result: r,
             violations: v,
+            violation: "".to_string(),
             value: val,
             has_error,
             error,
@@ -97,6 +100,14 @@ impl SchemaValidationResult {
     pub fn violations(&self) -> Array {
         self.violations.to_owned()
     }
+
+    pub fn violation(&self) -> String {
+        self.violation.to_owned()
+    }
+
+    pub fn set_violation(&mut self, val: String) {
+        self.violation = val;
+    }
 }
 
 #[wasm_bindgen]
@@ -210,15 +221,18 @@ pub fn validate(
         violations_result.push(&to_value(&v_result).unwrap());
     }
 
-    let result: SchemaValidationResult = SchemaValidationResult::new(
+    let mut validation_result: SchemaValidationResult = SchemaValidationResult::new(
         result.is_ok(),
         violations_result,
         format!("{value}"),
         false,
         "".to_string(),
     );
+    if let Err(violation) = &result {
+        validation_result.set_violation(format!("{}", violation.display()));
+    }
 
     log!("Schema validation was successful!");
 
-    result
+    validation_result
 }